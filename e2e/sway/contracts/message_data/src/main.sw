@@ -0,0 +1,26 @@
+contract;
+
+use std::{bytes::Bytes, inputs::{input_count, input_message_data, input_type, Input}};
+
+abi MessageData {
+    fn message_data() -> Bytes;
+}
+
+impl MessageData for Contract {
+    // Returns the data of the first message input carrying data.
+    fn message_data() -> Bytes {
+        let mut index = 0;
+        while index < input_count().as_u64() {
+            if let Some(Input::Message) = input_type(index) {
+                if let Some(data) = input_message_data(index, 0) {
+                    if data.len() > 0 {
+                        return data;
+                    }
+                }
+            }
+            index += 1;
+        }
+
+        Bytes::new()
+    }
+}
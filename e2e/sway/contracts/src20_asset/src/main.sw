@@ -0,0 +1,84 @@
+contract;
+
+use std::{asset::mint_to, string::String};
+
+abi SRC20 {
+    #[storage(read)]
+    fn total_assets() -> u64;
+
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64>;
+
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String>;
+
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String>;
+
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8>;
+}
+
+abi Mintable {
+    #[storage(read, write)]
+    fn mint(recipient: Identity, amount: u64);
+}
+
+configurable {
+    DECIMALS: u8 = 9,
+}
+
+storage {
+    total_supply: u64 = 0,
+}
+
+impl SRC20 for Contract {
+    #[storage(read)]
+    fn total_assets() -> u64 {
+        1
+    }
+
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64> {
+        if asset == AssetId::default() {
+            Some(storage.total_supply.read())
+        } else {
+            None
+        }
+    }
+
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String> {
+        if asset == AssetId::default() {
+            Some(String::from_ascii_str("Fuel Token"))
+        } else {
+            None
+        }
+    }
+
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String> {
+        if asset == AssetId::default() {
+            Some(String::from_ascii_str("FUEL"))
+        } else {
+            None
+        }
+    }
+
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8> {
+        if asset == AssetId::default() {
+            Some(DECIMALS)
+        } else {
+            None
+        }
+    }
+}
+
+impl Mintable for Contract {
+    #[storage(read, write)]
+    fn mint(recipient: Identity, amount: u64) {
+        mint_to(recipient, b256::zero(), amount);
+        storage.total_supply.write(storage.total_supply.read() + amount);
+    }
+}
@@ -0,0 +1,33 @@
+contract;
+
+use sway_libs::signed_integers::{i256::I256, i64::I64, i8::I8};
+
+struct SignedPair {
+    small: I8,
+    large: I256,
+}
+
+abi SignedIntegers {
+    fn add_i64(a: I64, b: I64) -> I64;
+    fn sub_i256(a: I256, b: I256) -> I256;
+    fn negative_one_i8() -> I8;
+    fn make_pair(small: I8, large: I256) -> SignedPair;
+}
+
+impl SignedIntegers for Contract {
+    fn add_i64(a: I64, b: I64) -> I64 {
+        a + b
+    }
+
+    fn sub_i256(a: I256, b: I256) -> I256 {
+        a - b
+    }
+
+    fn negative_one_i8() -> I8 {
+        I8::neg_try_from(1u8).unwrap()
+    }
+
+    fn make_pair(small: I8, large: I256) -> SignedPair {
+        SignedPair { small, large }
+    }
+}
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{collections::HashSet, num::NonZeroUsize, time::Duration};
 
 use fuel_tx::{
     consensus_parameters::{ConsensusParametersV1, FeeParametersV1},
@@ -7,6 +7,7 @@ use fuel_tx::{
 use fuels::{
     core::codec::{calldata, encode_fn_selector, DecoderConfig, EncoderConfig},
     prelude::*,
+    programs::contract::{get_balances_with_metadata, AssetMetadata},
     tx::ContractParameters,
     types::{errors::transaction::Reason, input::Input, Bits256, Identity},
 };
@@ -590,6 +591,142 @@ async fn test_contract_setup_macro_deploy_with_salt() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_contract_setup_macro_deploy_at_genesis() -> Result<()> {
+    // ANCHOR: contract_setup_macro_genesis
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(
+            Contract(
+                name = "LibContract",
+                project = "e2e/sway/contracts/lib_contract"
+            ),
+            Contract(
+                name = "LibContractCaller",
+                project = "e2e/sway/contracts/lib_contract_caller"
+            ),
+        ),
+        Deploy(
+            name = "lib_contract_instance",
+            contract = "LibContract",
+            wallet = "wallet",
+            genesis = true,
+        ),
+        Deploy(
+            name = "contract_caller_instance",
+            contract = "LibContractCaller",
+            wallet = "wallet",
+            genesis = true,
+        ),
+    );
+
+    // no transactions were needed to deploy the contracts
+    let provider = wallet.try_provider()?;
+    assert_eq!(provider.latest_block_height().await?, 0);
+
+    let response = contract_caller_instance
+        .methods()
+        .increment_from_contract(lib_contract_instance.contract_id(), 42)
+        .with_contracts(&[&lib_contract_instance])
+        .call()
+        .await?;
+
+    assert_eq!(43, response.value);
+    // ANCHOR_END: contract_setup_macro_genesis
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deploy_contracts_and_blobs_at_genesis() -> Result<()> {
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "e2e/sway/contracts/contract_test/out/release/contract_test-abi.json"
+    ));
+
+    // ANCHOR: genesis_deployments
+    let contract = Contract::load_from(
+        "sway/contracts/contract_test/out/release/contract_test.bin",
+        LoadConfiguration::default(),
+    )?;
+    let blob = Blob::new(vec![1, 2, 3]);
+
+    let deployments = GenesisDeployments::default()
+        .with_contract(
+            GenesisContract::new(contract.code())
+                .with_salt(contract.salt())
+                .with_storage_slots(contract.storage_slots().to_vec())
+                .with_balance(AssetId::zeroed(), 1_000),
+        )
+        .with_blob(blob.clone());
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins =
+        setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 1, DEFAULT_COIN_AMOUNT);
+
+    let (provider, contract_ids) =
+        setup_test_provider_with_genesis(coins, vec![], deployments, None, None).await?;
+    wallet.set_provider(provider.clone());
+
+    let contract_instance = MyContract::new(contract_ids[0].clone(), wallet);
+    // ANCHOR_END: genesis_deployments
+
+    assert_eq!(
+        contract_ids[0],
+        Bech32ContractId::from(contract.contract_id())
+    );
+    assert!(provider.blob_exists(blob.id()).await?);
+
+    let balances = contract_instance.get_balances().await?;
+    assert_eq!(balances[&AssetId::zeroed()], 1_000);
+
+    let response = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .call()
+        .await?;
+    assert_eq!(42, response.value);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_contract_setup_macro_with_custom_chain_config() -> Result<()> {
+    // ANCHOR: contract_setup_macro_chain_config
+    let base_asset_id = AssetId::from([1; 32]);
+    let chain_config = ChainConfigBuilder::default()
+        .with_base_asset_id(base_asset_id)
+        .with_gas_costs(GasCostsPreset::Unit);
+
+    setup_program_test!(
+        Options(chain_config = "chain_config"),
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // the wallets are funded with the base asset of the chain
+    let balance = wallet.get_asset_balance(&base_asset_id).await?;
+    assert!(balance > 0);
+    // ANCHOR_END: contract_setup_macro_chain_config
+
+    let response = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .call()
+        .await?;
+    assert_eq!(42, response.value);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_wallet_getter() -> Result<()> {
     setup_program_test!(
@@ -1967,6 +2104,42 @@ async fn simulations_can_be_made_without_coins() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn read_only_functions_can_be_viewed_without_coins() -> Result<()> {
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "e2e/sway/contracts/contract_test/out/release/contract_test-abi.json"
+    ));
+
+    let wallets = setup_node_with_high_price().await?;
+    let wallet = wallets.first().expect("has wallet");
+
+    let contract_id = Contract::load_from(
+        "sway/contracts/contract_test/out/release/contract_test.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy_if_not_exists(wallet, TxPolicies::default())
+    .await?;
+
+    MyContract::new(contract_id.clone(), wallet.clone())
+        .methods()
+        .initialize_counter(42)
+        .call()
+        .await?;
+
+    let provider = wallet.provider().cloned();
+    let no_funds_wallet = WalletUnlocked::new_random(provider);
+    let contract_instance = MyContract::new(contract_id, no_funds_wallet);
+
+    // ANCHOR: view
+    // `get_counter` is `#[storage(read)]`, no funds are needed to view it
+    let counter = contract_instance.views().get_counter().await?.value;
+    // ANCHOR_END: view
+    assert_eq!(counter, 42);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn simulations_can_be_made_without_coins_multicall() -> Result<()> {
     abigen!(Contract(
@@ -2007,6 +2180,79 @@ async fn simulations_can_be_made_without_coins_multicall() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn multi_calls_can_be_viewed_without_coins() -> Result<()> {
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "e2e/sway/contracts/contract_test/out/release/contract_test-abi.json"
+    ));
+
+    // given
+    let wallets = setup_node_with_high_price().await?;
+    let wallet = wallets.first().expect("has wallet");
+    let contract_id = Contract::load_from(
+        "sway/contracts/contract_test/out/release/contract_test.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy_if_not_exists(wallet, TxPolicies::default())
+    .await?;
+
+    let no_funds_wallet = WalletUnlocked::new_random(wallet.provider().cloned());
+    let contract_methods = MyContract::new(contract_id, no_funds_wallet.clone()).methods();
+
+    // when
+    // ANCHOR: multi_call_view
+    let value: (u64, u64) = CallHandler::new_multi_call(no_funds_wallet)
+        .add_call(contract_methods.get(1, 2))
+        .add_call(contract_methods.get(3, 4))
+        .view()
+        .await?
+        .value;
+    // ANCHOR_END: multi_call_view
+
+    // then
+    assert_eq!(value, (3, 7));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn strict_multi_call_view_rejects_functions_writing_storage() -> Result<()> {
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "e2e/sway/contracts/contract_test/out/release/contract_test-abi.json"
+    ));
+
+    // given
+    let wallets = setup_node_with_high_price().await?;
+    let wallet = wallets.first().expect("has wallet");
+    let contract_id = Contract::load_from(
+        "sway/contracts/contract_test/out/release/contract_test.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy_if_not_exists(wallet, TxPolicies::default())
+    .await?;
+
+    let no_funds_wallet = WalletUnlocked::new_random(wallet.provider().cloned());
+    let contract_methods = MyContract::new(contract_id, no_funds_wallet.clone()).methods();
+    let mut multi_call_handler = CallHandler::new_multi_call(no_funds_wallet)
+        .add_call(contract_methods.get(1, 2))
+        .add_call(contract_methods.increment_counter(1));
+
+    // when
+    // ANCHOR: multi_call_view_strict
+    let result = multi_call_handler.view_strict::<(u64, u64)>().await;
+    // ANCHOR_END: multi_call_view_strict
+
+    // then
+    let err = result.expect_err("should fail since one of the functions writes to storage");
+    assert!(err
+        .to_string()
+        .contains("cannot view a function that writes to storage"));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn contract_call_with_non_zero_base_asset_id_and_tip() -> Result<()> {
     use fuels::{prelude::*, tx::ConsensusParameters};
@@ -2409,6 +2655,130 @@ async fn loader_works_via_proxy() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn calls_are_routed_through_src14_proxy() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+
+    abigen!(
+        Contract(
+            name = "MyContract",
+            abi = "e2e/sway/contracts/huge_contract/out/release/huge_contract-abi.json"
+        ),
+        Contract(
+            name = "MyProxy",
+            abi = "e2e/sway/contracts/src14_proxy/out/release/src14_proxy-abi.json"
+        )
+    );
+
+    let contract_binary = "sway/contracts/huge_contract/out/release/huge_contract.bin";
+    let contract_id = Contract::load_from(contract_binary, LoadConfiguration::default())?
+        .convert_to_loader(100)?
+        .deploy_if_not_exists(&wallet, TxPolicies::default())
+        .await?;
+
+    // ANCHOR: src14_proxy
+    let configurables = MyProxyConfigurables::default()
+        .with_INITIAL_OWNER(Some(Identity::Address(wallet.address().into())))?
+        .with_INITIAL_TARGET(Some(ContractId::from(&contract_id)))?;
+    let proxy_contract = Contract::load_from(
+        "sway/contracts/src14_proxy/out/release/src14_proxy.bin",
+        LoadConfiguration::default().with_configurables(configurables),
+    )?;
+
+    let proxy = Src14Proxy::deploy(proxy_contract, wallet.clone(), TxPolicies::default()).await?;
+
+    let response = MyContract::new(proxy.id().clone(), wallet.clone())
+        .with_proxy(true)
+        .methods()
+        .something()
+        .call()
+        .await?
+        .value;
+    // ANCHOR_END: src14_proxy
+
+    assert_eq!(proxy.target().await?, Some(contract_id.clone()));
+    assert_eq!(response, 1001);
+
+    let new_target = Contract::load_from(contract_binary, LoadConfiguration::default())?
+        .with_salt([1; 32])
+        .convert_to_loader(100)?
+        .deploy_if_not_exists(&wallet, TxPolicies::default())
+        .await?;
+
+    // ANCHOR: src14_proxy_set_target
+    proxy.set_target(&new_target).call().await?;
+    // ANCHOR_END: src14_proxy_set_target
+
+    assert_eq!(proxy.target().await?, Some(new_target));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn src20_asset_metadata_and_amounts() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TokenContract",
+            project = "e2e/sway/contracts/src20_asset"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TokenContract",
+            wallet = "wallet",
+            random_salt = false,
+        ),
+    );
+    let recipient = Identity::Address(wallet.address().into());
+    contract_instance
+        .methods()
+        .mint(recipient, 1_500_000_000)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await?;
+
+    // ANCHOR: src20_asset
+    let asset = Src20Asset::new(
+        contract_instance.id().clone(),
+        Bits256::zeroed(),
+        wallet.clone(),
+    );
+
+    let metadata = asset.metadata().await?;
+    let total_supply = asset.total_supply().await?;
+    let balance = asset.balance_of(&wallet).await?;
+
+    let formatted = asset.format_amount(balance).await?;
+    let parsed = asset.parse_amount("0.25").await?;
+    // ANCHOR_END: src20_asset
+
+    assert_eq!(metadata.name.as_deref(), Some("Fuel Token"));
+    assert_eq!(metadata.symbol.as_deref(), Some("FUEL"));
+    assert_eq!(metadata.decimals, Some(9));
+    assert_eq!(total_supply, Some(1_500_000_000));
+    assert_eq!(balance, 1_500_000_000);
+    assert_eq!(formatted, "1.5");
+    assert_eq!(parsed, 250_000_000);
+
+    // ANCHOR: src20_balances
+    let balances = get_balances_with_metadata(&wallet, &[asset.clone()]).await?;
+
+    let token_balance = balances
+        .iter()
+        .find(|balance| balance.asset_id == asset.asset_id())
+        .expect("minted asset should be listed");
+    assert_eq!(token_balance.to_string(), "1.5 FUEL");
+    // ANCHOR_END: src20_balances
+
+    let base_balance = balances
+        .iter()
+        .find(|balance| balance.asset_id == AssetId::zeroed())
+        .expect("base asset should be listed");
+    assert_eq!(base_balance.metadata, AssetMetadata::default());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn loader_storage_works_via_proxy() -> Result<()> {
     let wallet = launch_provider_and_get_wallet().await?;
@@ -2480,3 +2850,220 @@ async fn loader_storage_works_via_proxy() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn contract_interface_is_implemented_by_methods_and_stub() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet",
+            random_salt = false,
+        ),
+    );
+
+    // ANCHOR: contract_interface
+    async fn initialize_and_increment(contract: &impl TestContractInterface) -> Result<u64> {
+        contract.initialize_counter(40).await?;
+
+        contract.increment_counter(2).await
+    }
+    // ANCHOR_END: contract_interface
+
+    let value = initialize_and_increment(&contract_instance.methods()).await?;
+    assert_eq!(value, 42);
+
+    // ANCHOR: contract_stub
+    let stub = TestContractStub::default()
+        .with_initialize_counter(Ok)
+        .with_increment_counter(|value| Ok(value + 1));
+
+    let value = initialize_and_increment(&stub).await?;
+    // ANCHOR_END: contract_stub
+    assert_eq!(value, 3);
+
+    let err = TestContractStub::default()
+        .get_counter()
+        .await
+        .expect_err("should fail since no response was programmed");
+    assert!(err
+        .to_string()
+        .contains("no response programmed for `get_counter`"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deployment_manifest_can_be_deployed_again() -> Result<()> {
+    use fuels::programs::contract::{DeploymentLock, DeploymentManifest};
+
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "e2e/sway/contracts/configurables/out/release/configurables-abi.json"
+    ));
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let temp_dir = tempfile::tempdir()?;
+
+    let contracts_dir = std::env::current_dir()?.join("sway/contracts");
+    let manifest_path = temp_dir.path().join("deployment.toml");
+    std::fs::write(
+        &manifest_path,
+        format!(
+            r#"
+            [contracts.registry]
+            binary = "{contracts_dir}/contract_test/out/release/contract_test.bin"
+
+            [contracts.config]
+            binary = "{contracts_dir}/configurables/out/release/configurables.bin"
+            abi = "{contracts_dir}/configurables/out/release/configurables-abi.json"
+
+            [contracts.config.configurables]
+            U8 = 7
+            B256 = {{ "$contract" = "registry" }}
+            "#,
+            contracts_dir = contracts_dir.display()
+        ),
+    )?;
+    let lockfile_path = temp_dir.path().join("deployment.lock.toml");
+
+    // ANCHOR: deployment_manifest
+    let plan = DeploymentManifest::load_from(&manifest_path)?
+        .plan()?
+        .with_lockfile(&lockfile_path);
+    let lock = plan.deploy(&wallet, TxPolicies::default()).await?;
+
+    let registry_id = lock.contract_id("registry").unwrap();
+    let config_id = lock.contract_id("config").unwrap();
+    // ANCHOR_END: deployment_manifest
+
+    assert_eq!(DeploymentLock::load_from(&lockfile_path)?, lock);
+
+    let (_, u8, _, _, _, _, b256, ..) = MyContract::new(config_id, wallet.clone())
+        .methods()
+        .return_configurables()
+        .call()
+        .await?
+        .value;
+    assert_eq!(u8, 7);
+    assert_eq!(b256, Bits256(*ContractId::from(&registry_id)));
+
+    let balance_before = wallet.get_asset_balance(&AssetId::zeroed()).await?;
+    let lock_again = DeploymentManifest::load_from(&manifest_path)?
+        .plan()?
+        .with_lockfile(&lockfile_path)
+        .deploy(&wallet, TxPolicies::default())
+        .await?;
+
+    assert_eq!(lock_again, lock);
+    assert_eq!(DeploymentLock::load_from(&lockfile_path)?, lock);
+    assert_eq!(
+        wallet.get_asset_balance(&AssetId::zeroed()).await?,
+        balance_before
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deployed_contract_can_be_verified_against_local_artifacts() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?.clone();
+
+    let binary_path = "sway/contracts/contract_test/out/release/contract_test.bin";
+    let contract = Contract::load_from(binary_path, LoadConfiguration::default())?;
+    let contract_id = contract
+        .clone()
+        .deploy(&wallet, TxPolicies::default())
+        .await?;
+
+    let deployed = provider
+        .contract(&contract_id)
+        .await?
+        .expect("contract should be deployed");
+    assert_eq!(deployed.bytecode, contract.code());
+    assert_eq!(deployed.salt, contract.salt());
+
+    // ANCHOR: verify_deployment
+    let report = Contract::load_from(binary_path, LoadConfiguration::default())?
+        .verify_deployment(&contract_id, &provider)
+        .await?;
+
+    assert!(report.is_verified());
+    // ANCHOR_END: verify_deployment
+
+    let report = contract
+        .with_salt([1; 32])
+        .verify_deployment(&contract_id, &provider)
+        .await?;
+    assert!(!report.is_verified());
+    assert!(!report.salt.matches());
+
+    let unknown_id = Bech32ContractId::from(ContractId::from([1; 32]));
+    assert!(provider.contract(&unknown_id).await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn loader_with_missing_blobs_is_not_verified() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?.clone();
+
+    let loader = Contract::loader_from_blob_ids(vec![[1; 32]], Salt::zeroed(), vec![])?;
+    let contract_id = loader
+        .clone()
+        .deploy(&wallet, TxPolicies::default())
+        .await?;
+
+    let report = loader.verify_deployment(&contract_id, &provider).await?;
+
+    assert!(!report.is_verified());
+    assert!(report.code.unwrap().matches());
+    assert_eq!(report.loader.unwrap().missing_blobs, vec![[1; 32]]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_calls_can_be_dispatched() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "e2e/sway/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet",
+            random_salt = false,
+        ),
+    );
+    const NUM_CALLS: u64 = 6;
+
+    // given
+    let calls: Vec<_> = (0..NUM_CALLS)
+        .map(|i| contract_instance.methods().get(i, 10 * i))
+        .collect();
+    let dispatcher = TxDispatcher::new(wallet).with_lanes(NonZeroUsize::new(2).unwrap());
+
+    // when
+    let results = dispatcher.dispatch(calls).await?;
+
+    // then
+    let responses = results.into_iter().collect::<Result<Vec<_>>>()?;
+    let values: Vec<_> = responses.iter().map(|response| response.value).collect();
+    let expected_values: Vec<_> = (0..NUM_CALLS).map(|i| 11 * i).collect();
+    assert_eq!(values, expected_values);
+
+    let tx_ids: HashSet<_> = responses.iter().map(|response| response.tx_id).collect();
+    assert_eq!(tx_ids.len(), NUM_CALLS as usize);
+
+    Ok(())
+}
@@ -43,6 +43,61 @@ async fn test_parse_logged_variables() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_decode_logs_as_events() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "e2e/sway/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet",
+            random_salt = false,
+        ),
+    );
+
+    // ANCHOR: decode_events
+    let response = contract_instance
+        .methods()
+        .produce_logs_variables()
+        .call()
+        .await?;
+
+    let events = response.decode_events::<LogContractEvent>()?;
+
+    let mut logged = vec![];
+    for ContractEvent { contract_id, event } in events {
+        assert_eq!(
+            contract_id,
+            ContractId::from(contract_instance.contract_id())
+        );
+
+        match event {
+            LogContractEvent::U64(value) => logged.push(format!("u64: {value}")),
+            LogContractEvent::Bits256(value) => logged.push(format!("b256: {}", value.0[0])),
+            LogContractEvent::SizedAsciiString4(value) => logged.push(format!("str: {value}")),
+            LogContractEvent::U8Array3(value) => logged.push(format!("array: {value:?}")),
+            other => panic!("unexpected event: {other:?}"),
+        }
+    }
+
+    assert_eq!(
+        logged,
+        ["u64: 64", "b256: 239", "str: Fuel", "array: [1, 2, 3]"]
+    );
+    // ANCHOR_END: decode_events
+
+    // the generated enum can also decode receipts directly
+    let contract_id = ContractId::from(contract_instance.contract_id());
+    let events = LogContractEvent::decode_events(&response.receipts, &[contract_id])?;
+    assert_eq!(events.len(), 4);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_parse_logs_values() -> Result<()> {
     setup_program_test!(
@@ -199,6 +199,54 @@ async fn spend_predicate_coins_messages_basic() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn predicate_setup_macro() -> Result<()> {
+    // ANCHOR: predicate_setup_macro
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Predicate(
+            name = "MyPredicate",
+            project = "e2e/sway/predicates/basic_predicate"
+        )),
+        LoadPredicate(
+            name = "predicate",
+            program = "MyPredicate",
+            wallet = "wallet",
+            data = MyPredicateEncoder::default().encode_data(4097, 4097)?
+        ),
+        Fund(predicate = "predicate", amount = 100, coins = 2),
+    );
+
+    let asset_id = AssetId::zeroed();
+    assert_eq!(predicate.get_asset_balance(&asset_id).await?, 200);
+    // ANCHOR_END: predicate_setup_macro
+
+    let provider = wallet.try_provider()?;
+    let receiver_balance = wallet.get_asset_balance(&asset_id).await?;
+
+    // TODO: https://github.com/FuelLabs/fuels-rs/issues/1394
+    let expected_fee = 1;
+    predicate
+        .transfer(
+            wallet.address(),
+            200 - expected_fee,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    assert_address_balance(predicate.address(), provider, asset_id, 0).await;
+    assert_address_balance(
+        wallet.address(),
+        provider,
+        asset_id,
+        receiver_balance + 200 - expected_fee,
+    )
+    .await;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn pay_with_predicate() -> Result<()> {
     abigen!(
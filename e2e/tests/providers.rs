@@ -13,7 +13,7 @@ use fuels::{
         message::Message,
         transaction_builders::{BuildableTransaction, ScriptTransactionBuilder},
         tx_status::TxStatus,
-        Bits256,
+        Bits256, Bytes,
     },
 };
 
@@ -151,6 +151,120 @@ async fn test_input_message() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn message_data_is_available_to_the_called_contract() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+
+    // ANCHOR: message_with_data
+    let deposit = setup_message_with_data(
+        &Bech32Address::default(),
+        wallet.address(),
+        DEFAULT_COIN_AMOUNT,
+        vec![1, 2, 3],
+    );
+    // ANCHOR_END: message_with_data
+    let coins =
+        setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 1, DEFAULT_COIN_AMOUNT);
+
+    let provider = setup_test_provider(coins, vec![deposit.clone()], None, None).await?;
+    let base_asset_id = *provider.base_asset_id();
+    wallet.set_provider(provider.clone());
+
+    setup_program_test!(
+        Abigen(Contract(
+            name = "MessageData",
+            project = "e2e/sway/contracts/message_data"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "MessageData",
+            wallet = "wallet",
+            random_salt = false,
+        ),
+    );
+
+    // ANCHOR: spend_message_with_data
+    let message = provider
+        .get_message(&deposit.nonce)
+        .await?
+        .expect("message should exist");
+
+    let response = contract_instance
+        .methods()
+        .message_data()
+        .with_messages([message])
+        .call()
+        .await?;
+    // ANCHOR_END: spend_message_with_data
+
+    assert_eq!(response.value, Bytes(vec![1, 2, 3]));
+    assert!(wallet.get_asset_balance(&base_asset_id).await? > DEFAULT_COIN_AMOUNT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn predicate_message_data_is_available_to_the_called_contract() -> Result<()> {
+    abigen!(Predicate(
+        name = "MyPredicate",
+        abi = "e2e/sway/predicates/basic_predicate/out/release/basic_predicate-abi.json"
+    ));
+
+    // given
+    let mut wallet = WalletUnlocked::new_random(None);
+    let predicate_data = MyPredicateEncoder::default().encode_data(4097, 4097)?;
+    let predicate =
+        Predicate::load_from("sway/predicates/basic_predicate/out/release/basic_predicate.bin")?
+            .with_data(predicate_data);
+
+    let deposit = setup_message_with_data(
+        Bech32Address::default(),
+        predicate.address(),
+        DEFAULT_COIN_AMOUNT,
+        vec![4, 5, 6],
+    );
+    let coins =
+        setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 1, DEFAULT_COIN_AMOUNT);
+
+    let provider = setup_test_provider(coins, vec![deposit.clone()], None, None).await?;
+    let base_asset_id = *provider.base_asset_id();
+    wallet.set_provider(provider.clone());
+
+    setup_program_test!(
+        Abigen(Contract(
+            name = "MessageData",
+            project = "e2e/sway/contracts/message_data"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "MessageData",
+            wallet = "wallet",
+            random_salt = false,
+        ),
+    );
+
+    let message = provider
+        .get_message(&deposit.nonce)
+        .await?
+        .expect("message should exist");
+
+    // when
+    // ANCHOR: spend_predicate_message_with_data
+    let response = contract_instance
+        .methods()
+        .message_data()
+        .with_predicate_messages(&predicate, [message])
+        .call()
+        .await?;
+    // ANCHOR_END: spend_predicate_message_with_data
+
+    // then
+    assert_eq!(response.value, Bytes(vec![4, 5, 6]));
+    assert!(wallet.get_asset_balance(&base_asset_id).await? > DEFAULT_COIN_AMOUNT);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_input_message_pays_fee() -> Result<()> {
     let mut wallet = WalletUnlocked::new_random(None);
@@ -217,6 +331,125 @@ async fn can_increase_block_height() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_nodes_can_be_managed_within_a_test() -> Result<()> {
+    // ANCHOR: test_node
+    let chain_config = |chain_name: &str| ChainConfig {
+        chain_name: chain_name.to_string(),
+        ..ChainConfig::local_testnet()
+    };
+
+    let mut first_node = setup_test_node(vec![], vec![], None, Some(chain_config("first"))).await?;
+    let second_node = setup_test_node(vec![], vec![], None, Some(chain_config("second"))).await?;
+
+    let provider = first_node.provider().await?;
+    provider.produce_blocks(3, None).await?;
+
+    first_node.stop().await?;
+    assert!(provider.latest_block_height().await.is_err());
+
+    // an in-memory node starts again from genesis, on the same address
+    first_node.restart().await?;
+    assert_eq!(provider.latest_block_height().await?, 0);
+
+    assert_eq!(
+        second_node.provider().await?.chain_info().await?.name,
+        "second"
+    );
+    // ANCHOR_END: test_node
+    assert_ne!(first_node.address(), second_node.address());
+
+    Ok(())
+}
+
+#[cfg(any(not(feature = "fuel-core-lib"), feature = "rocksdb"))]
+#[tokio::test]
+async fn test_node_keeps_its_chain_across_restarts() -> Result<()> {
+    let temp_dir = tempfile::tempdir().expect("failed to make tempdir");
+
+    // ANCHOR: test_node_restart
+    let node_config = NodeConfig {
+        database_type: DbType::RocksDb(Some(temp_dir.path().join("db"))),
+        ..NodeConfig::default()
+    };
+    let mut node = setup_test_node(vec![], vec![], Some(node_config), None).await?;
+
+    let provider = node.provider().await?;
+    provider.produce_blocks(3, None).await?;
+
+    node.restart().await?;
+
+    assert_eq!(provider.latest_block_height().await?, 3);
+    // ANCHOR_END: test_node_restart
+
+    Ok(())
+}
+
+#[cfg(any(not(feature = "fuel-core-lib"), feature = "rocksdb"))]
+#[tokio::test]
+async fn test_nodes_can_be_started_from_a_snapshot() -> Result<()> {
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "e2e/sway/contracts/contract_test/out/release/contract_test-abi.json"
+    ));
+    let db_dir = tempfile::tempdir().expect("failed to make tempdir");
+    let snapshot_dir = tempfile::tempdir().expect("failed to make tempdir");
+
+    // ANCHOR: take_snapshot
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins =
+        setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 1, DEFAULT_COIN_AMOUNT);
+    let node_config = NodeConfig {
+        database_type: DbType::RocksDb(Some(db_dir.path().join("db"))),
+        ..NodeConfig::default()
+    };
+    let mut node = setup_test_node(coins, vec![], Some(node_config), None).await?;
+    wallet.set_provider(node.provider().await?);
+
+    let contract_id = Contract::load_from(
+        "sway/contracts/contract_test/out/release/contract_test.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await?;
+    MyContract::new(contract_id.clone(), wallet.clone())
+        .methods()
+        .initialize_counter(42)
+        .call()
+        .await?;
+
+    node.snapshot().await?.write_to(snapshot_dir.path())?;
+    // ANCHOR_END: take_snapshot
+
+    // ANCHOR: start_from_snapshot
+    let snapshot = ChainSnapshot::load_from(snapshot_dir.path())?;
+    let restored_node = snapshot.start_node(None).await?;
+    wallet.set_provider(restored_node.provider().await?);
+
+    let counter = MyContract::new(contract_id, wallet)
+        .methods()
+        .get_counter()
+        .call()
+        .await?
+        .value;
+    assert_eq!(counter, 42);
+    // ANCHOR_END: start_from_snapshot
+
+    // the restored chain continues after the block the snapshot was taken at
+    let restored_provider = restored_node.provider().await?;
+    let snapshot_height = node.provider().await?.latest_block_height().await?;
+    let restored_height = restored_provider.latest_block_height().await?;
+    assert!(restored_height > snapshot_height);
+
+    restored_provider.produce_blocks(1, None).await?;
+    assert_eq!(
+        restored_provider.latest_block_height().await?,
+        restored_height + 1
+    );
+
+    Ok(())
+}
+
 // debug builds are slower (20x for `fuel-core-lib`, 4x for a release-fuel-core-binary), makes for
 // flaky tests
 #[cfg(not(feature = "fuel-core-lib"))]
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use fuels::{
     prelude::*,
-    types::{Bits256, EvmAddress, Identity, SizedAsciiString, B512, U256},
+    types::{Bits256, EvmAddress, Identity, SizedAsciiString, B512, I256, I64, I8, U256},
 };
 
 pub fn null_contract_id() -> Bech32ContractId {
@@ -1058,6 +1058,41 @@ async fn test_identity_can_be_decoded() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn generated_types_can_derive_serde() -> Result<()> {
+    // ANCHOR: abigen_derives
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TypesContract",
+            project = "e2e/sway/types/contracts/identity",
+            derives = "serde, Hash"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TypesContract",
+            wallet = "wallet",
+            random_salt = false,
+        ),
+    );
+    // ANCHOR_END: abigen_derives
+
+    let response = contract_instance
+        .methods()
+        .get_identity_tuple()
+        .call()
+        .await?;
+    let (s, e) = response.value;
+
+    let json = serde_json::to_string(&(&s, &e))?;
+    let (decoded_s, decoded_e): (TestStruct, TestEnum) = serde_json::from_str(&json)?;
+
+    assert_eq!(decoded_s, s);
+    assert_eq!(decoded_e, e);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_identity_can_be_encoded() -> Result<()> {
     setup_program_test!(
@@ -1541,6 +1576,77 @@ fn u256_from(parts: (u64, u64, u64, u64)) -> U256 {
     U256::from(bytes)
 }
 
+#[tokio::test]
+async fn test_signed_integers() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TypesContract",
+            project = "e2e/sway/types/contracts/signed_integers"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TypesContract",
+            wallet = "wallet",
+            random_salt = false,
+        ),
+    );
+    let contract_methods = contract_instance.methods();
+
+    // ANCHOR: signed_integers
+    let sum = contract_methods
+        .add_i64(I64::new(-10), I64::new(3))
+        .call()
+        .await?
+        .value;
+    assert_eq!(sum, I64::new(-7));
+    assert_eq!(i64::from(sum), -7);
+    // ANCHOR_END: signed_integers
+
+    let difference = contract_methods
+        .sub_i256(I256::from(5), I256::from(i128::MAX))
+        .call()
+        .await?
+        .value;
+    assert_eq!(difference, I256::from(5) - I256::from(i128::MAX));
+
+    let negative_one = contract_methods.negative_one_i8().call().await?.value;
+    assert_eq!(negative_one, I8::new(-1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn signed_integers_in_generated_types_support_serde() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TypesContract",
+            project = "e2e/sway/types/contracts/signed_integers",
+            derives = "serde"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TypesContract",
+            wallet = "wallet",
+            random_salt = false,
+        ),
+    );
+
+    let pair = contract_instance
+        .methods()
+        .make_pair(I8::new(-3), I256::from(i128::MIN))
+        .call()
+        .await?
+        .value;
+
+    let json = serde_json::to_string(&pair)?;
+    assert_eq!(json, format!(r#"{{"small":-3,"large":"{}"}}"#, i128::MIN));
+    assert_eq!(serde_json::from_str::<SignedPair>(&json)?, pair);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_u256() -> Result<()> {
     setup_program_test!(
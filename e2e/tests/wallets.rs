@@ -1,4 +1,4 @@
-use std::iter::repeat;
+use std::{collections::HashSet, iter::repeat, num::NonZeroUsize};
 
 use fuel_tx::{input::coin::CoinSigned, Input};
 use fuels::{
@@ -338,6 +338,65 @@ async fn transfer_coins_with_change() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn dispatch_transfers_concurrently() -> Result<()> {
+    const NUM_COINS: u64 = 3;
+    const COIN_AMOUNT: u64 = 1_000_000;
+    const SEND_AMOUNT: u64 = 10;
+    const NUM_TRANSFERS: usize = 7;
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let receiver = WalletUnlocked::new_random(None);
+    let coins =
+        setup_single_asset_coins(wallet.address(), AssetId::zeroed(), NUM_COINS, COIN_AMOUNT);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider);
+
+    let transfer = |amount| {
+        ScriptTransactionBuilder::prepare_transfer(
+            vec![],
+            vec![Output::coin(
+                receiver.address().into(),
+                amount,
+                AssetId::zeroed(),
+            )],
+            TxPolicies::default(),
+        )
+    };
+    let mut transfers: Vec<_> = repeat(SEND_AMOUNT)
+        .take(NUM_TRANSFERS)
+        .map(transfer)
+        .collect();
+    // more than any single lane owns
+    transfers.insert(2, transfer(2 * COIN_AMOUNT));
+
+    // ANCHOR: dispatch_transfers
+    let dispatcher =
+        TxDispatcher::new(wallet).with_lanes(NonZeroUsize::new(NUM_COINS as usize).unwrap());
+    let results = dispatcher.dispatch(transfers).await?;
+    // ANCHOR_END: dispatch_transfers
+
+    assert_eq!(results.len(), NUM_TRANSFERS + 1);
+    assert!(results[2].is_err());
+
+    let tx_ids: HashSet<_> = results
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != 2)
+        .map(|(_, result)| result.as_ref().expect("transfer should succeed").0)
+        .collect();
+    assert_eq!(tx_ids.len(), NUM_TRANSFERS);
+
+    let received = dispatcher
+        .account()
+        .try_provider()?
+        .get_asset_balance(receiver.address(), AssetId::zeroed())
+        .await?;
+    assert_eq!(received, SEND_AMOUNT * NUM_TRANSFERS as u64);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_wallet_get_coins() -> Result<()> {
     const AMOUNT: u64 = 1000;
@@ -371,6 +430,37 @@ async fn setup_transfer_test(amount: u64) -> Result<(WalletUnlocked, WalletUnloc
     Ok((wallet_1, wallet_2))
 }
 
+#[tokio::test]
+async fn transfer_accepts_addresses_in_any_format() -> Result<()> {
+    // given
+    const AMOUNT: u64 = 100;
+    let (wallet_1, wallet_2) = setup_transfer_test(AMOUNT).await?;
+    let recipient = wallet_2.address().format_as(AddressFormat::ChecksumHex);
+
+    // when
+    // ANCHOR: transfer_to_hex_address
+    wallet_1
+        .transfer(
+            recipient.as_str(),
+            AMOUNT / 2,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+    // ANCHOR_END: transfer_to_hex_address
+    let err = wallet_1
+        .transfer("0x1234", 1, AssetId::zeroed(), TxPolicies::default())
+        .await
+        .expect_err("should fail since the address is too short");
+
+    // then
+    let balance = wallet_2.get_asset_balance(&AssetId::zeroed()).await?;
+    assert_eq!(balance, AMOUNT / 2);
+    assert!(err.to_string().contains("invalid hex address"));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn transfer_more_than_owned() -> Result<()> {
     const AMOUNT: u64 = 1000000;
@@ -478,3 +568,39 @@ async fn test_transfer_with_multiple_signatures() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn withdrawal_is_tracked_until_its_proof_is_available() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?.clone();
+    let base_layer_address = Bech32Address::from(Address::from([1; 32]));
+
+    // ANCHOR: withdrawal_tracker
+    // a local node has no base layer, its latest block stands in for the last committed one
+    let tracker =
+        WithdrawalTracker::new(provider.clone(), LatestBlockCommitHeight(provider.clone()));
+
+    let withdrawal = tracker
+        .withdraw(&wallet, &base_layer_address, 1000, TxPolicies::default())
+        .await?;
+    assert!(matches!(
+        tracker.status(&withdrawal).await?,
+        WithdrawalStatus::Pending
+    ));
+
+    // the withdrawal can be proven once a later block is committed
+    provider.produce_blocks(1, None).await?;
+
+    let WithdrawalStatus::ProofAvailable(proof) = tracker.status(&withdrawal).await? else {
+        panic!("proof should be available");
+    };
+    // relay the message on the base layer using `proof`, then stop tracking it
+    tracker.complete(&withdrawal.nonce).await?;
+    // ANCHOR_END: withdrawal_tracker
+
+    assert_eq!(proof.amount, 1000);
+    assert_eq!(proof.recipient, base_layer_address);
+    assert!(tracker.withdrawals().await?.is_empty());
+
+    Ok(())
+}
@@ -112,4 +112,38 @@ mod tests {
 
         Ok(())
     }
+
+    mod property_based_testing {
+        // ANCHOR: property_based_testing
+        use fuels::{
+            core::codec::{
+                arbitrary::{check_round_trip, value_strategy},
+                DecoderConfig,
+            },
+            macros::{Parameterize, Tokenizable},
+        };
+        use proptest::proptest;
+
+        #[derive(Parameterize, Tokenizable, Debug, Clone, PartialEq)]
+        enum Payload {
+            Empty(()),
+            Items(Vec<u64>),
+        }
+
+        #[derive(Parameterize, Tokenizable, Debug, Clone, PartialEq)]
+        struct Message {
+            id: u32,
+            payload: Payload,
+        }
+
+        proptest! {
+            #[test]
+            fn messages_round_trip(
+                message in value_strategy::<Message>(DecoderConfig::default()).unwrap()
+            ) {
+                check_round_trip(&message, DecoderConfig::default())?;
+            }
+        }
+        // ANCHOR_END: property_based_testing
+    }
 }
@@ -139,6 +139,37 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn custom_chain_with_builder() -> Result<()> {
+        use fuels::{prelude::*, tx::FeeParameters};
+
+        // ANCHOR: custom_chain_builder
+        let base_asset_id = AssetId::from([1; 32]);
+        let chain_config = ChainConfigBuilder::default()
+            .with_chain_name("custom_chain")
+            .with_base_asset_id(base_asset_id)
+            .with_max_inputs(2)
+            .with_fee_params(FeeParameters::default().with_gas_price_factor(10))
+            .with_gas_costs(GasCostsPreset::Unit)
+            .build();
+
+        let wallet = WalletUnlocked::new_random(None);
+        let coins = setup_single_asset_coins(
+            wallet.address(),
+            base_asset_id,
+            DEFAULT_NUM_COINS,
+            DEFAULT_COIN_AMOUNT,
+        );
+
+        let provider = setup_test_provider(coins, vec![], None, Some(chain_config)).await?;
+        // ANCHOR_END: custom_chain_builder
+
+        assert_eq!(*provider.base_asset_id(), base_asset_id);
+        assert_eq!(provider.consensus_parameters().tx_params().max_inputs(), 2);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn transfer_multiple() -> Result<()> {
         use std::str::FromStr;
@@ -100,7 +100,10 @@ mod tests {
     #[tokio::test]
     async fn bech32() -> Result<()> {
         // ANCHOR: bech32
-        use fuels::types::{bech32::Bech32Address, Address, Bytes32};
+        use fuels::types::{
+            bech32::{AddressFormat, Bech32Address},
+            Address, Bytes32,
+        };
 
         // New from HRP string and a hash
         // ANCHOR: array_to_bech32
@@ -134,6 +137,31 @@ mod tests {
         let _plain_address: Address = bech32_address.into();
         // ANCHOR_END: bech32_to_address
 
+        // From and to hex
+        // ANCHOR: hex_to_bech32
+        let hex = "0x9cfb2cad509d417ec40b70ebe1dd72a3624d46fdd1ea5420dbd755ce7f4dc897";
+        let bech32_address = Bech32Address::from_str(hex)?;
+
+        let checksummed = bech32_address.format_as(AddressFormat::ChecksumHex);
+        assert_eq!(
+            checksummed,
+            "0x9cfB2CAd509D417ec40b70ebE1DD72a3624D46fdD1Ea5420dBD755CE7f4Dc897"
+        );
+
+        // mixed-case hex is validated against its checksum
+        assert_eq!(Bech32Address::from_str(&checksummed)?, bech32_address);
+        assert!(Bech32Address::from_str(&checksummed.replace('B', "b")).is_err());
+
+        let message = format!("sent to {}", bech32_address.display_as(AddressFormat::Hex));
+        assert_eq!(message, format!("sent to {hex}"));
+        // ANCHOR_END: hex_to_bech32
+
+        // ANCHOR: display_default
+        AddressFormat::set_display_default(AddressFormat::Hex);
+        assert_eq!(bech32_address.to_string(), hex);
+        AddressFormat::set_display_default(AddressFormat::Bech32);
+        // ANCHOR_END: display_default
+
         // ANCHOR_END: bech32
 
         Ok(())
@@ -242,6 +242,49 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn setup_seeded_wallets() -> Result<()> {
+        // ANCHOR: seeded_wallets
+        use fuels::prelude::*;
+
+        let config = WalletsConfig::new(Some(2), Some(3), Some(100)).with_seed(42);
+        let wallets = launch_custom_provider_and_get_wallets(config, None, None).await?;
+
+        // The same seed gives the same keys and the same coin UTXO ids on every run
+        let config = WalletsConfig::new(Some(2), Some(3), Some(100)).with_seed(42);
+        let same_wallets = launch_custom_provider_and_get_wallets(config, None, None).await?;
+        assert_eq!(wallets[0].address(), same_wallets[0].address());
+        // ANCHOR_END: seeded_wallets
+
+        // ANCHOR: seeded_coins
+        seed_test_rng(42);
+        let (coins, asset_ids) = setup_multiple_assets_coins(wallets[0].address(), 3, 2, 100);
+
+        seed_test_rng(42);
+        let (same_coins, same_asset_ids) =
+            setup_multiple_assets_coins(wallets[0].address(), 3, 2, 100);
+
+        assert_eq!(coins, same_coins);
+        assert_eq!(asset_ids, same_asset_ids);
+        // ANCHOR_END: seeded_coins
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn report_the_test_seed() -> Result<()> {
+        use fuels::prelude::*;
+
+        // ANCHOR: report_test_seed
+        // prints the seed behind the generated coins if the test panics
+        let _seed = report_test_seed();
+
+        let wallet = launch_provider_and_get_wallet().await?;
+        // ANCHOR_END: report_test_seed
+        assert!(!wallet.get_balances().await?.is_empty());
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[allow(unused_variables)]
     async fn setup_wallet_multiple_assets() -> Result<()> {
@@ -382,6 +425,9 @@ mod tests {
         // Verify the amount and recipient
         assert_eq!(proof.amount, amount);
         assert_eq!(proof.recipient, base_layer_address);
+
+        // Verify the proof locally instead of trusting the node
+        proof.verify()?;
         // ANCHOR_END: wallet_withdraw_to_base
 
         Ok(())
@@ -9,7 +9,7 @@ use fuels_core::types::{
     coin::Coin,
     coin_type::CoinType,
     coin_type_id::CoinTypeId,
-    errors::Result,
+    errors::{Error, Result},
     input::Input,
     message::Message,
     transaction::{Transaction, TxPolicies},
@@ -125,6 +125,19 @@ pub trait Account: ViewOnlyAccount {
     /// Add base asset inputs to the transaction to cover the estimated fee.
     /// Requires contract inputs to be at the start of the transactions inputs vec
     /// so that their indexes are retained
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip(self, tb),
+            fields(
+                account = %self.address(),
+                missing_base_amount = tracing::field::Empty,
+                new_inputs = tracing::field::Empty
+            ),
+            err
+        )
+    )]
     async fn adjust_for_fee<Tb: TransactionBuilder + Sync>(
         &self,
         tb: &mut Tb,
@@ -136,6 +149,9 @@ pub trait Account: ViewOnlyAccount {
         let missing_base_amount =
             calculate_missing_base_amount(tb, base_amount, used_base_amount, provider).await?;
 
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("missing_base_amount", missing_base_amount);
+
         if missing_base_amount > 0 {
             let new_base_inputs = self
                 .get_asset_inputs_for_amount(
@@ -145,6 +161,9 @@ pub trait Account: ViewOnlyAccount {
                 )
                 .await?;
 
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("new_inputs", new_base_inputs.len());
+
             adjust_inputs_outputs(
                 tb,
                 new_base_inputs,
@@ -161,22 +180,25 @@ pub trait Account: ViewOnlyAccount {
         Ok(())
     }
 
-    /// Transfer funds from this account to another `Address`.
+    /// Transfer funds from this account to another `Address`, given as any of the types
+    /// convertible into a `Bech32Address`, e.g. an `Address` or a string in one of the
+    /// `AddressFormat`s.
     /// Fails if amount for asset ID is larger than address's spendable coins.
     /// Returns the transaction ID that was sent and the list of receipts.
     async fn transfer(
         &self,
-        to: &Bech32Address,
+        to: impl TryInto<Bech32Address, Error: Into<Error>> + Send,
         amount: u64,
         asset_id: AssetId,
         tx_policies: TxPolicies,
     ) -> Result<(TxId, Vec<Receipt>)> {
+        let to: Bech32Address = to.try_into().map_err(Into::<Error>::into)?;
         let provider = self.try_provider()?;
 
         let inputs = self
             .get_asset_inputs_for_amount(asset_id, amount, None)
             .await?;
-        let outputs = self.get_asset_outputs_for_amount(to, asset_id, amount);
+        let outputs = self.get_asset_outputs_for_amount(&to, asset_id, amount);
 
         let mut tx_builder =
             ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
@@ -202,7 +224,7 @@ pub trait Account: ViewOnlyAccount {
     }
 
     /// Unconditionally transfers `balance` of type `asset_id` to
-    /// the contract at `to`.
+    /// the contract at `to`, given as any of the types convertible into a `Bech32ContractId`.
     /// Fails if balance for `asset_id` is larger than this account's spendable balance.
     /// Returns the corresponding transaction ID and the list of receipts.
     ///
@@ -212,11 +234,12 @@ pub trait Account: ViewOnlyAccount {
     /// to the PERMANENT LOSS OF COINS if not used with care.
     async fn force_transfer_to_contract(
         &self,
-        to: &Bech32ContractId,
+        to: impl TryInto<Bech32ContractId, Error: Into<Error>> + Send,
         balance: u64,
         asset_id: AssetId,
         tx_policies: TxPolicies,
     ) -> Result<(String, Vec<Receipt>)> {
+        let to: Bech32ContractId = to.try_into().map_err(Into::<Error>::into)?;
         let provider = self.try_provider()?;
 
         let zeroes = Bytes32::zeroed();
@@ -264,14 +287,16 @@ pub trait Account: ViewOnlyAccount {
     }
 
     /// Withdraws an amount of the base asset to
-    /// an address on the base chain.
+    /// an address on the base chain, given as any of the types convertible into a
+    /// `Bech32Address`.
     /// Returns the transaction ID, message ID and the list of receipts.
     async fn withdraw_to_base_layer(
         &self,
-        to: &Bech32Address,
+        to: impl TryInto<Bech32Address, Error: Into<Error>> + Send,
         amount: u64,
         tx_policies: TxPolicies,
     ) -> Result<(TxId, Nonce, Vec<Receipt>)> {
+        let to: Bech32Address = to.try_into().map_err(Into::<Error>::into)?;
         let provider = self.try_provider()?;
 
         let inputs = self
@@ -56,7 +56,8 @@ pub fn available_base_assets_and_amount(
                         sum += amount;
                         Some(resource.id())
                     }
-                    CoinType::Message(message) => {
+                    // messages carrying data can't be used to pay for the transaction
+                    CoinType::Message(message) if message.data.is_empty() => {
                         sum += message.amount;
                         Some(resource.id())
                     }
@@ -1,24 +1,388 @@
 use std::{
     collections::{HashMap, HashSet},
+    fmt::Debug,
     hash::{Hash, Hasher},
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
 };
 
-use fuel_types::AssetId;
-use fuels_core::types::{bech32::Bech32Address, coin_type_id::CoinTypeId};
-use tokio::time::{Duration, Instant};
+use async_trait::async_trait;
+use fuel_tx::{TxId, UtxoId};
+use fuel_types::{Address, AssetId, Nonce};
+use fuels_core::types::{bech32::Bech32Address, coin_type_id::CoinTypeId, errors::Result};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::json_file_store::JsonFileStore;
+
+pub type CoinCacheKey = (Bech32Address, AssetId);
+
+pub const DEFAULT_RESERVATION_TTL: Duration = Duration::from_secs(30);
+
+/// Keeps track of coins that were used by submitted transactions so that they are not selected
+/// again while the transaction is pending.
+///
+/// The `Provider` consults the store before selecting coins and updates it whenever it submits a
+/// transaction or learns that one failed. Implementations sharing their state between processes
+/// (e.g. [`FileCoinReservations`]) allow several senders spending from the same wallet to avoid
+/// selecting the same coins.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait CoinReservationStore: Debug + Send + Sync {
+    /// Marks `coins` as used by the transaction with the id `tx_id`.
+    async fn reserve(
+        &self,
+        tx_id: TxId,
+        coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>,
+    ) -> Result<()>;
+
+    /// Returns the coins of the given owner and asset that are currently reserved.
+    async fn reserved(&self, key: &CoinCacheKey) -> Result<HashSet<CoinTypeId>>;
+
+    /// Releases the given coins so they can be selected again.
+    async fn release(&self, coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>) -> Result<()>;
+
+    /// Releases all coins reserved by the transaction with the id `tx_id`. Returns the number of
+    /// coins released.
+    async fn release_tx(&self, tx_id: &TxId) -> Result<u64>;
+}
+
+/// Counters describing how the coin reservations were used by a `Provider`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CoinReservationMetrics {
+    /// Number of coin selections that consulted the reservations.
+    pub lookups: u64,
+    /// Number of coins excluded from a coin selection because they were reserved.
+    pub hits: u64,
+    /// Number of coins reserved by submitted transactions.
+    pub reserved: u64,
+    /// Number of coins explicitly released.
+    pub released: u64,
+    /// Number of reservation store operations that failed. Failures after a transaction was
+    /// submitted are not returned to the caller, they are only counted here.
+    pub store_errors: u64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CoinReservationCounters {
+    lookups: AtomicU64,
+    hits: AtomicU64,
+    reserved: AtomicU64,
+    released: AtomicU64,
+    store_errors: AtomicU64,
+}
+
+impl CoinReservationCounters {
+    pub fn record_lookup(&self, hits: usize) {
+        self.lookups.fetch_add(1, Ordering::Relaxed);
+        self.hits.fetch_add(hits as u64, Ordering::Relaxed);
+    }
+
+    pub fn record_reserved(&self, coins: &HashMap<CoinCacheKey, Vec<CoinTypeId>>) {
+        self.reserved
+            .fetch_add(count_coins(coins), Ordering::Relaxed);
+    }
+
+    pub fn record_released(&self, coins: &HashMap<CoinCacheKey, Vec<CoinTypeId>>) {
+        self.record_released_count(count_coins(coins));
+    }
+
+    pub fn record_released_count(&self, count: u64) {
+        self.released.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub fn record_store_error(&self) {
+        self.store_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> CoinReservationMetrics {
+        CoinReservationMetrics {
+            lookups: self.lookups.load(Ordering::Relaxed),
+            hits: self.hits.load(Ordering::Relaxed),
+            reserved: self.reserved.load(Ordering::Relaxed),
+            released: self.released.load(Ordering::Relaxed),
+            store_errors: self.store_errors.load(Ordering::Relaxed),
+        }
+    }
+}
 
-type CoinCacheKey = (Bech32Address, AssetId);
+fn count_coins(coins: &HashMap<CoinCacheKey, Vec<CoinTypeId>>) -> u64 {
+    coins.values().map(|ids| ids.len() as u64).sum()
+}
 
+/// The default [`CoinReservationStore`]. Reservations live in memory and are only shared between
+/// clones of the same `Provider`.
 #[derive(Debug)]
-pub(crate) struct CoinsCache {
-    ttl: Duration,
-    items: HashMap<CoinCacheKey, HashSet<CoinCacheItem>>,
+pub struct InMemoryCoinReservations {
+    inner: Mutex<InMemoryState>,
 }
 
-impl Default for CoinsCache {
+#[derive(Debug)]
+struct InMemoryState {
+    cache: CoinsCache,
+    by_tx: HashMap<TxId, (Instant, HashMap<CoinCacheKey, Vec<CoinTypeId>>)>,
+}
+
+impl InMemoryCoinReservations {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            inner: Mutex::new(InMemoryState {
+                cache: CoinsCache::new(ttl),
+                by_tx: HashMap::default(),
+            }),
+        }
+    }
+}
+
+impl Default for InMemoryCoinReservations {
     fn default() -> Self {
-        Self::new(Duration::from_secs(30))
+        Self::new(DEFAULT_RESERVATION_TTL)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl CoinReservationStore for InMemoryCoinReservations {
+    async fn reserve(
+        &self,
+        tx_id: TxId,
+        coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>,
+    ) -> Result<()> {
+        let mut state = self.inner.lock().await;
+
+        let ttl = state.cache.ttl;
+        state
+            .by_tx
+            .retain(|_, (created_at, _)| *created_at + ttl > Instant::now());
+
+        state.cache.insert_multiple(coins.clone());
+        state.by_tx.insert(tx_id, (Instant::now(), coins));
+
+        Ok(())
+    }
+
+    async fn reserved(&self, key: &CoinCacheKey) -> Result<HashSet<CoinTypeId>> {
+        Ok(self.inner.lock().await.cache.get_active(key))
     }
+
+    async fn release(&self, coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>) -> Result<()> {
+        self.inner.lock().await.cache.remove_items(coins);
+
+        Ok(())
+    }
+
+    async fn release_tx(&self, tx_id: &TxId) -> Result<u64> {
+        let mut state = self.inner.lock().await;
+        let Some((_, coins)) = state.by_tx.remove(tx_id) else {
+            return Ok(0);
+        };
+
+        let released = count_coins(&coins);
+        state.cache.remove_items(coins);
+
+        Ok(released)
+    }
+}
+
+/// A [`CoinReservationStore`] persisting reservations in a JSON file so that several processes
+/// (or several `Provider` instances) spending from the same wallet do not select the same coins.
+///
+/// Updates of the file are serialized through a lockfile created next to it (`<path>.lock`)
+/// holding a token unique to its owner. Reads don't take the lock, unless they find expired
+/// reservations to drop. A lockfile older than `stale_lock_timeout` is assumed to be left
+/// over by a crashed process and is removed, after checking that its token did not change, i.e.
+/// that it wasn't replaced by a live lock in the meantime. The file is accessed on tokio's
+/// blocking thread pool.
+#[derive(Debug, Clone)]
+pub struct FileCoinReservations {
+    file: JsonFileStore,
+    ttl: Duration,
+}
+
+impl FileCoinReservations {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            file: JsonFileStore::new(path, "coin reservations"),
+            ttl: DEFAULT_RESERVATION_TTL,
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn with_stale_lock_timeout(mut self, stale_lock_timeout: Duration) -> Self {
+        self.file = self.file.with_stale_lock_timeout(stale_lock_timeout);
+        self
+    }
+
+    pub fn with_lock_wait_timeout(mut self, lock_wait_timeout: Duration) -> Self {
+        self.file = self.file.with_lock_wait_timeout(lock_wait_timeout);
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        self.file.path()
+    }
+
+    async fn with_records<T: Send + 'static>(
+        &self,
+        update: impl FnOnce(&mut Vec<ReservationRecord>) -> T + Send + 'static,
+    ) -> Result<T> {
+        self.file
+            .run(move |file| {
+                file.update(|records| {
+                    drop_expired(records);
+                    update(records)
+                })
+            })
+            .await
+    }
+
+    // The file is only ever replaced by a rename, so it can be read without the lock. The lock is
+    // only taken, and the file rewritten, if there are expired records to drop.
+    async fn read_records_with<T: Send + 'static>(
+        &self,
+        read: impl FnOnce(&[ReservationRecord]) -> T + Send + 'static,
+    ) -> Result<T> {
+        self.file
+            .run(move |file| {
+                let now = unix_millis(SystemTime::now());
+                let records: Vec<ReservationRecord> = file.read()?;
+
+                if records.iter().any(|record| record.expires_at_ms <= now) {
+                    return file.update(|records| {
+                        drop_expired(records);
+                        read(records)
+                    });
+                }
+
+                Ok(read(&records))
+            })
+            .await
+    }
+}
+
+fn drop_expired(records: &mut Vec<ReservationRecord>) {
+    let now = unix_millis(SystemTime::now());
+    records.retain(|record| record.expires_at_ms > now);
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl CoinReservationStore for FileCoinReservations {
+    async fn reserve(
+        &self,
+        tx_id: TxId,
+        coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>,
+    ) -> Result<()> {
+        let expires_at_ms = unix_millis(SystemTime::now() + self.ttl);
+
+        self.with_records(move |records| {
+            let new_records = coins.into_iter().flat_map(|((owner, asset_id), ids)| {
+                let owner = Address::from(&owner);
+                ids.into_iter().map(move |id| ReservationRecord {
+                    tx_id,
+                    owner,
+                    asset_id,
+                    coin: id.into(),
+                    expires_at_ms,
+                })
+            });
+
+            records.extend(new_records);
+        })
+        .await
+    }
+
+    async fn reserved(&self, key: &CoinCacheKey) -> Result<HashSet<CoinTypeId>> {
+        let (owner, asset_id) = key;
+        let (owner, asset_id) = (Address::from(owner), *asset_id);
+
+        self.read_records_with(move |records| {
+            records
+                .iter()
+                .filter(|record| record.owner == owner && record.asset_id == asset_id)
+                .map(|record| record.coin.clone().into())
+                .collect()
+        })
+        .await
+    }
+
+    async fn release(&self, coins: HashMap<CoinCacheKey, Vec<CoinTypeId>>) -> Result<()> {
+        let to_release: HashSet<_> = coins
+            .into_iter()
+            .flat_map(|((owner, asset_id), ids)| {
+                let owner = Address::from(&owner);
+                ids.into_iter().map(move |id| (owner, asset_id, id))
+            })
+            .collect();
+
+        self.with_records(move |records| {
+            records.retain(|record| {
+                !to_release.contains(&(record.owner, record.asset_id, record.coin.clone().into()))
+            })
+        })
+        .await
+    }
+
+    async fn release_tx(&self, tx_id: &TxId) -> Result<u64> {
+        let tx_id = *tx_id;
+        self.with_records(move |records| {
+            let before = records.len();
+            records.retain(|record| record.tx_id != tx_id);
+
+            (before - records.len()) as u64
+        })
+        .await
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReservationRecord {
+    tx_id: TxId,
+    owner: Address,
+    asset_id: AssetId,
+    coin: StoredCoinId,
+    expires_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum StoredCoinId {
+    Utxo(UtxoId),
+    Message(Nonce),
+}
+
+impl From<CoinTypeId> for StoredCoinId {
+    fn from(id: CoinTypeId) -> Self {
+        match id {
+            CoinTypeId::UtxoId(utxo_id) => Self::Utxo(utxo_id),
+            CoinTypeId::Nonce(nonce) => Self::Message(nonce),
+        }
+    }
+}
+
+impl From<StoredCoinId> for CoinTypeId {
+    fn from(id: StoredCoinId) -> Self {
+        match id {
+            StoredCoinId::Utxo(utxo_id) => Self::UtxoId(utxo_id),
+            StoredCoinId::Message(nonce) => Self::Nonce(nonce),
+        }
+    }
+}
+
+fn unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+#[derive(Debug)]
+pub(crate) struct CoinsCache {
+    ttl: Duration,
+    items: HashMap<CoinCacheKey, HashSet<CoinCacheItem>>,
 }
 
 impl CoinsCache {
@@ -111,8 +475,7 @@ impl CoinCacheItem {
 
 #[cfg(test)]
 mod tests {
-    use fuel_tx::UtxoId;
-    use fuel_types::{Bytes32, Nonce};
+    use fuel_types::Bytes32;
 
     use super::*;
 
@@ -194,4 +557,178 @@ mod tests {
         assert!(!active_coins.contains(&item1));
         assert!(active_coins.contains(&item2));
     }
+
+    fn reservation(
+        key: &CoinCacheKey,
+        ids: Vec<CoinTypeId>,
+    ) -> HashMap<CoinCacheKey, Vec<CoinTypeId>> {
+        HashMap::from([(key.clone(), ids)])
+    }
+
+    #[tokio::test]
+    async fn in_memory_store_releases_coins_of_a_tx() -> Result<()> {
+        let store = InMemoryCoinReservations::default();
+
+        let key: CoinCacheKey = Default::default();
+        let (item1, item2) = get_items();
+        let (tx1, tx2) = (TxId::from([1; 32]), TxId::from([2; 32]));
+
+        store
+            .reserve(tx1, reservation(&key, vec![item1.clone()]))
+            .await?;
+        store
+            .reserve(tx2, reservation(&key, vec![item2.clone()]))
+            .await?;
+
+        let released = store.release_tx(&tx1).await?;
+
+        assert_eq!(released, 1);
+        assert_eq!(store.reserved(&key).await?, HashSet::from([item2]));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_is_shared_between_instances() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("reservations.json");
+        let store_a = FileCoinReservations::new(&path);
+        let store_b = FileCoinReservations::new(&path);
+
+        let key: CoinCacheKey = Default::default();
+        let (item1, item2) = get_items();
+        let (tx1, tx2) = (TxId::from([1; 32]), TxId::from([2; 32]));
+
+        store_a
+            .reserve(tx1, reservation(&key, vec![item1.clone()]))
+            .await?;
+        store_b
+            .reserve(tx2, reservation(&key, vec![item2.clone()]))
+            .await?;
+
+        assert_eq!(
+            store_a.reserved(&key).await?,
+            HashSet::from([item1.clone(), item2.clone()])
+        );
+
+        store_b
+            .release(reservation(&key, vec![item2.clone()]))
+            .await?;
+        assert_eq!(store_a.reserved(&key).await?, HashSet::from([item1]));
+
+        assert_eq!(store_a.release_tx(&tx1).await?, 1);
+        assert!(store_b.reserved(&key).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_drops_expired_reservations() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let store = FileCoinReservations::new(dir.path().join("reservations.json"))
+            .with_ttl(Duration::ZERO);
+
+        let key: CoinCacheKey = Default::default();
+        let (item1, _) = get_items();
+        store
+            .reserve(TxId::zeroed(), reservation(&key, vec![item1]))
+            .await?;
+
+        assert!(store.reserved(&key).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_reads_without_rewriting_the_file() -> Result<()> {
+        // given
+        let dir = tempfile::tempdir()?;
+        let store = FileCoinReservations::new(dir.path().join("reservations.json"));
+        let key: CoinCacheKey = Default::default();
+        let (item1, _) = get_items();
+        store
+            .reserve(TxId::zeroed(), reservation(&key, vec![item1.clone()]))
+            .await?;
+        let written_at = std::fs::metadata(store.path())?.modified()?;
+
+        // when
+        std::fs::write(store.file.lock_path(), "")?;
+        let reserved = store.reserved(&key).await?;
+
+        // then
+        assert_eq!(reserved, HashSet::from([item1]));
+        assert_eq!(std::fs::metadata(store.path())?.modified()?, written_at);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_removes_stale_lock() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let store = FileCoinReservations::new(dir.path().join("reservations.json"))
+            .with_stale_lock_timeout(Duration::ZERO)
+            .with_lock_wait_timeout(Duration::from_millis(100));
+        std::fs::write(store.file.lock_path(), "")?;
+
+        let released = store.release_tx(&TxId::zeroed()).await?;
+
+        assert_eq!(released, 0);
+        assert!(!store.file.lock_path().exists());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_does_not_remove_lock_taken_over_by_another_owner() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let store = FileCoinReservations::new(dir.path().join("reservations.json"));
+
+        let lock = store.file.acquire_lock()?;
+        std::fs::write(store.file.lock_path(), "another owner")?;
+        drop(lock);
+
+        assert!(store.file.lock_path().exists());
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn file_store_serializes_concurrent_updates() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("reservations.json");
+        let key: CoinCacheKey = Default::default();
+
+        let tasks = (0..20u8).map(|i| {
+            let store = FileCoinReservations::new(&path);
+            let item = CoinTypeId::UtxoId(UtxoId::new(TxId::from([i; 32]), 0));
+            let coins = reservation(&key, vec![item]);
+            tokio::spawn(async move { store.reserve(TxId::from([i; 32]), coins).await })
+        });
+        for result in futures::future::join_all(tasks).await {
+            result.expect("task should not panic")?;
+        }
+
+        let reserved = FileCoinReservations::new(&path).reserved(&key).await?;
+
+        assert_eq!(reserved.len(), 20);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_times_out_on_held_lock() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let store = FileCoinReservations::new(dir.path().join("reservations.json"))
+            .with_lock_wait_timeout(Duration::from_millis(50));
+        std::fs::write(store.file.lock_path(), "")?;
+
+        let err = store
+            .release_tx(&TxId::zeroed())
+            .await
+            .expect_err("should have timed out waiting for the lock");
+
+        assert!(err.to_string().contains("timed out waiting"));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,421 @@
+use std::{collections::VecDeque, num::NonZeroUsize, sync::Mutex};
+
+use async_trait::async_trait;
+use fuel_tx::{Output, Receipt, TxId, UtxoId};
+use fuel_types::AssetId;
+use fuels_core::types::{
+    bech32::Bech32Address,
+    coin::{Coin, CoinStatus},
+    coin_type::CoinType,
+    errors::{error, Result},
+    input::Input,
+    transaction::{Transaction, TransactionType},
+    transaction_builders::{BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder},
+    tx_status::TxStatus,
+};
+
+use crate::Account;
+
+/// Something that can be turned into a script transaction and sent by a [`TxDispatcher`].
+///
+/// The dispatcher takes over funding the base asset: base asset inputs owned by the dispatching
+/// account are replaced with coins from the lane the item was assigned to.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait Dispatchable: Send {
+    type Output: Send;
+
+    /// Called once, right before the item is sent.
+    async fn transaction_builder(&mut self) -> Result<ScriptTransactionBuilder>;
+
+    /// Converts the final status of the sent transaction into the item's result.
+    fn into_output(self, tx_id: TxId, tx_status: TxStatus) -> Result<Self::Output>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Dispatchable for ScriptTransactionBuilder {
+    type Output = (TxId, Vec<Receipt>);
+
+    async fn transaction_builder(&mut self) -> Result<ScriptTransactionBuilder> {
+        Ok(std::mem::take(self))
+    }
+
+    fn into_output(self, tx_id: TxId, tx_status: TxStatus) -> Result<Self::Output> {
+        Ok((tx_id, tx_status.take_receipts_checked(None)?))
+    }
+}
+
+const DEFAULT_LANES: NonZeroUsize = match NonZeroUsize::new(4) {
+    Some(lanes) => lanes,
+    None => unreachable!(),
+};
+
+/// Sends many transactions from one account concurrently without coin conflicts.
+///
+/// The account's base asset resources are partitioned into independent lanes. Every lane sends
+/// its transactions one after another, spending the change output of the previous transaction in
+/// the next one, while all lanes run concurrently. The number of lanes therefore bounds the
+/// number of transactions in flight.
+#[derive(Debug, Clone)]
+pub struct TxDispatcher<A> {
+    account: A,
+    lanes: NonZeroUsize,
+}
+
+impl<A: Account> TxDispatcher<A> {
+    pub fn new(account: A) -> Self {
+        Self {
+            account,
+            lanes: DEFAULT_LANES,
+        }
+    }
+
+    /// Sets the maximum number of lanes, i.e. transactions in flight at the same time.
+    /// Fewer lanes are used if the account doesn't own enough resources to fill them.
+    pub fn with_lanes(mut self, lanes: NonZeroUsize) -> Self {
+        self.lanes = lanes;
+        self
+    }
+
+    pub fn account(&self) -> &A {
+        &self.account
+    }
+
+    /// Sends all `items` and returns their results in the order the items were given.
+    /// A failing item doesn't stop the others. Fails only if the account's resources could not
+    /// be partitioned into lanes.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(items = tracing::field::Empty), err)
+    )]
+    pub async fn dispatch<D: Dispatchable>(
+        &self,
+        items: impl IntoIterator<Item = D>,
+    ) -> Result<Vec<Result<D::Output>>> {
+        let queue: VecDeque<_> = items.into_iter().enumerate().collect();
+        let num_items = queue.len();
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("items", num_items);
+        if num_items == 0 {
+            return Ok(vec![]);
+        }
+
+        let lanes = self.partition_resources(num_items).await?;
+        let queue = Mutex::new(queue);
+
+        let lane_results = futures::future::join_all(
+            lanes
+                .into_iter()
+                .enumerate()
+                .map(|(lane, inputs)| self.run_lane(lane, inputs, &queue)),
+        )
+        .await;
+
+        let mut results: Vec<Option<Result<D::Output>>> =
+            std::iter::repeat_with(|| None).take(num_items).collect();
+        for (index, result) in lane_results.into_iter().flatten() {
+            results[index] = Some(result);
+        }
+
+        // items left in the queue were not picked up because every lane ran dry
+        let leftover = queue.into_inner().expect("queue lock poisoned");
+        for (index, _) in leftover {
+            results[index] = Some(Err(error!(
+                Other,
+                "no lane has spendable base asset resources left to send item {index}"
+            )));
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|result| result.expect("every item is either sent or left in the queue"))
+            .collect())
+    }
+
+    async fn partition_resources(&self, num_items: usize) -> Result<Vec<Vec<Input>>> {
+        let provider = self.account.try_provider()?;
+        let base_asset_id = *provider.base_asset_id();
+
+        let balance = self.account.get_asset_balance(&base_asset_id).await?;
+        let mut inputs = self
+            .account
+            .get_asset_inputs_for_amount(base_asset_id, balance, None)
+            .await?;
+        if inputs.is_empty() {
+            return Err(error!(
+                Other,
+                "account `{}` has no base asset resources to dispatch from",
+                self.account.address()
+            ));
+        }
+
+        inputs.sort_by_key(|input| std::cmp::Reverse(input.amount().unwrap_or_default()));
+
+        let num_lanes = self.lanes.get().min(num_items).min(inputs.len());
+        let mut lanes = vec![vec![]; num_lanes];
+        for (i, input) in inputs.into_iter().enumerate() {
+            lanes[i % num_lanes].push(input);
+        }
+
+        Ok(lanes)
+    }
+
+    async fn run_lane<D: Dispatchable>(
+        &self,
+        lane: usize,
+        mut inputs: Vec<Input>,
+        queue: &Mutex<VecDeque<(usize, D)>>,
+    ) -> Vec<(usize, Result<D::Output>)> {
+        let mut results = vec![];
+
+        while !inputs.is_empty() {
+            let Some((index, item)) = queue.lock().expect("queue lock poisoned").pop_front() else {
+                break;
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(lane, index, "dispatching item");
+            #[cfg(not(feature = "tracing"))]
+            let _ = lane;
+
+            let (result, remaining_inputs) = self.send_item(item, inputs).await;
+            inputs = remaining_inputs;
+            results.push((index, result));
+        }
+
+        results
+    }
+
+    /// Sends `item` funded by `inputs`. Returns the item's result along with the inputs the lane
+    /// can keep spending from.
+    async fn send_item<D: Dispatchable>(
+        &self,
+        mut item: D,
+        inputs: Vec<Input>,
+    ) -> (Result<D::Output>, Vec<Input>) {
+        let sent = match self.build_and_send(&mut item, &inputs).await {
+            Ok(sent) => sent,
+            Err(err) => return (Err(err), inputs),
+        };
+        let (tx_id, change_index, tx_status) = sent;
+
+        let consumed_inputs = !matches!(tx_status, TxStatus::SqueezedOut { .. });
+        let remaining_inputs = if consumed_inputs {
+            match self.change_input(&inputs[0], tx_id, change_index).await {
+                Ok(change) => change.into_iter().collect(),
+                Err(err) => return (Err(err), vec![]),
+            }
+        } else {
+            inputs
+        };
+
+        (item.into_output(tx_id, tx_status), remaining_inputs)
+    }
+
+    async fn build_and_send<D: Dispatchable>(
+        &self,
+        item: &mut D,
+        inputs: &[Input],
+    ) -> Result<(TxId, u16, TxStatus)> {
+        let provider = self.account.try_provider()?;
+        let base_asset_id = *provider.base_asset_id();
+        let address = self.account.address();
+
+        let mut tb = item.transaction_builder().await?;
+
+        tb.inputs_mut()
+            .retain(|input| !is_base_resource_of(input, address, &base_asset_id));
+        tb.inputs_mut().extend(inputs.iter().cloned());
+
+        let change_index = match find_change_output(tb.outputs(), address, &base_asset_id) {
+            Some(index) => index,
+            None => {
+                tb.outputs_mut()
+                    .push(Output::change(address.into(), 0, base_asset_id));
+                tb.outputs().len() - 1
+            }
+        };
+        let change_index = u16::try_from(change_index)
+            .map_err(|_| error!(Other, "too many outputs in dispatched transaction"))?;
+
+        self.account.add_witnesses(&mut tb)?;
+
+        let tx = tb.build(provider).await?;
+        let tx_id = tx.id(provider.chain_id());
+
+        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+
+        Ok((tx_id, change_index, tx_status))
+    }
+
+    /// Returns the change coin produced by `tx_id` as an input spendable the same way as
+    /// `template`. There is no change coin if everything was spent.
+    ///
+    /// The amount is only known once the transaction is executed, so it is read from the change
+    /// output of the committed transaction rather than looked up among the account's coins.
+    async fn change_input(
+        &self,
+        template: &Input,
+        tx_id: TxId,
+        change_index: u16,
+    ) -> Result<Option<Input>> {
+        let provider = self.account.try_provider()?;
+        let response = provider
+            .get_transaction_by_id(&tx_id)
+            .await?
+            .ok_or_else(|| error!(Provider, "transaction `{tx_id}` not found"))?;
+
+        let TransactionType::Script(tx) = response.transaction else {
+            return Err(error!(
+                Other,
+                "dispatched transaction `{tx_id}` is not a script"
+            ));
+        };
+        let Some(&Output::Change {
+            amount, asset_id, ..
+        }) = tx.outputs().get(usize::from(change_index))
+        else {
+            return Err(error!(
+                Other,
+                "dispatched transaction `{tx_id}` has no change output at index {change_index}"
+            ));
+        };
+        if amount == 0 {
+            return Ok(None);
+        }
+
+        let resource = CoinType::Coin(Coin {
+            amount,
+            block_created: response.block_height.map(u32::from).unwrap_or_default(),
+            asset_id,
+            utxo_id: UtxoId::new(tx_id, change_index),
+            owner: self.account.address().clone(),
+            status: CoinStatus::Unspent,
+        });
+
+        Ok(Some(match template {
+            Input::ResourcePredicate { code, data, .. } => {
+                Input::resource_predicate(resource, code.clone(), data.clone())
+            }
+            _ => Input::resource_signed(resource),
+        }))
+    }
+}
+
+fn is_base_resource_of(input: &Input, owner: &Bech32Address, base_asset_id: &AssetId) -> bool {
+    match input {
+        Input::ResourceSigned { resource } | Input::ResourcePredicate { resource, .. } => {
+            let is_base = match resource {
+                CoinType::Coin(coin) => coin.asset_id == *base_asset_id,
+                CoinType::Message(message) => message.data.is_empty(),
+            };
+
+            is_base && resource.owner() == owner
+        }
+        Input::Contract { .. } => false,
+    }
+}
+
+fn find_change_output(
+    outputs: &[Output],
+    owner: &Bech32Address,
+    base_asset_id: &AssetId,
+) -> Option<usize> {
+    outputs.iter().position(|output| {
+        matches!(output, Output::Change { to, asset_id, .. }
+            if *to == owner.into() && asset_id == base_asset_id)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::Address;
+    use fuels_core::types::message::Message;
+
+    use super::*;
+
+    fn coin(owner: &Bech32Address, asset_id: AssetId) -> CoinType {
+        CoinType::Coin(Coin {
+            amount: 1,
+            asset_id,
+            utxo_id: UtxoId::default(),
+            owner: owner.clone(),
+            ..Default::default()
+        })
+    }
+
+    fn message(recipient: &Bech32Address, data: Vec<u8>) -> CoinType {
+        CoinType::Message(Message {
+            amount: 1,
+            sender: Bech32Address::default(),
+            recipient: recipient.clone(),
+            nonce: Default::default(),
+            data,
+            da_height: 0,
+            status: Default::default(),
+        })
+    }
+
+    #[test]
+    fn only_base_resources_of_the_account_are_replaced() {
+        let owner = Bech32Address::default();
+        let other = Bech32Address::from(Address::from([1; 32]));
+        let base_asset_id = AssetId::zeroed();
+        let other_asset_id = AssetId::from([2; 32]);
+
+        let data_message = message(&owner, vec![1]);
+        let base_message = message(&owner, vec![]);
+
+        assert!(is_base_resource_of(
+            &Input::resource_signed(coin(&owner, base_asset_id)),
+            &owner,
+            &base_asset_id
+        ));
+        assert!(is_base_resource_of(
+            &Input::resource_predicate(coin(&owner, base_asset_id), vec![], vec![]),
+            &owner,
+            &base_asset_id
+        ));
+        assert!(is_base_resource_of(
+            &Input::resource_signed(base_message),
+            &owner,
+            &base_asset_id
+        ));
+        assert!(!is_base_resource_of(
+            &Input::resource_signed(data_message),
+            &owner,
+            &base_asset_id
+        ));
+        assert!(!is_base_resource_of(
+            &Input::resource_signed(coin(&owner, other_asset_id)),
+            &owner,
+            &base_asset_id
+        ));
+        assert!(!is_base_resource_of(
+            &Input::resource_signed(coin(&other, base_asset_id)),
+            &owner,
+            &base_asset_id
+        ));
+    }
+
+    #[test]
+    fn finds_base_change_output_of_the_account() {
+        let owner = Bech32Address::default();
+        let base_asset_id = AssetId::zeroed();
+        let other_asset_id = AssetId::from([2; 32]);
+
+        let outputs = vec![
+            Output::coin(owner.clone().into(), 10, base_asset_id),
+            Output::change(owner.clone().into(), 0, other_asset_id),
+            Output::change(owner.clone().into(), 0, base_asset_id),
+        ];
+
+        assert_eq!(
+            find_change_output(&outputs, &owner, &base_asset_id),
+            Some(2)
+        );
+        assert_eq!(
+            find_change_output(&outputs[..2], &owner, &base_asset_id),
+            None
+        );
+    }
+}
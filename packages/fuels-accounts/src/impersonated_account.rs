@@ -20,8 +20,11 @@ pub struct ImpersonatedAccount {
 }
 
 impl ImpersonatedAccount {
-    pub fn new(address: Bech32Address, provider: Option<Provider>) -> Self {
-        Self { address, provider }
+    pub fn new(address: impl Into<Bech32Address>, provider: Option<Provider>) -> Self {
+        Self {
+            address: address.into(),
+            provider,
+        }
     }
 
     pub fn address(&self) -> &Bech32Address {
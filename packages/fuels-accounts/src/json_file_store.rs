@@ -0,0 +1,224 @@
+use std::{
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use fuels_core::types::errors::{error, Result};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A list of records kept as JSON in a file that several processes can share.
+///
+/// Writers take a lockfile (`<path>.lock`) and replace the file with a rename, so readers never
+/// see a partial write and need no lock. A lockfile older than `stale_lock_timeout` is taken to
+/// be left over by a crashed process. All file access is blocking, see [`JsonFileStore::run`].
+#[derive(Debug, Clone)]
+pub(crate) struct JsonFileStore {
+    path: PathBuf,
+    // what the records are, e.g. "coin reservations", used in error messages
+    description: &'static str,
+    stale_lock_timeout: Duration,
+    lock_wait_timeout: Duration,
+}
+
+impl JsonFileStore {
+    pub fn new(path: impl AsRef<Path>, description: &'static str) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            description,
+            stale_lock_timeout: Duration::from_secs(10),
+            lock_wait_timeout: Duration::from_secs(5),
+        }
+    }
+
+    pub fn with_stale_lock_timeout(mut self, stale_lock_timeout: Duration) -> Self {
+        self.stale_lock_timeout = stale_lock_timeout;
+        self
+    }
+
+    pub fn with_lock_wait_timeout(mut self, lock_wait_timeout: Duration) -> Self {
+        self.lock_wait_timeout = lock_wait_timeout;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn lock_path(&self) -> PathBuf {
+        let mut lock_path = self.path.clone().into_os_string();
+        lock_path.push(".lock");
+
+        lock_path.into()
+    }
+
+    /// Runs `f`, which accesses the store with blocking calls, on tokio's blocking thread pool.
+    pub async fn run<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&Self) -> Result<T> + Send + 'static,
+    ) -> Result<T> {
+        let store = self.clone();
+        let description = self.description;
+
+        tokio::task::spawn_blocking(move || f(&store))
+            .await
+            .map_err(|e| error!(Other, "{description} task failed: {e}"))?
+    }
+
+    /// Reads the records without taking the lock. A missing or empty file holds no records.
+    pub fn read<R: DeserializeOwned>(&self) -> Result<Vec<R>> {
+        let contents = match std::fs::read(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => {
+                return Err(error!(
+                    IO,
+                    "failed to read {} from {:?}: {e}", self.description, self.path
+                ))
+            }
+        };
+
+        if contents.is_empty() {
+            return Ok(vec![]);
+        }
+
+        serde_json::from_slice(&contents).map_err(|e| {
+            error!(
+                Other,
+                "failed to parse {} from {:?}: {e}", self.description, self.path
+            )
+        })
+    }
+
+    /// Applies `update` to the records and writes them back, holding the lock throughout.
+    pub fn update<R: Serialize + DeserializeOwned, T>(
+        &self,
+        update: impl FnOnce(&mut Vec<R>) -> T,
+    ) -> Result<T> {
+        let _lock = self.acquire_lock()?;
+
+        let mut records = self.read()?;
+        let result = update(&mut records);
+        self.write(&records)?;
+
+        Ok(result)
+    }
+
+    fn write<R: Serialize>(&self, records: &[R]) -> Result<()> {
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+
+        std::fs::write(&tmp_path, serde_json::to_vec(records)?)?;
+        std::fs::rename(&tmp_path, &self.path)?;
+
+        Ok(())
+    }
+
+    pub fn acquire_lock(&self) -> Result<FileLock> {
+        let lock_path = self.lock_path();
+        let token = lock_token();
+        let started = std::time::Instant::now();
+
+        loop {
+            match std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&lock_path)
+            {
+                Ok(mut file) => {
+                    let lock = FileLock {
+                        path: lock_path,
+                        token,
+                    };
+                    file.write_all(lock.token.as_bytes())?;
+
+                    return Ok(lock);
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    if self.remove_stale_lock(&lock_path, &token) {
+                        continue;
+                    }
+                }
+                Err(e) => {
+                    return Err(error!(
+                        IO,
+                        "failed to create {} lockfile {lock_path:?}: {e}", self.description
+                    ))
+                }
+            }
+
+            if started.elapsed() > self.lock_wait_timeout {
+                return Err(error!(
+                    IO,
+                    "timed out waiting for {} lockfile {lock_path:?}", self.description
+                ));
+            }
+
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    // Removes the lockfile if it is stale. The lockfile is first moved out of the way and its
+    // token compared to the one seen stale, so that a lock taken by another process in the
+    // meantime is handed back instead of being removed.
+    fn remove_stale_lock(&self, lock_path: &Path, token: &str) -> bool {
+        let Ok(stale_token) = std::fs::read_to_string(lock_path) else {
+            return false;
+        };
+        if !self.is_lock_stale(lock_path) {
+            return false;
+        }
+
+        let mut taken_path = lock_path.to_path_buf().into_os_string();
+        taken_path.push(format!(".{token}"));
+        if std::fs::rename(lock_path, &taken_path).is_err() {
+            return false;
+        }
+
+        let removed = std::fs::read_to_string(&taken_path).is_ok_and(|taken| taken == stale_token);
+        if !removed {
+            let _ = std::fs::hard_link(&taken_path, lock_path);
+        }
+        let _ = std::fs::remove_file(&taken_path);
+
+        removed
+    }
+
+    fn is_lock_stale(&self, lock_path: &Path) -> bool {
+        std::fs::metadata(lock_path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > self.stale_lock_timeout)
+    }
+}
+
+pub(crate) struct FileLock {
+    path: PathBuf,
+    token: String,
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        // the lock might have been considered stale and taken over by another process
+        if std::fs::read_to_string(&self.path).is_ok_and(|token| token == self.token) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+fn lock_token() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    format!(
+        "{}-{nanos}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
@@ -3,17 +3,23 @@ mod account;
 #[cfg(feature = "std")]
 mod accounts_utils;
 #[cfg(feature = "std")]
+pub mod dispatcher;
+#[cfg(feature = "std")]
 pub mod impersonated_account;
+#[cfg(any(feature = "std", feature = "coin-cache"))]
+mod json_file_store;
 #[cfg(feature = "std")]
 pub mod provider;
 #[cfg(feature = "std")]
 pub mod wallet;
+#[cfg(feature = "std")]
+pub mod withdrawal;
 
 #[cfg(feature = "std")]
 pub use account::*;
 
 #[cfg(feature = "coin-cache")]
-mod coin_cache;
+pub mod coin_cache;
 
 pub mod predicate;
 
@@ -19,7 +19,7 @@ use fuel_core_client::client::{
 };
 use fuel_core_types::services::executor::TransactionExecutionResult;
 use fuel_tx::{
-    AssetId, ConsensusParameters, Receipt, Transaction as FuelTransaction, TxId, UtxoId,
+    AssetId, ConsensusParameters, Receipt, Salt, Transaction as FuelTransaction, TxId, UtxoId,
 };
 use fuel_types::{Address, BlockHeight, Bytes32, ChainId, Nonce};
 #[cfg(feature = "coin-cache")]
@@ -43,14 +43,14 @@ use fuels_core::{
         DryRun, DryRunner,
     },
 };
-pub use retry_util::{Backoff, RetryConfig};
+pub use retry_util::{Backoff, CircuitBreakerConfig, Jitter, RetryClassifier, RetryConfig};
 pub use supported_fuel_core_version::SUPPORTED_FUEL_CORE_VERSION;
 use tai64::Tai64;
-#[cfg(feature = "coin-cache")]
-use tokio::sync::Mutex;
 
 #[cfg(feature = "coin-cache")]
-use crate::coin_cache::CoinsCache;
+use crate::coin_cache::{
+    CoinReservationCounters, CoinReservationMetrics, CoinReservationStore, InMemoryCoinReservations,
+};
 use crate::provider::retryable_client::RetryableClient;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -63,6 +63,14 @@ pub struct TransactionCost {
 }
 // ANCHOR_END: transaction_cost
 
+/// Bytecode and salt of a contract as stored on chain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeployedContract {
+    pub contract_id: Bech32ContractId,
+    pub bytecode: Vec<u8>,
+    pub salt: Salt,
+}
+
 pub(crate) struct ResourceQueries {
     utxos: Vec<UtxoId>,
     messages: Vec<Nonce>,
@@ -118,7 +126,9 @@ pub struct Provider {
     client: RetryableClient,
     consensus_parameters: ConsensusParameters,
     #[cfg(feature = "coin-cache")]
-    cache: Arc<Mutex<CoinsCache>>,
+    cache: Arc<dyn CoinReservationStore>,
+    #[cfg(feature = "coin-cache")]
+    cache_counters: Arc<CoinReservationCounters>,
 }
 
 impl Provider {
@@ -132,6 +142,10 @@ impl Provider {
     }
 
     /// Connects to an existing node at the given address.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(url = url.as_ref()), err)
+    )]
     pub async fn connect(url: impl AsRef<str>) -> Result<Provider> {
         let client = RetryableClient::connect(&url, Default::default()).await?;
         let consensus_parameters = client.chain_info().await?.consensus_parameters;
@@ -140,7 +154,9 @@ impl Provider {
             client,
             consensus_parameters,
             #[cfg(feature = "coin-cache")]
-            cache: Default::default(),
+            cache: Arc::new(InMemoryCoinReservations::default()),
+            #[cfg(feature = "coin-cache")]
+            cache_counters: Default::default(),
         })
     }
 
@@ -161,31 +177,57 @@ impl Provider {
     }
 
     /// Sends a transaction to the underlying Provider's client.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(tx_id = tracing::field::Empty, status = tracing::field::Empty),
+            err
+        )
+    )]
     pub async fn send_transaction_and_await_commit<T: Transaction>(
         &self,
         tx: T,
     ) -> Result<TxStatus> {
         let tx = self.prepare_transaction_for_sending(tx).await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("tx_id", tracing::field::display(tx.id(self.chain_id())));
+
         let tx_status = self
             .client
             .submit_and_await_commit(&tx.clone().into())
             .await?
             .into();
 
+        #[cfg(feature = "tracing")]
+        record_tx_status(&tx_status);
+
         #[cfg(feature = "coin-cache")]
         if matches!(
             tx_status,
             TxStatus::SqueezedOut { .. } | TxStatus::Revert { .. }
         ) {
-            self.cache
-                .lock()
-                .await
-                .remove_items(tx.used_coins(self.base_asset_id()))
+            // the transaction's status is what the caller needs, failing to release its coins
+            // only delays their reuse until the reservation expires
+            if let Err(err) = self.release_coins(&tx).await {
+                self.record_reservation_error(err, "release");
+            }
         }
 
         Ok(tx_status)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "trace",
+            skip_all,
+            fields(uses_predicates = tx.is_using_predicates()),
+            err
+        )
+    )]
     async fn prepare_transaction_for_sending<T: Transaction>(&self, mut tx: T) -> Result<T> {
         tx.precompute(&self.chain_id())?;
 
@@ -208,13 +250,47 @@ impl Provider {
         Ok(tx)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_id = tracing::field::Empty), err)
+    )]
     pub async fn send_transaction<T: Transaction>(&self, tx: T) -> Result<TxId> {
         let tx = self.prepare_transaction_for_sending(tx).await?;
-        self.submit(tx).await
+        let tx_id = self.submit(tx).await?;
+
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("tx_id", tracing::field::display(tx_id));
+
+        Ok(tx_id)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip(self),
+            fields(%id, status = tracing::field::Empty),
+            err
+        )
+    )]
     pub async fn await_transaction_commit<T: Transaction>(&self, id: TxId) -> Result<TxStatus> {
-        Ok(self.client.await_transaction_commit(&id).await?.into())
+        let tx_status = self.client.await_transaction_commit(&id).await?.into();
+
+        #[cfg(feature = "coin-cache")]
+        if matches!(
+            tx_status,
+            TxStatus::SqueezedOut { .. } | TxStatus::Revert { .. }
+        ) {
+            match self.cache.release_tx(&id).await {
+                Ok(released) => self.cache_counters.record_released_count(released),
+                Err(err) => self.record_reservation_error(err, "release"),
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        record_tx_status(&tx_status);
+
+        Ok(tx_status)
     }
 
     async fn validate_transaction<T: Transaction>(&self, tx: T) -> Result<()> {
@@ -237,11 +313,59 @@ impl Provider {
     async fn submit<T: Transaction>(&self, tx: T) -> Result<TxId> {
         let used_utxos = tx.used_coins(self.base_asset_id());
         let tx_id = self.client.submit(&tx.into()).await?;
-        self.cache.lock().await.insert_multiple(used_utxos);
+
+        // the transaction is already submitted, returning an error would lose its id and lead to
+        // its inputs being spent again
+        match self.cache.reserve(tx_id, used_utxos.clone()).await {
+            Ok(()) => self.cache_counters.record_reserved(&used_utxos),
+            Err(err) => self.record_reservation_error(err, "reserve"),
+        }
 
         Ok(tx_id)
     }
 
+    /// Replaces the store used to keep track of coins spent by pending transactions. By default
+    /// the reservations are kept in memory and are only shared between clones of this `Provider`.
+    /// Use a shared store, e.g. [`FileCoinReservations`](crate::coin_cache::FileCoinReservations),
+    /// to avoid selecting the same coins from several processes.
+    #[cfg(feature = "coin-cache")]
+    pub fn with_coin_reservation_store(
+        mut self,
+        store: impl CoinReservationStore + 'static,
+    ) -> Self {
+        self.cache = Arc::new(store);
+        self
+    }
+
+    /// Releases the coins used by `tx` so that they can be selected again. Useful when a
+    /// submitted transaction is known to have failed, e.g. because it was squeezed out.
+    #[cfg(feature = "coin-cache")]
+    pub async fn release_coins<T: Transaction>(&self, tx: &T) -> Result<()> {
+        let used_coins = tx.used_coins(self.base_asset_id());
+
+        self.cache.release(used_coins.clone()).await?;
+        self.cache_counters.record_released(&used_coins);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "coin-cache")]
+    pub fn coin_reservation_metrics(&self) -> CoinReservationMetrics {
+        self.cache_counters.snapshot()
+    }
+
+    /// Store failures on the submission path are not returned, the transaction was already sent
+    /// and its status is what the caller needs. They are counted in the metrics instead.
+    #[cfg(feature = "coin-cache")]
+    fn record_reservation_error(&self, err: fuels_core::types::errors::Error, action: &str) {
+        self.cache_counters.record_store_error();
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(error = %err, "could not {action} coins in the reservation store");
+        #[cfg(not(feature = "tracing"))]
+        let _ = (err, action);
+    }
+
     pub async fn tx_status(&self, tx_id: &TxId) -> Result<TxStatus> {
         Ok(self.client.transaction_status(tx_id).await?.into())
     }
@@ -274,6 +398,10 @@ impl Provider {
         Ok(self.client.estimate_gas_price(block_horizon).await?)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(status = tracing::field::Empty), err)
+    )]
     pub async fn dry_run(&self, tx: impl Transaction) -> Result<TxStatus> {
         let [tx_status] = self
             .client
@@ -285,6 +413,9 @@ impl Provider {
             .try_into()
             .expect("should have only one element");
 
+        #[cfg(feature = "tracing")]
+        record_tx_status(&tx_status);
+
         Ok(tx_status)
     }
 
@@ -301,6 +432,15 @@ impl Provider {
             .collect())
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip(self, tx),
+            fields(status = tracing::field::Empty),
+            err
+        )
+    )]
     pub async fn dry_run_opt(
         &self,
         tx: impl Transaction,
@@ -321,6 +461,9 @@ impl Provider {
             .try_into()
             .expect("should have only one element");
 
+        #[cfg(feature = "tracing")]
+        record_tx_status(&tx_status);
+
         Ok(tx_status)
     }
 
@@ -385,6 +528,14 @@ impl Provider {
             .map(CoinType::try_from)
             .collect::<Result<Vec<CoinType>>>()?;
 
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            excluded_utxos = filter.excluded_utxos.len(),
+            excluded_messages = filter.excluded_message_nonces.len(),
+            selected = res.len(),
+            "coins selected"
+        );
+
         Ok(res)
     }
 
@@ -392,6 +543,15 @@ impl Provider {
     /// amount `amount`. The returned coins (UTXOs) are actual coins that can be spent. The number
     /// of coins (UXTOs) is optimized to prevent dust accumulation.
     #[cfg(not(feature = "coin-cache"))]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(owner = %filter.from, asset_id = ?filter.asset_id, amount = filter.amount),
+            err
+        )
+    )]
     pub async fn get_spendable_resources(&self, filter: ResourceFilter) -> Result<Vec<CoinType>> {
         self.request_coins_to_spend(filter).await
     }
@@ -401,20 +561,33 @@ impl Provider {
     /// of coins (UXTOs) is optimized to prevent dust accumulation.
     /// Coins that were recently submitted inside a tx will be ignored from the results.
     #[cfg(feature = "coin-cache")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(owner = %filter.from, asset_id = ?filter.asset_id, amount = filter.amount),
+            err
+        )
+    )]
     pub async fn get_spendable_resources(
         &self,
         mut filter: ResourceFilter,
     ) -> Result<Vec<CoinType>> {
-        self.extend_filter_with_cached(&mut filter).await;
+        self.extend_filter_with_cached(&mut filter).await?;
 
         self.request_coins_to_spend(filter).await
     }
 
     #[cfg(feature = "coin-cache")]
-    async fn extend_filter_with_cached(&self, filter: &mut ResourceFilter) {
-        let mut cache = self.cache.lock().await;
+    async fn extend_filter_with_cached(&self, filter: &mut ResourceFilter) -> Result<()> {
         let asset_id = filter.asset_id.unwrap_or(*self.base_asset_id());
-        let used_coins = cache.get_active(&(filter.from.clone(), asset_id));
+        let used_coins = self
+            .cache
+            .reserved(&(filter.from.clone(), asset_id))
+            .await?;
+
+        self.cache_counters.record_lookup(used_coins.len());
 
         let excluded_utxos = used_coins
             .iter()
@@ -438,6 +611,8 @@ impl Provider {
         filter
             .excluded_message_nonces
             .extend(excluded_message_nonces);
+
+        Ok(())
     }
 
     /// Get the balance of all spendable coins `asset_id` for address `address`. This is different
@@ -617,6 +792,19 @@ impl Provider {
         })
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip(self, tx),
+            fields(
+                gas_price = tracing::field::Empty,
+                gas_used = tracing::field::Empty,
+                total_fee = tracing::field::Empty
+            ),
+            err
+        )
+    )]
     pub async fn estimate_transaction_cost<T: Transaction>(
         &self,
         mut tx: T,
@@ -641,6 +829,19 @@ impl Provider {
             .fee_checked_from_tx(&self.consensus_parameters, gas_price)
             .expect("Error calculating TransactionFee");
 
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("gas_price", gas_price);
+            span.record("gas_used", gas_used);
+            span.record("total_fee", transaction_fee.max_fee());
+            tracing::debug!(
+                histogram.fuels_estimated_gas_used = gas_used,
+                histogram.fuels_estimated_total_fee = transaction_fee.max_fee(),
+                "transaction cost estimated"
+            );
+        }
+
         Ok(TransactionCost {
             gas_price,
             gas_used,
@@ -673,6 +874,11 @@ impl Provider {
             .unwrap_or(0)
     }
 
+    /// Gets the message with the given `nonce`, e.g. a deposit from the base layer.
+    pub async fn get_message(&self, nonce: &Nonce) -> Result<Option<Message>> {
+        Ok(self.client.message(nonce).await?.map(Into::into))
+    }
+
     pub async fn get_messages(&self, from: &Bech32Address) -> Result<Vec<Message>> {
         let pagination = PaginationRequest {
             cursor: None,
@@ -690,6 +896,10 @@ impl Provider {
             .collect())
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), err)
+    )]
     pub async fn get_message_proof(
         &self,
         tx_id: &TxId,
@@ -702,7 +912,7 @@ impl Provider {
             .message_proof(
                 tx_id,
                 nonce,
-                commit_block_id.map(Into::into),
+                commit_block_id,
                 commit_block_height.map(Into::into),
             )
             .await?
@@ -724,6 +934,22 @@ impl Provider {
     pub async fn contract_exists(&self, contract_id: &Bech32ContractId) -> Result<bool> {
         Ok(self.client.contract_exists(&contract_id.into()).await?)
     }
+
+    /// Fetches the deployed bytecode and salt of a contract, if it exists.
+    pub async fn contract(
+        &self,
+        contract_id: &Bech32ContractId,
+    ) -> Result<Option<DeployedContract>> {
+        Ok(self
+            .client
+            .contract(&contract_id.into())
+            .await?
+            .map(|contract| DeployedContract {
+                contract_id: contract.id.into(),
+                bytecode: contract.bytecode,
+                salt: contract.salt,
+            }))
+    }
 }
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
@@ -731,7 +957,7 @@ impl DryRunner for Provider {
     async fn dry_run(&self, tx: FuelTransaction) -> Result<DryRun> {
         let [tx_execution_status] = self
             .client
-            .dry_run_opt(&vec![tx], Some(false), Some(0))
+            .dry_run_opt(&[tx], Some(false), Some(0))
             .await?
             .try_into()
             .expect("should have only one element");
@@ -757,6 +983,14 @@ impl DryRunner for Provider {
             variable_outputs,
         };
 
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            succeeded,
+            script_gas,
+            variable_outputs,
+            "estimation dry run"
+        );
+
         Ok(dry_run)
     }
 
@@ -768,6 +1002,10 @@ impl DryRunner for Provider {
         self.consensus_parameters()
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, err)
+    )]
     async fn maybe_estimate_predicates(
         &self,
         tx: &FuelTransaction,
@@ -778,3 +1016,20 @@ impl DryRunner for Provider {
         Ok(Some(self.client.estimate_predicates(tx).await?))
     }
 }
+
+#[cfg(feature = "tracing")]
+fn record_tx_status(tx_status: &TxStatus) {
+    let status = match tx_status {
+        TxStatus::Success { .. } => "success",
+        TxStatus::Submitted => "submitted",
+        TxStatus::SqueezedOut { .. } => "squeezed_out",
+        TxStatus::Revert { .. } => "revert",
+    };
+
+    tracing::Span::current().record("status", status);
+    tracing::debug!(
+        monotonic_counter.fuels_tx_status = 1_u64,
+        status,
+        "transaction status"
+    );
+}
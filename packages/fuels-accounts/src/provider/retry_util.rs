@@ -1,6 +1,15 @@
-use std::{fmt::Debug, future::Future, num::NonZeroU32, time::Duration};
+use std::{
+    fmt::{self, Debug},
+    future::Future,
+    io,
+    num::NonZeroU32,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use fuels_core::types::errors::{error, Result};
+use rand::Rng;
+use tokio::time::Instant;
 
 /// A set of strategies to control retry intervals between attempts.
 ///
@@ -42,13 +51,158 @@ impl Default for Backoff {
 impl Backoff {
     pub fn wait_duration(&self, attempt: u32) -> Duration {
         match self {
-            Backoff::Linear(base_duration) => *base_duration * (attempt + 1),
-            Backoff::Exponential(base_duration) => *base_duration * 2u32.pow(attempt),
+            Backoff::Linear(base_duration) => base_duration.saturating_mul(attempt + 1),
+            Backoff::Exponential(base_duration) => {
+                base_duration.saturating_mul(2u32.saturating_pow(attempt))
+            }
             Backoff::Fixed(interval) => *interval,
         }
     }
 }
 
+/// Randomization applied on top of the [`Backoff`] wait duration so that many clients failing
+/// at the same time do not retry in lockstep.
+///
+/// # Variants
+///
+/// - `None`: The wait duration is used as is.
+/// - `Full`: Waits for a random duration between zero and the backoff duration.
+/// - `Equal`: Waits for half of the backoff duration plus a random duration of up to the other half.
+//ANCHOR: jitter
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Jitter {
+    #[default]
+    None,
+    Full,
+    Equal,
+}
+//ANCHOR_END: jitter
+
+impl Jitter {
+    pub fn apply(&self, duration: Duration) -> Duration {
+        let random_up_to = |max: Duration| {
+            if max.is_zero() {
+                return Duration::ZERO;
+            }
+            rand::thread_rng().gen_range(Duration::ZERO..=max)
+        };
+
+        match self {
+            Jitter::None => duration,
+            Jitter::Full => random_up_to(duration),
+            Jitter::Equal => {
+                let half = duration / 2;
+                half + random_up_to(duration - half)
+            }
+        }
+    }
+}
+
+/// Decides whether a failed request should be retried.
+///
+/// The default classifier retries on every error, matching the behavior of the SDK before
+/// classifiers were introduced. [`RetryClassifier::transient`] only retries errors that look
+/// like network hiccups (timeouts, dropped connections, `5xx` responses) and gives up immediately
+/// on everything else, e.g. a transaction failing validation.
+#[derive(Clone)]
+pub struct RetryClassifier(Arc<dyn Fn(&io::Error) -> bool + Send + Sync>);
+
+impl RetryClassifier {
+    pub fn new(is_retryable: impl Fn(&io::Error) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(is_retryable))
+    }
+
+    /// Retries on any error.
+    pub fn always() -> Self {
+        Self::new(|_| true)
+    }
+
+    /// Retries only on timeouts, connection failures and server-side (`5xx`) errors.
+    pub fn transient() -> Self {
+        Self::new(is_transient_error)
+    }
+
+    pub fn is_retryable(&self, err: &io::Error) -> bool {
+        (self.0)(err)
+    }
+}
+
+impl Default for RetryClassifier {
+    fn default() -> Self {
+        Self::always()
+    }
+}
+
+impl Debug for RetryClassifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryClassifier").finish_non_exhaustive()
+    }
+}
+
+fn is_transient_error(err: &io::Error) -> bool {
+    use io::ErrorKind;
+
+    if matches!(
+        err.kind(),
+        ErrorKind::TimedOut
+            | ErrorKind::Interrupted
+            | ErrorKind::ConnectionRefused
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::NotConnected
+            | ErrorKind::BrokenPipe
+            | ErrorKind::UnexpectedEof
+    ) {
+        return true;
+    }
+
+    // `FuelClient` reports most failures as `ErrorKind::Other`, so we have to look at the message.
+    let msg = err.to_string().to_lowercase();
+
+    const TRANSIENT_MARKERS: [&str; 10] = [
+        "timed out",
+        "timeout",
+        "connection",
+        "error sending request",
+        "500 internal server error",
+        "502 bad gateway",
+        "503 service unavailable",
+        "504 gateway timeout",
+        "429 too many requests",
+        "temporarily unavailable",
+    ];
+
+    TRANSIENT_MARKERS.iter().any(|marker| msg.contains(marker))
+}
+
+/// Configuration of the circuit breaker guarding the requests made by a `Provider`.
+///
+/// After `failure_threshold` consecutive failed requests the breaker opens and every request
+/// fails immediately, without reaching the node, until `reset_timeout` has passed. After that a
+/// single trial request is let through: if it succeeds the breaker closes again, otherwise it
+/// stays open for another `reset_timeout`.
+///
+/// Only failures deemed retryable by the [`RetryClassifier`] count towards the threshold.
+// ANCHOR: circuit_breaker_config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    pub failure_threshold: NonZeroU32,
+    pub reset_timeout: Duration,
+}
+// ANCHOR_END: circuit_breaker_config
+
+impl CircuitBreakerConfig {
+    pub fn new(failure_threshold: u32, reset_timeout: Duration) -> Result<Self> {
+        let failure_threshold = NonZeroU32::new(failure_threshold)
+            .ok_or_else(|| error!(Other, "`failure_threshold` must be greater than `0`"))?;
+
+        Ok(Self {
+            failure_threshold,
+            reset_timeout,
+        })
+    }
+}
+
 /// Configuration for controlling retry behavior.
 ///
 /// The `RetryConfig` struct encapsulates the configuration parameters for controlling the retry behavior
@@ -59,24 +213,39 @@ impl Backoff {
 ///
 /// - `max_attempts`: The maximum number of attempts before giving up.
 /// - `interval`: The chosen interval strategy from the `Backoff` enum.
+/// - `jitter`: The randomization applied to every wait duration.
+/// - `max_delay`: An upper bound on the time waited between two attempts.
+/// - `max_elapsed_time`: An upper bound on the total time spent retrying a request.
+/// - `classifier`: Decides which errors are worth retrying.
+/// - `circuit_breaker`: Short-circuits requests after repeated failures.
 ///
 /// # Examples
 ///
 /// ```rust
 /// use std::num::NonZeroUsize;
 /// use std::time::Duration;
-/// use fuels_accounts::provider::{Backoff, RetryConfig};
+/// use fuels_accounts::provider::{Backoff, Jitter, RetryClassifier, RetryConfig};
 ///
 /// let max_attempts = 5;
 /// let interval_strategy = Backoff::Exponential(Duration::from_secs(1));
 ///
-/// let retry_config = RetryConfig::new(max_attempts, interval_strategy).unwrap();
+/// let retry_config = RetryConfig::new(max_attempts, interval_strategy)
+///     .unwrap()
+///     .with_jitter(Jitter::Full)
+///     .with_max_delay(Duration::from_secs(10))
+///     .with_max_elapsed_time(Duration::from_secs(30))
+///     .with_classifier(RetryClassifier::transient());
 /// ```
 // ANCHOR: retry_config
 #[derive(Clone, Debug)]
 pub struct RetryConfig {
     max_attempts: NonZeroU32,
     interval: Backoff,
+    jitter: Jitter,
+    max_delay: Option<Duration>,
+    max_elapsed_time: Option<Duration>,
+    classifier: RetryClassifier,
+    circuit_breaker: Option<CircuitBreakerConfig>,
 }
 // ANCHOR_END: retry_config
 
@@ -88,8 +257,52 @@ impl RetryConfig {
         Ok(RetryConfig {
             max_attempts,
             interval,
+            ..Default::default()
         })
     }
+
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = Some(max_delay);
+        self
+    }
+
+    pub fn with_max_elapsed_time(mut self, max_elapsed_time: Duration) -> Self {
+        self.max_elapsed_time = Some(max_elapsed_time);
+        self
+    }
+
+    pub fn with_classifier(mut self, classifier: RetryClassifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    pub fn classifier(&self) -> &RetryClassifier {
+        &self.classifier
+    }
+
+    pub fn circuit_breaker(&self) -> Option<CircuitBreakerConfig> {
+        self.circuit_breaker
+    }
+
+    /// The time to wait after the failed `attempt` (zero-based), with jitter and `max_delay` applied.
+    pub fn wait_duration(&self, attempt: u32) -> Duration {
+        let duration = self.interval.wait_duration(attempt);
+        let capped = self
+            .max_delay
+            .map_or(duration, |max_delay| duration.min(max_delay));
+
+        self.jitter.apply(capped)
+    }
 }
 
 impl Default for RetryConfig {
@@ -97,10 +310,108 @@ impl Default for RetryConfig {
         Self {
             max_attempts: NonZeroU32::new(1).expect("should not fail"),
             interval: Default::default(),
+            jitter: Default::default(),
+            max_delay: None,
+            max_elapsed_time: None,
+            classifier: Default::default(),
+            circuit_breaker: None,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+enum CircuitState {
+    Closed { consecutive_failures: u32 },
+    Open { until: Instant },
+    // A single trial request is in flight after the breaker was open. Should its outcome never be
+    // recorded, e.g. because the request was cancelled, another trial is let through at `until`.
+    TrialInFlight { until: Instant },
+}
+
+/// Tracks request failures according to a [`CircuitBreakerConfig`]. Cloning it yields a handle to
+/// the same state, so all clones of a `Provider` share one breaker.
+#[derive(Debug, Clone)]
+pub(crate) struct CircuitBreaker {
+    config: Option<CircuitBreakerConfig>,
+    state: Arc<Mutex<CircuitState>>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: Option<CircuitBreakerConfig>) -> Self {
+        Self {
+            config,
+            state: Arc::new(Mutex::new(CircuitState::Closed {
+                consecutive_failures: 0,
+            })),
+        }
+    }
+
+    /// Returns the remaining time the breaker stays open, if requests are to be short-circuited.
+    pub fn check(&self) -> Option<Duration> {
+        let config = self.config?;
+
+        let mut state = self.state.lock().expect("circuit breaker lock poisoned");
+        match *state {
+            CircuitState::Open { until } | CircuitState::TrialInFlight { until } => {
+                let now = Instant::now();
+                if now < until {
+                    Some(until - now)
+                } else {
+                    *state = CircuitState::TrialInFlight {
+                        until: now + config.reset_timeout,
+                    };
+                    None
+                }
+            }
+            CircuitState::Closed { .. } => None,
+        }
+    }
+
+    pub fn record_success(&self) {
+        if self.config.is_none() {
+            return;
+        }
+
+        *self.state.lock().expect("circuit breaker lock poisoned") = CircuitState::Closed {
+            consecutive_failures: 0,
+        };
+    }
+
+    pub fn record_failure(&self) {
+        let Some(config) = self.config else {
+            return;
+        };
+
+        let mut state = self.state.lock().expect("circuit breaker lock poisoned");
+        let open = CircuitState::Open {
+            until: Instant::now() + config.reset_timeout,
+        };
+
+        *state = match *state {
+            CircuitState::Closed {
+                consecutive_failures,
+            } => {
+                let consecutive_failures = consecutive_failures.saturating_add(1);
+                if consecutive_failures >= config.failure_threshold.get() {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        monotonic_counter.fuels_circuit_breaker_opened = 1_u64,
+                        consecutive_failures,
+                        "circuit breaker opened"
+                    );
+
+                    open
+                } else {
+                    CircuitState::Closed {
+                        consecutive_failures,
+                    }
+                }
+            }
+            CircuitState::TrialInFlight { .. } | CircuitState::Open { .. } => open,
+        };
+    }
+}
+
 /// Retries an asynchronous action with customizable retry behavior.
 ///
 /// This function takes an asynchronous action represented by a closure `action`.
@@ -119,9 +430,10 @@ impl Default for RetryConfig {
 /// # Return
 ///
 /// Returns `Ok(T)` if the action succeeds without requiring further retries.
-/// Returns `Err(Error)` if the maximum number of attempts is reached and the action
-/// still fails. If a retryable error occurs during the attempts, the error will
-/// be returned if the `should_retry` condition allows further retries.
+/// Returns `Err(Error)` if the maximum number of attempts is reached, or waiting for the
+/// next attempt would exceed the maximum elapsed time, and the action still fails. If a
+/// retryable error occurs during the attempts, the error will be returned if the
+/// `should_retry` condition allows further retries.
 pub(crate) async fn retry<Fut, T, ShouldRetry>(
     mut action: impl FnMut() -> Fut,
     retry_config: &RetryConfig,
@@ -131,21 +443,38 @@ where
     Fut: Future<Output = T>,
     ShouldRetry: Fn(&T) -> bool,
 {
-    let mut last_result = None;
+    let start = Instant::now();
+    let max_attempts = retry_config.max_attempts.get();
+    let mut attempt = 0;
 
-    for attempt in 0..retry_config.max_attempts.into() {
+    loop {
         let result = action().await;
 
-        if should_retry(&result) {
-            last_result = Some(result)
-        } else {
+        let is_last_attempt = attempt + 1 >= max_attempts;
+        if is_last_attempt || !should_retry(&result) {
             return result;
         }
 
-        tokio::time::sleep(retry_config.interval.wait_duration(attempt)).await;
-    }
+        let wait_duration = retry_config.wait_duration(attempt);
+
+        if let Some(max_elapsed_time) = retry_config.max_elapsed_time {
+            if start.elapsed() + wait_duration > max_elapsed_time {
+                return result;
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            monotonic_counter.fuels_provider_retries = 1_u64,
+            attempt = attempt + 1,
+            max_attempts,
+            wait_ms = wait_duration.as_millis() as u64,
+            "request failed, retrying"
+        );
 
-    last_result.expect("should not happen")
+        tokio::time::sleep(wait_duration).await;
+        attempt += 1;
+    }
 }
 
 #[cfg(test)]
@@ -332,4 +661,196 @@ mod tests {
             Ok(())
         }
     }
+
+    mod retry_config {
+        use std::{io, time::Duration};
+
+        use fuels_core::types::errors::Result;
+        use tokio::sync::Mutex;
+
+        use crate::provider::{
+            retry_util::{self, CircuitBreaker},
+            Backoff, CircuitBreakerConfig, Jitter, RetryClassifier, RetryConfig,
+        };
+
+        #[test]
+        fn wait_duration_is_capped_by_max_delay() -> Result<()> {
+            // given
+            let retry_config = RetryConfig::new(10, Backoff::Exponential(Duration::from_secs(1)))?
+                .with_max_delay(Duration::from_secs(5));
+
+            // when
+            let waits = (0..5)
+                .map(|attempt| retry_config.wait_duration(attempt))
+                .collect::<Vec<_>>();
+
+            // then
+            assert_eq!(waits, [1, 2, 4, 5, 5].map(Duration::from_secs));
+
+            Ok(())
+        }
+
+        #[test]
+        fn jitter_stays_within_bounds() {
+            let duration = Duration::from_millis(100);
+
+            for _ in 0..100 {
+                assert_eq!(Jitter::None.apply(duration), duration);
+                assert!(Jitter::Full.apply(duration) <= duration);
+
+                let equal = Jitter::Equal.apply(duration);
+                assert!(equal >= duration / 2 && equal <= duration);
+            }
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn stops_retrying_when_max_elapsed_time_would_be_exceeded() -> Result<()> {
+            // given
+            let number_of_attempts = Mutex::new(0usize);
+            let will_always_fail = || async {
+                *number_of_attempts.lock().await += 1;
+                Result::<()>::Err(fuels_core::error!(Other, "error"))
+            };
+
+            let retry_config = RetryConfig::new(10, Backoff::Fixed(Duration::from_secs(1)))?
+                .with_max_elapsed_time(Duration::from_millis(2500));
+
+            // when
+            let _ = retry_util::retry(will_always_fail, &retry_config, |_| true).await;
+
+            // then
+            assert_eq!(*number_of_attempts.lock().await, 3);
+
+            Ok(())
+        }
+
+        #[test]
+        fn transient_classifier_only_retries_network_errors() {
+            let classifier = RetryClassifier::transient();
+
+            let retryable = [
+                io::Error::new(io::ErrorKind::TimedOut, "deadline elapsed"),
+                io::Error::new(io::ErrorKind::ConnectionRefused, "refused"),
+                io::Error::other("error sending request for url (http://127.0.0.1:4000)"),
+                io::Error::other("HTTP status client error (503 Service Unavailable)"),
+            ];
+            let non_retryable = [
+                io::Error::other("Response errors; Validity(InsufficientFeeAmount)"),
+                io::Error::other(
+                    "Response errors; Transaction is not inserted. UTXO does not exist",
+                ),
+            ];
+
+            assert!(retryable.iter().all(|err| classifier.is_retryable(err)));
+            assert!(!non_retryable.iter().any(|err| classifier.is_retryable(err)));
+            assert!(non_retryable
+                .iter()
+                .all(|err| RetryClassifier::always().is_retryable(err)));
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn circuit_breaker_opens_after_threshold_and_half_opens_after_timeout() -> Result<()>
+        {
+            // given
+            let config = CircuitBreakerConfig::new(2, Duration::from_secs(10))?;
+            let breaker = CircuitBreaker::new(Some(config));
+
+            // when
+            breaker.record_failure();
+            assert!(breaker.check().is_none());
+            breaker.record_failure();
+
+            // then
+            assert_eq!(breaker.check(), Some(Duration::from_secs(10)));
+
+            tokio::time::advance(Duration::from_secs(11)).await;
+            assert!(
+                breaker.check().is_none(),
+                "a trial request should be let through"
+            );
+
+            breaker.record_failure();
+            assert!(
+                breaker.check().is_some(),
+                "failed trial should reopen the breaker"
+            );
+
+            tokio::time::advance(Duration::from_secs(11)).await;
+            assert!(breaker.check().is_none());
+            breaker.record_success();
+            breaker.record_failure();
+            assert!(
+                breaker.check().is_none(),
+                "success should reset the failure count"
+            );
+
+            Ok(())
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn circuit_breaker_lets_a_single_trial_through_concurrent_requests() -> Result<()> {
+            // given
+            let config = CircuitBreakerConfig::new(1, Duration::from_secs(10))?;
+            let breaker = CircuitBreaker::new(Some(config));
+            breaker.record_failure();
+            tokio::time::advance(Duration::from_secs(11)).await;
+
+            // when
+            let checks = (0..10).map(|_| {
+                let breaker = breaker.clone();
+                tokio::spawn(async move { breaker.check() })
+            });
+            let results = futures::future::try_join_all(checks)
+                .await
+                .expect("tasks should not panic");
+
+            // then
+            let let_through = results.iter().filter(|result| result.is_none()).count();
+            assert_eq!(
+                let_through, 1,
+                "only the trial request should be let through"
+            );
+
+            breaker.record_success();
+            assert!(
+                breaker.check().is_none(),
+                "successful trial should close the breaker"
+            );
+
+            Ok(())
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn abandoned_trial_is_replaced_after_the_timeout() -> Result<()> {
+            // given
+            let config = CircuitBreakerConfig::new(1, Duration::from_secs(10))?;
+            let breaker = CircuitBreaker::new(Some(config));
+            breaker.record_failure();
+            tokio::time::advance(Duration::from_secs(11)).await;
+            assert!(breaker.check().is_none());
+
+            // when
+            tokio::time::advance(Duration::from_secs(11)).await;
+
+            // then
+            assert!(
+                breaker.check().is_none(),
+                "a new trial should be let through if the first one never reported back"
+            );
+            assert!(breaker.check().is_some());
+
+            Ok(())
+        }
+
+        #[test]
+        fn circuit_breaker_is_disabled_by_default() {
+            let breaker = CircuitBreaker::new(RetryConfig::default().circuit_breaker());
+
+            for _ in 0..100 {
+                breaker.record_failure();
+            }
+
+            assert!(breaker.check().is_none());
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use std::{future::Future, io};
+use std::{future::Future, io, time::Duration};
 
 use custom_queries::{ContractExistsQuery, IsUserAccountQuery, IsUserAccountVariables};
 use cynic::QueryBuilder;
@@ -8,8 +8,8 @@ use fuel_core_client::client::{
     types::{
         gas_price::{EstimateGasPrice, LatestGasPrice},
         primitives::{BlockId, TransactionId},
-        Balance, Blob, Block, ChainInfo, Coin, CoinType, ContractBalance, Message, MessageProof,
-        NodeInfo, TransactionResponse, TransactionStatus,
+        Balance, Blob, Block, ChainInfo, Coin, CoinType, Contract, ContractBalance, Message,
+        MessageProof, NodeInfo, TransactionResponse, TransactionStatus,
     },
     FuelClient,
 };
@@ -19,12 +19,17 @@ use fuel_types::{Address, AssetId, BlockHeight, ContractId, Nonce};
 use fuels_core::types::errors::{error, Error, Result};
 
 use super::supported_versions::{self, VersionCompatibility};
-use crate::provider::{retry_util, RetryConfig};
+use crate::provider::{
+    retry_util::{self, CircuitBreaker},
+    RetryConfig,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum RequestError {
     #[error("io error: {0}")]
     IO(String),
+    #[error("circuit breaker is open after repeated failures, retry in {0:?}")]
+    CircuitOpen(Duration),
 }
 
 type RequestResult<T> = std::result::Result<T, RequestError>;
@@ -40,6 +45,7 @@ pub(crate) struct RetryableClient {
     client: FuelClient,
     url: String,
     retry_config: RetryConfig,
+    circuit_breaker: CircuitBreaker,
     prepend_warning: Option<String>,
 }
 
@@ -53,6 +59,7 @@ impl RetryableClient {
 
         Ok(Self {
             client,
+            circuit_breaker: CircuitBreaker::new(retry_config.circuit_breaker()),
             retry_config,
             url,
             prepend_warning: warning,
@@ -88,6 +95,7 @@ impl RetryableClient {
     }
 
     pub(crate) fn set_retry_config(&mut self, retry_config: RetryConfig) {
+        self.circuit_breaker = CircuitBreaker::new(retry_config.circuit_breaker());
         self.retry_config = retry_config;
     }
 
@@ -95,16 +103,33 @@ impl RetryableClient {
     where
         Fut: Future<Output = io::Result<T>>,
     {
-        retry_util::retry(action, &self.retry_config, |result| result.is_err())
-            .await
-            .map_err(|e| {
-                let msg = if let Some(warning) = &self.prepend_warning {
-                    format!("{warning}. {e}")
-                } else {
-                    e.to_string()
-                };
-                RequestError::IO(msg)
-            })
+        if let Some(remaining) = self.circuit_breaker.check() {
+            return Err(RequestError::CircuitOpen(remaining));
+        }
+
+        let classifier = self.retry_config.classifier();
+        let result = retry_util::retry(action, &self.retry_config, |result| {
+            result
+                .as_ref()
+                .is_err_and(|err| classifier.is_retryable(err))
+        })
+        .await;
+
+        match &result {
+            Ok(_) => self.circuit_breaker.record_success(),
+            Err(err) if classifier.is_retryable(err) => self.circuit_breaker.record_failure(),
+            // The node answered, it just didn't like the request.
+            Err(_) => self.circuit_breaker.record_success(),
+        }
+
+        result.map_err(|e| {
+            let msg = if let Some(warning) = &self.prepend_warning {
+                format!("{warning}. {e}")
+            } else {
+                e.to_string()
+            };
+            RequestError::IO(msg)
+        })
     }
 
     // DELEGATION START
@@ -293,6 +318,10 @@ impl RetryableClient {
             .await
     }
 
+    pub async fn message(&self, nonce: &Nonce) -> RequestResult<Option<Message>> {
+        self.wrap(|| self.client.message(nonce)).await
+    }
+
     /// Request a merkle proof of an output message.
     pub async fn message_proof(
         &self,
@@ -308,6 +337,10 @@ impl RetryableClient {
         .await
     }
 
+    pub async fn contract(&self, contract_id: &ContractId) -> RequestResult<Option<Contract>> {
+        self.wrap(|| self.client.contract(contract_id)).await
+    }
+
     pub async fn contract_exists(&self, contract_id: &ContractId) -> RequestResult<bool> {
         self.wrap(|| {
             let query = ContractExistsQuery::build(ContractByIdArgs {
@@ -0,0 +1,478 @@
+use std::{fmt::Debug, path::Path, sync::Arc};
+
+use async_trait::async_trait;
+use fuel_tx::{Receipt, TxId};
+use fuel_types::{Address, Nonce};
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Error, Result},
+    message_proof::MessageProof,
+    transaction::TxPolicies,
+    tx_status::TxStatus,
+};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{json_file_store::JsonFileStore, provider::Provider, Account};
+
+/// A withdrawal of the base asset to the base layer, i.e. a message sent out of the chain by
+/// [`Account::withdraw_to_base_layer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Withdrawal {
+    pub tx_id: TxId,
+    pub nonce: Nonce,
+    pub recipient: Address,
+    pub amount: u64,
+    /// Height of the block containing the withdrawal transaction.
+    pub block_height: u32,
+}
+
+impl Withdrawal {
+    /// Extracts the withdrawal with the given `nonce` from the receipts of the transaction
+    /// `tx_id` included at `block_height`.
+    pub fn from_receipts(
+        tx_id: TxId,
+        nonce: Nonce,
+        receipts: &[Receipt],
+        block_height: u32,
+    ) -> Result<Self> {
+        receipts
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::MessageOut {
+                    recipient,
+                    amount,
+                    nonce: receipt_nonce,
+                    ..
+                } if *receipt_nonce == nonce => Some(Self {
+                    tx_id,
+                    nonce,
+                    recipient: *recipient,
+                    amount: *amount,
+                    block_height,
+                }),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                error!(
+                    Other,
+                    "transaction `{tx_id}` did not send a message with nonce `{nonce}`"
+                )
+            })
+    }
+}
+
+/// Where to find a withdrawal on its way to the base layer, relative to the last block committed
+/// to the base layer.
+#[derive(Debug)]
+pub enum WithdrawalStatus {
+    /// No block after the one containing the withdrawal has been committed yet.
+    Pending,
+    /// The block at `commit_block_height`, which is after the block containing the withdrawal, has
+    /// been committed, but the node has no proof for the withdrawal against it yet.
+    Committed { commit_block_height: u32 },
+    /// The proof of the withdrawal against the commit block, verified locally. It can be used to
+    /// relay the message on the base layer.
+    ProofAvailable(Box<MessageProof>),
+}
+
+/// Tells a [`WithdrawalTracker`] how far the chain has been committed to the base layer.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait CommitHeightSource: Debug + Send + Sync {
+    /// Height of the last block of the chain committed to the base layer.
+    async fn commit_block_height(&self) -> Result<u32>;
+}
+
+/// A [`CommitHeightSource`] treating the latest block of the chain as committed. Only correct for
+/// chains without a base layer, e.g. local test nodes, where nothing is ever committed. For real
+/// networks, take the height from the base layer instead.
+#[derive(Debug, Clone)]
+pub struct LatestBlockCommitHeight(pub Provider);
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl CommitHeightSource for LatestBlockCommitHeight {
+    async fn commit_block_height(&self) -> Result<u32> {
+        self.0.latest_block_height().await
+    }
+}
+
+/// Persists the withdrawals a [`WithdrawalTracker`] is waiting on, so that they survive restarts.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait WithdrawalStore: Debug + Send + Sync {
+    /// Adds `withdrawal` to the store, replacing any withdrawal with the same nonce.
+    async fn insert(&self, withdrawal: Withdrawal) -> Result<()>;
+
+    /// Removes the withdrawal with the given `nonce`, if any.
+    async fn remove(&self, nonce: &Nonce) -> Result<()>;
+
+    /// Returns all stored withdrawals, oldest first.
+    async fn withdrawals(&self) -> Result<Vec<Withdrawal>>;
+}
+
+/// A [`WithdrawalStore`] keeping the withdrawals in memory only.
+#[derive(Debug, Default)]
+pub struct InMemoryWithdrawalStore {
+    withdrawals: Mutex<Vec<Withdrawal>>,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl WithdrawalStore for InMemoryWithdrawalStore {
+    async fn insert(&self, withdrawal: Withdrawal) -> Result<()> {
+        insert_withdrawal(&mut *self.withdrawals.lock().await, withdrawal);
+
+        Ok(())
+    }
+
+    async fn remove(&self, nonce: &Nonce) -> Result<()> {
+        self.withdrawals
+            .lock()
+            .await
+            .retain(|withdrawal| withdrawal.nonce != *nonce);
+
+        Ok(())
+    }
+
+    async fn withdrawals(&self) -> Result<Vec<Withdrawal>> {
+        Ok(self.withdrawals.lock().await.clone())
+    }
+}
+
+/// A [`WithdrawalStore`] persisting the withdrawals in a JSON file, which can be shared between
+/// processes. Changes are serialized through a lockfile next to it (`<path>.lock`); a lockfile
+/// older than `stale_lock_timeout` is assumed to be left over by a crashed process. The file is
+/// accessed on tokio's blocking thread pool.
+#[derive(Debug, Clone)]
+pub struct FileWithdrawalStore {
+    file: JsonFileStore,
+}
+
+impl FileWithdrawalStore {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            file: JsonFileStore::new(path, "withdrawals"),
+        }
+    }
+
+    pub fn with_stale_lock_timeout(mut self, stale_lock_timeout: Duration) -> Self {
+        self.file = self.file.with_stale_lock_timeout(stale_lock_timeout);
+        self
+    }
+
+    pub fn with_lock_wait_timeout(mut self, lock_wait_timeout: Duration) -> Self {
+        self.file = self.file.with_lock_wait_timeout(lock_wait_timeout);
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        self.file.path()
+    }
+
+    async fn with_withdrawals<T: Send + 'static>(
+        &self,
+        update: impl FnOnce(&mut Vec<Withdrawal>) -> T + Send + 'static,
+    ) -> Result<T> {
+        self.file.run(move |file| file.update(update)).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl WithdrawalStore for FileWithdrawalStore {
+    async fn insert(&self, withdrawal: Withdrawal) -> Result<()> {
+        self.with_withdrawals(move |withdrawals| insert_withdrawal(withdrawals, withdrawal))
+            .await
+    }
+
+    async fn remove(&self, nonce: &Nonce) -> Result<()> {
+        let nonce = *nonce;
+        self.with_withdrawals(move |withdrawals| {
+            withdrawals.retain(|withdrawal| withdrawal.nonce != nonce)
+        })
+        .await
+    }
+
+    async fn withdrawals(&self) -> Result<Vec<Withdrawal>> {
+        self.file.run(|file| file.read()).await
+    }
+}
+
+// a withdrawal tracked again keeps its place, so that the withdrawals stay ordered oldest first
+fn insert_withdrawal(withdrawals: &mut Vec<Withdrawal>, withdrawal: Withdrawal) {
+    match withdrawals
+        .iter_mut()
+        .find(|stored| stored.nonce == withdrawal.nonce)
+    {
+        Some(stored) => *stored = withdrawal,
+        None => withdrawals.push(withdrawal),
+    }
+}
+
+/// Follows withdrawals to the base layer until their message proofs become available.
+///
+/// A withdrawal can only be proven against a block committed to the base layer after the one
+/// containing it. The node doesn't know which blocks were committed, so the tracker asks its
+/// [`CommitHeightSource`]. Tracked withdrawals stay in the store until
+/// [`WithdrawalTracker::complete`] is called, e.g. once the message was relayed.
+#[derive(Debug, Clone)]
+pub struct WithdrawalTracker {
+    provider: Provider,
+    commit_heights: Arc<dyn CommitHeightSource>,
+    store: Arc<dyn WithdrawalStore>,
+}
+
+impl WithdrawalTracker {
+    pub fn new(provider: Provider, commit_heights: impl CommitHeightSource + 'static) -> Self {
+        Self {
+            provider,
+            commit_heights: Arc::new(commit_heights),
+            store: Arc::new(InMemoryWithdrawalStore::default()),
+        }
+    }
+
+    pub fn with_store(mut self, store: impl WithdrawalStore + 'static) -> Self {
+        self.store = Arc::new(store);
+        self
+    }
+
+    /// Withdraws `amount` of the base asset to `to` on the base layer and starts tracking the
+    /// withdrawal.
+    pub async fn withdraw(
+        &self,
+        account: &impl Account,
+        to: impl TryInto<Bech32Address, Error: Into<Error>> + Send,
+        amount: u64,
+        tx_policies: TxPolicies,
+    ) -> Result<Withdrawal> {
+        let (tx_id, nonce, _) = account
+            .withdraw_to_base_layer(to, amount, tx_policies)
+            .await?;
+
+        self.track(tx_id, nonce).await
+    }
+
+    /// Starts tracking the withdrawal with the given `nonce` sent by the transaction `tx_id`.
+    pub async fn track(&self, tx_id: TxId, nonce: Nonce) -> Result<Withdrawal> {
+        let response = self
+            .provider
+            .get_transaction_by_id(&tx_id)
+            .await?
+            .ok_or_else(|| error!(Provider, "transaction `{tx_id}` not found"))?;
+
+        let (TxStatus::Success { receipts }, Some(block_height)) =
+            (response.status, response.block_height)
+        else {
+            return Err(error!(
+                Other,
+                "transaction `{tx_id}` did not succeed, there is no withdrawal to track"
+            ));
+        };
+
+        let withdrawal = Withdrawal::from_receipts(tx_id, nonce, &receipts, *block_height)?;
+        self.store.insert(withdrawal).await?;
+
+        Ok(withdrawal)
+    }
+
+    /// Returns all tracked withdrawals, oldest first.
+    pub async fn withdrawals(&self) -> Result<Vec<Withdrawal>> {
+        self.store.withdrawals().await
+    }
+
+    /// Stops tracking the withdrawal with the given `nonce`.
+    pub async fn complete(&self, nonce: &Nonce) -> Result<()> {
+        self.store.remove(nonce).await
+    }
+
+    /// Returns the status of `withdrawal` relative to the last committed block, as reported by the
+    /// tracker's [`CommitHeightSource`].
+    pub async fn status(&self, withdrawal: &Withdrawal) -> Result<WithdrawalStatus> {
+        let commit_block_height = self.commit_heights.commit_block_height().await?;
+
+        self.status_at(withdrawal, commit_block_height).await
+    }
+
+    /// Returns the status of `withdrawal` relative to the block at `commit_block_height`, which
+    /// must have been committed to the base layer. Available proofs are verified before being
+    /// returned.
+    pub async fn status_at(
+        &self,
+        withdrawal: &Withdrawal,
+        commit_block_height: u32,
+    ) -> Result<WithdrawalStatus> {
+        if commit_block_height <= withdrawal.block_height {
+            return Ok(WithdrawalStatus::Pending);
+        }
+
+        let proof = self
+            .provider
+            .get_message_proof(
+                &withdrawal.tx_id,
+                &withdrawal.nonce,
+                None,
+                Some(commit_block_height),
+            )
+            .await?;
+
+        match proof {
+            Some(proof) => {
+                proof.verify()?;
+                Ok(WithdrawalStatus::ProofAvailable(Box::new(proof)))
+            }
+            None => Ok(WithdrawalStatus::Committed {
+                commit_block_height,
+            }),
+        }
+    }
+
+    /// Polls the status of `withdrawal` every `poll_interval` until its proof against the last
+    /// committed block is available, giving up after `timeout`.
+    pub async fn wait_for_proof(
+        &self,
+        withdrawal: &Withdrawal,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<MessageProof> {
+        let started = Instant::now();
+
+        loop {
+            if let WithdrawalStatus::ProofAvailable(proof) = self.status(withdrawal).await? {
+                return Ok(*proof);
+            }
+
+            if started.elapsed() > timeout {
+                return Err(error!(
+                    Provider,
+                    "timed out waiting for the proof of withdrawal `{}`", withdrawal.nonce
+                ));
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn withdrawal(nonce: u8) -> Withdrawal {
+        Withdrawal {
+            tx_id: TxId::from([nonce; 32]),
+            nonce: Nonce::from([nonce; 32]),
+            recipient: Address::from([2; 32]),
+            amount: 100,
+            block_height: 1,
+        }
+    }
+
+    #[test]
+    fn withdrawal_is_extracted_from_receipts() -> Result<()> {
+        // given
+        let expected = withdrawal(1);
+        let receipts = [Receipt::message_out(
+            &expected.tx_id,
+            0,
+            Address::from([1; 32]),
+            expected.recipient,
+            expected.amount,
+            vec![],
+        )];
+        let nonce = *receipts[0].nonce().expect("message out has a nonce");
+
+        // when
+        let withdrawal =
+            Withdrawal::from_receipts(expected.tx_id, nonce, &receipts, expected.block_height)?;
+        let missing =
+            Withdrawal::from_receipts(expected.tx_id, Nonce::zeroed(), &receipts, 1).unwrap_err();
+
+        // then
+        assert_eq!(withdrawal, Withdrawal { nonce, ..expected });
+        assert!(missing
+            .to_string()
+            .contains("did not send a message with nonce"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_store_persists_withdrawals() -> Result<()> {
+        // given
+        let dir = tempdir()?;
+        let path = dir.path().join("withdrawals.json");
+        let store = FileWithdrawalStore::new(&path);
+
+        // when
+        store.insert(withdrawal(1)).await?;
+        store.insert(withdrawal(2)).await?;
+        store.insert(withdrawal(1)).await?;
+        store.remove(&withdrawal(2).nonce).await?;
+
+        // then
+        let reopened = FileWithdrawalStore::new(&path);
+        assert_eq!(reopened.withdrawals().await?, vec![withdrawal(1)]);
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn file_store_serializes_inserts_from_several_instances() -> Result<()> {
+        // given
+        let dir = tempdir()?;
+        let path = dir.path().join("withdrawals.json");
+
+        // when
+        let tasks = (0..20u8).map(|nonce| {
+            let store = FileWithdrawalStore::new(&path);
+            tokio::spawn(async move { store.insert(withdrawal(nonce)).await })
+        });
+        for result in futures::future::join_all(tasks).await {
+            result.expect("task should not panic")?;
+        }
+
+        // then
+        let store = FileWithdrawalStore::new(&path);
+        assert_eq!(store.withdrawals().await?.len(), 20);
+        assert!(!store.file.lock_path().exists());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tracking_a_withdrawal_again_keeps_its_place() -> Result<()> {
+        // given
+        let store = InMemoryWithdrawalStore::default();
+        store.insert(withdrawal(1)).await?;
+        store.insert(withdrawal(2)).await?;
+
+        // when
+        let updated = Withdrawal {
+            block_height: 5,
+            ..withdrawal(1)
+        };
+        store.insert(updated).await?;
+
+        // then
+        assert_eq!(store.withdrawals().await?, vec![updated, withdrawal(2)]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_file_store_is_empty() -> Result<()> {
+        // given
+        let dir = tempdir()?;
+        let store = FileWithdrawalStore::new(dir.path().join("withdrawals.json"));
+
+        // when
+        let withdrawals = store.withdrawals().await?;
+
+        // then
+        assert!(withdrawals.is_empty());
+
+        Ok(())
+    }
+}
@@ -4,4 +4,4 @@ mod generated_code;
 mod resolved_type;
 mod utils;
 
-pub use abigen::{Abi, Abigen, AbigenTarget, ProgramType};
+pub use abigen::{Abi, Abigen, AbigenTarget, BindingsFile, ProgramType};
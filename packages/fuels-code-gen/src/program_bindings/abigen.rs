@@ -1,6 +1,7 @@
 use std::{collections::HashSet, path::PathBuf};
 
 pub use abigen_target::{Abi, AbigenTarget, ProgramType};
+pub use bindings_file::BindingsFile;
 use fuel_abi_types::abi::full_program::FullTypeDeclaration;
 use inflector::Inflector;
 use itertools::Itertools;
@@ -19,6 +20,7 @@ use crate::{
 
 mod abigen_target;
 mod bindings;
+mod bindings_file;
 mod configurables;
 mod logs;
 
@@ -34,7 +36,18 @@ impl Abigen {
     ///   for, and of what nature (Contract, Script or Predicate).
     /// * `no_std`: don't use the Rust std library.
     pub fn generate(targets: Vec<AbigenTarget>, no_std: bool) -> Result<TokenStream> {
-        let generated_code = Self::generate_code(no_std, targets)?;
+        Self::generate_with_recompile_trigger(targets, no_std, true)
+    }
+
+    /// `recompile_trigger`: include the ABI files in the generated code so that the procedural
+    /// macro is reevaluated when they change. Build scripts rely on `cargo:rerun-if-changed`
+    /// instead.
+    pub(crate) fn generate_with_recompile_trigger(
+        targets: Vec<AbigenTarget>,
+        no_std: bool,
+        recompile_trigger: bool,
+    ) -> Result<TokenStream> {
+        let generated_code = Self::generate_code(no_std, targets, recompile_trigger)?;
 
         let use_statements = generated_code.use_statements_for_uniquely_named_types();
 
@@ -65,25 +78,32 @@ impl Abigen {
         .expect("Wasm hotfix failed!")
     }
 
-    fn generate_code(no_std: bool, parsed_targets: Vec<AbigenTarget>) -> Result<GeneratedCode> {
+    fn generate_code(
+        no_std: bool,
+        parsed_targets: Vec<AbigenTarget>,
+        recompile_trigger: bool,
+    ) -> Result<GeneratedCode> {
         let custom_types = Self::filter_custom_types(&parsed_targets);
         let shared_types = Self::filter_shared_types(custom_types);
 
-        let bindings = Self::generate_all_bindings(parsed_targets, no_std, &shared_types)?;
-        let shared_types = Self::generate_shared_types(shared_types, no_std)?;
+        let shared_types_code =
+            Self::generate_shared_types(&shared_types, &parsed_targets, no_std)?;
+        let bindings =
+            Self::generate_all_bindings(parsed_targets, no_std, &shared_types, recompile_trigger)?;
 
         let mod_name = ident("abigen_bindings");
-        Ok(shared_types.merge(bindings).wrap_in_mod(mod_name))
+        Ok(shared_types_code.merge(bindings).wrap_in_mod(mod_name))
     }
 
     fn generate_all_bindings(
         targets: Vec<AbigenTarget>,
         no_std: bool,
         shared_types: &HashSet<FullTypeDeclaration>,
+        recompile_trigger: bool,
     ) -> Result<GeneratedCode> {
         targets
             .into_iter()
-            .map(|target| Self::generate_binding(target, no_std, shared_types))
+            .map(|target| Self::generate_binding(target, no_std, shared_types, recompile_trigger))
             .fold_ok(GeneratedCode::default(), |acc, generated_code| {
                 acc.merge(generated_code)
             })
@@ -93,12 +113,18 @@ impl Abigen {
         target: AbigenTarget,
         no_std: bool,
         shared_types: &HashSet<FullTypeDeclaration>,
+        recompile_trigger: bool,
     ) -> Result<GeneratedCode> {
         let mod_name = ident(&format!("{}_mod", &target.name.to_snake_case()));
 
-        let recompile_trigger =
-            Self::generate_macro_recompile_trigger(target.source.path.as_ref(), no_std);
-        let types = generate_types(&target.source.abi.types, shared_types, no_std)?;
+        let path = target.source.path.as_ref().filter(|_| recompile_trigger);
+        let recompile_trigger = Self::generate_macro_recompile_trigger(path, no_std);
+        let types = generate_types(
+            &target.source.abi.types,
+            shared_types,
+            &target.derives,
+            no_std,
+        )?;
         let bindings = generate_bindings(target, no_std)?;
         Ok(recompile_trigger
             .merge(types)
@@ -122,11 +148,30 @@ impl Abigen {
         GeneratedCode::new(code, Default::default(), no_std)
     }
 
+    /// A shared type gets the derives requested by any of the targets using it.
     fn generate_shared_types(
-        shared_types: HashSet<FullTypeDeclaration>,
+        shared_types: &HashSet<FullTypeDeclaration>,
+        targets: &[AbigenTarget],
         no_std: bool,
     ) -> Result<GeneratedCode> {
-        let types = generate_types(&shared_types, &HashSet::default(), no_std)?;
+        // Sorted so that the generated code doesn't depend on the iteration order of the set
+        let types = shared_types
+            .iter()
+            .sorted_by(|lhs, rhs| lhs.type_field.cmp(&rhs.type_field))
+            .map(|ttype| {
+                let derives = targets
+                    .iter()
+                    .filter(|target| target.source.abi.types.contains(ttype))
+                    .flat_map(|target| &target.derives)
+                    .unique_by(|derive| derive.to_string())
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                generate_types([ttype], &HashSet::default(), &derives, no_std)
+            })
+            .fold_ok(GeneratedCode::default(), |acc, generated_code| {
+                acc.merge(generated_code)
+            })?;
 
         if types.is_empty() {
             Ok(Default::default())
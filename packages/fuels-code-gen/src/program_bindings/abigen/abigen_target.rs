@@ -1,12 +1,17 @@
 use std::{
+    collections::HashMap,
     convert::TryFrom,
     env, fs,
     path::{Path, PathBuf},
     str::FromStr,
 };
 
-use fuel_abi_types::abi::full_program::FullProgramABI;
-use proc_macro2::Ident;
+use fuel_abi_types::abi::{
+    full_program::FullProgramABI,
+    program::{Attribute, ProgramABI},
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::ToTokens;
 
 use crate::error::{error, Error, Result};
 
@@ -15,6 +20,7 @@ pub struct AbigenTarget {
     pub(crate) name: String,
     pub(crate) source: Abi,
     pub(crate) program_type: ProgramType,
+    pub(crate) derives: Vec<TokenStream>,
 }
 
 impl AbigenTarget {
@@ -23,9 +29,18 @@ impl AbigenTarget {
             name,
             source,
             program_type,
+            derives: vec![],
         }
     }
 
+    /// Additional derives for the generated structs and enums, given as a comma-separated list of
+    /// paths, e.g. `"Hash, serde::Serialize"`. `serde` is short for
+    /// `serde::Serialize, serde::Deserialize`.
+    pub fn with_derives(mut self, derives: &str) -> Result<Self> {
+        self.derives = parse_derives(derives)?;
+        Ok(self)
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -37,12 +52,47 @@ impl AbigenTarget {
     pub fn program_type(&self) -> ProgramType {
         self.program_type
     }
+
+    pub fn derives(&self) -> &[TokenStream] {
+        &self.derives
+    }
+}
+
+fn parse_derives(derives: &str) -> Result<Vec<TokenStream>> {
+    let mut paths: Vec<TokenStream> = vec![];
+
+    for derive in derives.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+        let expanded = if derive == "serde" {
+            vec!["serde::Serialize", "serde::Deserialize"]
+        } else {
+            vec![derive]
+        };
+
+        for derive in expanded {
+            let mut path = syn::parse_str::<syn::Path>(derive)
+                .map_err(|e| error!("`{derive}` is not a valid derive path: {e}"))?;
+            // the bindings are generated in `#[no_implicit_prelude]` mods
+            if path.segments.len() > 1 {
+                path.leading_colon.get_or_insert_with(Default::default);
+            }
+
+            let path = path.into_token_stream();
+            if !paths.iter().any(|p| p.to_string() == path.to_string()) {
+                paths.push(path);
+            }
+        }
+    }
+
+    Ok(paths)
 }
 
 #[derive(Debug, Clone)]
 pub struct Abi {
     pub(crate) path: Option<PathBuf>,
     pub(crate) abi: FullProgramABI,
+    /// Attributes of each function, keyed by function name. Kept separately because
+    /// [`FullProgramABI`] doesn't expose them.
+    pub(crate) function_attributes: HashMap<String, Vec<Attribute>>,
 }
 
 impl Abi {
@@ -57,10 +107,12 @@ impl Abi {
             )
         })?;
         let abi = Self::parse_from_json(&json_abi)?;
+        let function_attributes = Self::parse_function_attributes(&json_abi)?;
 
         Ok(Abi {
             path: Some(path),
             abi,
+            function_attributes,
         })
     }
 
@@ -99,6 +151,18 @@ impl Abi {
             .map_err(|e| error!("malformed `abi`. Did you use `forc` to create it?: ").combine(e))
     }
 
+    fn parse_function_attributes(json_abi: &str) -> Result<HashMap<String, Vec<Attribute>>> {
+        let abi = serde_json::from_str::<ProgramABI>(json_abi).map_err(|e| {
+            error!("malformed `abi`. Did you use `forc` to create it?: ").combine(e)
+        })?;
+
+        Ok(abi
+            .functions
+            .into_iter()
+            .map(|function| (function.name, function.attributes.unwrap_or_default()))
+            .collect())
+    }
+
     pub fn path(&self) -> Option<&PathBuf> {
         self.path.as_ref()
     }
@@ -113,8 +177,13 @@ impl FromStr for Abi {
 
     fn from_str(json_abi: &str) -> Result<Self> {
         let abi = Abi::parse_from_json(json_abi)?;
+        let function_attributes = Abi::parse_function_attributes(json_abi)?;
 
-        Ok(Abi { path: None, abi })
+        Ok(Abi {
+            path: None,
+            abi,
+            function_attributes,
+        })
     }
 }
 
@@ -155,3 +224,79 @@ impl TryFrom<Ident> for ProgramType {
             .map_err(|e| Self::Error::new(ident.span(), e))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_are_parsed_into_absolute_paths() -> Result<()> {
+        // given
+        let derives = "Hash, serde, serde::Serialize, ::my_crate::MyDerive";
+
+        // when
+        let parsed = parse_derives(derives)?;
+
+        // then
+        let parsed = parsed.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            parsed,
+            [
+                "Hash",
+                ":: serde :: Serialize",
+                ":: serde :: Deserialize",
+                ":: my_crate :: MyDerive"
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_derives_are_rejected() {
+        let err = parse_derives("Hash, not a path").expect_err("should have failed");
+
+        assert!(err
+            .to_string()
+            .contains("`not a path` is not a valid derive path"));
+    }
+
+    #[test]
+    fn function_attributes_are_kept() -> Result<()> {
+        // given
+        let json_abi = r#"{
+            "programType": "contract",
+            "specVersion": "1",
+            "encodingVersion": "1",
+            "concreteTypes": [ { "type": "()", "concreteTypeId": "01" } ],
+            "metadataTypes": [],
+            "functions": [
+                {
+                    "name": "increment",
+                    "inputs": [],
+                    "output": "01",
+                    "attributes": [ { "name": "storage", "arguments": ["read", "write"] } ]
+                },
+                { "name": "noop", "inputs": [], "output": "01", "attributes": null }
+            ],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }"#;
+
+        // when
+        let abi = json_abi.parse::<Abi>()?;
+
+        // then
+        assert_eq!(
+            abi.function_attributes["increment"],
+            [Attribute {
+                name: "storage".to_string(),
+                arguments: vec!["read".to_string(), "write".to_string()],
+            }]
+        );
+        assert!(abi.function_attributes["noop"].is_empty());
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     error::Result,
     program_bindings::{
         abigen::{
-            abigen_target::AbigenTarget,
+            abigen_target::{Abi, AbigenTarget},
             bindings::{
                 contract::contract_bindings, predicate::predicate_bindings, script::script_bindings,
             },
@@ -20,13 +20,16 @@ mod script;
 mod utils;
 
 pub(crate) fn generate_bindings(target: AbigenTarget, no_std: bool) -> Result<GeneratedCode> {
-    let bindings_generator = match target.program_type {
-        ProgramType::Script => script_bindings,
-        ProgramType::Contract => contract_bindings,
-        ProgramType::Predicate => predicate_bindings,
-    };
-
     let name = ident(&target.name);
-    let abi = target.source.abi;
-    bindings_generator(&name, abi, no_std)
+    let Abi {
+        abi,
+        function_attributes,
+        ..
+    } = target.source;
+
+    match target.program_type {
+        ProgramType::Script => script_bindings(&name, abi, no_std),
+        ProgramType::Contract => contract_bindings(&name, abi, &function_attributes, no_std),
+        ProgramType::Predicate => predicate_bindings(&name, abi, no_std),
+    }
 }
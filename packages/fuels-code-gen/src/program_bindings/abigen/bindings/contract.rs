@@ -1,4 +1,9 @@
-use fuel_abi_types::abi::full_program::{FullABIFunction, FullProgramABI};
+use std::collections::HashMap;
+
+use fuel_abi_types::abi::{
+    full_program::{FullABIFunction, FullProgramABI},
+    program::Attribute,
+};
 use itertools::Itertools;
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, TokenStreamExt};
@@ -9,16 +14,17 @@ use crate::{
         abigen::{
             bindings::function_generator::FunctionGenerator,
             configurables::generate_code_for_configurable_constants,
-            logs::log_formatters_instantiation_code,
+            logs::{generate_event_enum, log_formatters_instantiation_code},
         },
         generated_code::GeneratedCode,
     },
-    utils::{ident, TypePath},
+    utils::{ident, safe_ident, TypePath},
 };
 
 pub(crate) fn contract_bindings(
     name: &Ident,
     abi: FullProgramABI,
+    function_attributes: &HashMap<String, Vec<Attribute>>,
     no_std: bool,
 ) -> Result<GeneratedCode> {
     if no_std {
@@ -30,12 +36,23 @@ pub(crate) fn contract_bindings(
 
     let methods_name = ident(&format!("{name}Methods"));
 
-    let contract_functions = expand_functions(&abi.functions)?;
+    let contract_functions = expand_functions(&abi.functions, function_attributes)?;
+
+    let views_name = ident(&format!("{name}Views"));
+    let view_functions = expand_view_functions(&abi.functions, function_attributes)?;
+
+    let interface_name = ident(&format!("{name}Interface"));
+    let stub_name = ident(&format!("{name}Stub"));
+    let interface_code =
+        expand_interface(&abi.functions, &interface_name, &methods_name, &stub_name)?;
 
     let configuration_struct_name = ident(&format!("{name}Configurables"));
     let constant_configuration_code =
         generate_code_for_configurable_constants(&configuration_struct_name, &abi.configurables)?;
 
+    let event_name = ident(&format!("{name}Event"));
+    let event_code = generate_event_enum(&event_name, &abi.logged_types)?;
+
     let code = quote! {
         #[derive(Debug, Clone)]
         pub struct #name<A: ::fuels::accounts::Account> {
@@ -43,6 +60,7 @@ pub(crate) fn contract_bindings(
             account: A,
             log_decoder: ::fuels::core::codec::LogDecoder,
             encoder_config: ::fuels::core::codec::EncoderConfig,
+            through_proxy: bool,
         }
 
         impl<A: ::fuels::accounts::Account> #name<A>
@@ -54,7 +72,23 @@ pub(crate) fn contract_bindings(
                 let contract_id: ::fuels::types::bech32::Bech32ContractId = contract_id.into();
                 let log_decoder = ::fuels::core::codec::LogDecoder::new(#log_formatters);
                 let encoder_config = ::fuels::core::codec::EncoderConfig::default();
-                Self { contract_id, account, log_decoder, encoder_config }
+                Self { contract_id, account, log_decoder, encoder_config, through_proxy: false }
+            }
+
+            /// Like [`Self::new`], but also accepts the contract id as a string in any of the
+            /// formats `Bech32ContractId` parses.
+            pub fn try_new(
+                contract_id: impl ::core::convert::TryInto<
+                    ::fuels::types::bech32::Bech32ContractId,
+                    Error: ::core::convert::Into<::fuels::types::errors::Error>,
+                >,
+                account: A,
+            ) -> ::fuels::types::errors::Result<Self> {
+                let contract_id: ::fuels::types::bech32::Bech32ContractId = contract_id
+                    .try_into()
+                    .map_err(::core::convert::Into::<::fuels::types::errors::Error>::into)?;
+
+                ::fuels::types::errors::Result::Ok(Self::new(contract_id, account))
             }
 
             pub fn contract_id(&self) -> &::fuels::types::bech32::Bech32ContractId {
@@ -71,7 +105,8 @@ pub(crate) fn contract_bindings(
                         contract_id: self.contract_id,
                         account,
                         log_decoder: self.log_decoder,
-                        encoder_config: self.encoder_config
+                        encoder_config: self.encoder_config,
+                        through_proxy: self.through_proxy,
                 }
             }
 
@@ -82,6 +117,14 @@ pub(crate) fn contract_bindings(
                 self
             }
 
+            /// Routes the calls through an SRC-14 proxy deployed at `contract_id`. The proxy's
+            /// current target is looked up and added as an external contract of every call.
+            pub fn with_proxy(mut self, through_proxy: bool) -> #name::<A> {
+                self.through_proxy = through_proxy;
+
+                self
+            }
+
             pub async fn get_balances(&self) -> ::fuels::types::errors::Result<::std::collections::HashMap<::fuels::types::AssetId, u64>> {
                 ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account)?
                                   .get_contract_balances(&self.contract_id)
@@ -95,6 +138,15 @@ pub(crate) fn contract_bindings(
                     account: self.account.clone(),
                     log_decoder: self.log_decoder.clone(),
                     encoder_config: self.encoder_config.clone(),
+                    through_proxy: self.through_proxy,
+                }
+            }
+
+            /// The read-only and pure functions of the contract, run against the current state
+            /// of the chain without committing anything. No funds are needed to cover the fee.
+            pub fn views(&self) -> #views_name<A> {
+                #views_name {
+                    methods: self.methods(),
                 }
             }
         }
@@ -105,12 +157,21 @@ pub(crate) fn contract_bindings(
             account: A,
             log_decoder: ::fuels::core::codec::LogDecoder,
             encoder_config: ::fuels::core::codec::EncoderConfig,
+            through_proxy: bool,
         }
 
         impl<A: ::fuels::accounts::Account> #methods_name<A> {
             #contract_functions
         }
 
+        pub struct #views_name<A: ::fuels::accounts::Account> {
+            methods: #methods_name<A>,
+        }
+
+        impl<A: ::fuels::accounts::Account> #views_name<A> {
+            #view_functions
+        }
+
         impl<A: ::fuels::accounts::Account>
             ::fuels::programs::calls::ContractDependency for #name<A>
         {
@@ -123,32 +184,218 @@ pub(crate) fn contract_bindings(
             }
         }
 
+        #interface_code
+
         #constant_configuration_code
+
+        #event_code
     };
 
     // All publicly available types generated above should be listed here.
-    let type_paths = [name, &methods_name, &configuration_struct_name]
-        .map(|type_name| TypePath::new(type_name).expect("We know the given types are not empty"))
-        .into_iter()
-        .collect();
+    let type_paths = [
+        name,
+        &methods_name,
+        &views_name,
+        &configuration_struct_name,
+        &event_name,
+        &interface_name,
+        &stub_name,
+    ]
+    .into_iter()
+    .map(|type_name| TypePath::new(type_name).expect("We know the given types are not empty"))
+    .collect();
 
     Ok(GeneratedCode::new(code, type_paths, no_std))
 }
 
-fn expand_functions(functions: &[FullABIFunction]) -> Result<TokenStream> {
+fn expand_functions(
+    functions: &[FullABIFunction],
+    function_attributes: &HashMap<String, Vec<Attribute>>,
+) -> Result<TokenStream> {
     functions
         .iter()
-        .map(expand_fn)
+        .map(|abi_fun| {
+            let attributes = function_attributes
+                .get(abi_fun.name())
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            expand_fn(abi_fun, attributes)
+        })
         .fold_ok(TokenStream::default(), |mut all_code, code| {
             all_code.append_all(code);
             all_code
         })
 }
 
+// Functions that don't write to storage get a view, see `expand_view_fn`.
+fn expand_view_functions(
+    functions: &[FullABIFunction],
+    function_attributes: &HashMap<String, Vec<Attribute>>,
+) -> Result<TokenStream> {
+    functions
+        .iter()
+        .filter(|abi_fun| {
+            let attributes = function_attributes
+                .get(abi_fun.name())
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            !writes_storage(attributes)
+        })
+        .map(expand_view_fn)
+        .fold_ok(TokenStream::default(), |mut all_code, code| {
+            all_code.append_all(code);
+            all_code
+        })
+}
+
+/// Generates the view of a read-only or pure function: it builds the same call as the method in
+/// `<Name>Methods` and simulates it with `Execution::StateReadOnly`.
+fn expand_view_fn(abi_fun: &FullABIFunction) -> Result<TokenStream> {
+    let mut generator = FunctionGenerator::new(abi_fun)?;
+
+    generator.set_docs(abi_fun.doc_strings()?);
+
+    let fn_name = safe_ident(generator.name());
+    let arg_names = generator
+        .args()
+        .iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+
+    let original_output = generator.output_type();
+    generator.set_output_type(quote! {
+        impl ::core::future::Future<
+            Output = ::fuels::types::errors::Result<::fuels::programs::responses::CallResponse<#original_output>>
+        >
+    });
+    generator.set_body(quote! {
+        let mut call_handler = self.methods.#fn_name(#(#arg_names),*);
+
+        async move {
+            call_handler
+                .simulate(::fuels::programs::calls::Execution::StateReadOnly)
+                .await
+        }
+    });
+
+    Ok(generator.generate())
+}
+
+/// Generates a trait with a typed, async method per ABI function, implements it for the
+/// `methods_name` wrapper by submitting the call and implements it for `stub_name`, an in-memory
+/// stand-in whose responses are programmed per method.
+fn expand_interface(
+    functions: &[FullABIFunction],
+    interface_name: &Ident,
+    methods_name: &Ident,
+    stub_name: &Ident,
+) -> Result<TokenStream> {
+    let mut declarations = vec![];
+    let mut method_impls = vec![];
+    let mut stub_fields = vec![];
+    let mut stub_setters = vec![];
+    let mut stub_impls = vec![];
+
+    for abi_fun in functions {
+        let generator = FunctionGenerator::new(abi_fun)?;
+        let fn_name = safe_ident(generator.name());
+        let fn_name_str = generator.name();
+        let setter_name = ident(&format!("with_{}", generator.name()));
+        let (arg_names, arg_types): (Vec<_>, Vec<_>) = generator.args().iter().unzip();
+        let output_type = generator.output_type();
+        let docs = abi_fun.doc_strings()?;
+
+        let output = quote! {
+            impl ::core::future::Future<Output = ::fuels::types::errors::Result<#output_type>> + ::core::marker::Send
+        };
+        let signature = quote! {
+            fn #fn_name(&self, #(#arg_names: #arg_types),*) -> #output
+        };
+        let response_fn = quote! {
+            dyn ::core::ops::Fn(#(#arg_types),*) -> ::fuels::types::errors::Result<#output_type>
+                + ::core::marker::Send
+                + ::core::marker::Sync
+        };
+
+        declarations.push(quote! {
+            #(#[doc = #docs])*
+            #signature;
+        });
+        method_impls.push(quote! {
+            #signature {
+                let call_handler = #methods_name::#fn_name(self, #(#arg_names),*);
+                async move { call_handler.call().await.map(|response| response.value) }
+            }
+        });
+        stub_fields.push(quote! {
+            #fn_name: ::core::option::Option<::std::sync::Arc<#response_fn>>
+        });
+        stub_setters.push(quote! {
+            #[doc = ::core::concat!("Programs the response of `", #fn_name_str, "`.")]
+            pub fn #setter_name(
+                mut self,
+                response: impl ::core::ops::Fn(#(#arg_types),*) -> ::fuels::types::errors::Result<#output_type>
+                    + ::core::marker::Send
+                    + ::core::marker::Sync
+                    + 'static,
+            ) -> Self {
+                self.#fn_name = ::core::option::Option::Some(::std::sync::Arc::new(response));
+                self
+            }
+        });
+        stub_impls.push(quote! {
+            #signature {
+                let response = match &self.#fn_name {
+                    ::core::option::Option::Some(response) => response(#(#arg_names),*),
+                    ::core::option::Option::None => ::core::result::Result::Err(
+                        ::fuels::types::errors::Error::Other(::std::format!(
+                            "no response programmed for `{}`",
+                            #fn_name_str
+                        )),
+                    ),
+                };
+
+                ::core::future::ready(response)
+            }
+        });
+    }
+
+    Ok(quote! {
+        pub trait #interface_name {
+            #(#declarations)*
+        }
+
+        impl<A: ::fuels::accounts::Account> #interface_name for #methods_name<A> {
+            #(#method_impls)*
+        }
+
+        /// An in-memory implementation of the interface for tests. Responses are programmed with
+        /// synchronous `Fn` closures, so they can't `await`, and calls are not recorded; capture
+        /// shared state, e.g. an `Arc<Mutex<_>>`, in the closures to track them.
+        #[derive(::core::clone::Clone, ::core::default::Default)]
+        pub struct #stub_name {
+            #(#stub_fields,)*
+        }
+
+        impl #stub_name {
+            #(#stub_setters)*
+        }
+
+        impl #interface_name for #stub_name {
+            #(#stub_impls)*
+        }
+    })
+}
+
 /// Transforms a function defined in [`FullABIFunction`] into a [`TokenStream`]
 /// that represents that same function signature as a Rust-native function
 /// declaration.
-pub(crate) fn expand_fn(abi_fun: &FullABIFunction) -> Result<TokenStream> {
+pub(crate) fn expand_fn(
+    abi_fun: &FullABIFunction,
+    attributes: &[Attribute],
+) -> Result<TokenStream> {
     let mut generator = FunctionGenerator::new(abi_fun)?;
 
     generator.set_docs(abi_fun.doc_strings()?);
@@ -171,25 +418,41 @@ pub(crate) fn expand_fn(abi_fun: &FullABIFunction) -> Result<TokenStream> {
                 #is_payable,
                 self.encoder_config.clone(),
             )
+            .with_proxy(self.through_proxy)
+    };
+    let body = if writes_storage(attributes) {
+        quote! { #body.with_writes_storage(true) }
+    } else {
+        body
     };
     generator.set_body(body);
 
     Ok(generator.generate())
 }
 
+/// Whether the function is declared with `#[storage(write)]` or `#[storage(read, write)]`.
+fn writes_storage(attributes: &[Attribute]) -> bool {
+    attributes.iter().any(|attribute| {
+        attribute.name == "storage" && attribute.arguments.iter().any(|arg| arg == "write")
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
     use fuel_abi_types::abi::{
-        full_program::FullABIFunction,
+        full_program::{FullABIFunction, FullProgramABI},
         program::Attribute,
         unified_program::{UnifiedABIFunction, UnifiedTypeApplication, UnifiedTypeDeclaration},
     };
     use pretty_assertions::assert_eq;
     use quote::quote;
 
-    use crate::{error::Result, program_bindings::abigen::bindings::contract::expand_fn};
+    use super::{contract_bindings, expand_interface, expand_view_functions};
+    use crate::{
+        error::Result, program_bindings::abigen::bindings::contract::expand_fn, utils::ident,
+    };
 
     #[test]
     fn expand_contract_method_simple() -> Result<()> {
@@ -226,7 +489,10 @@ mod tests {
         ]
         .into_iter()
         .collect::<HashMap<_, _>>();
-        let result = expand_fn(&FullABIFunction::from_counterpart(&the_function, &types)?);
+        let result = expand_fn(
+            &FullABIFunction::from_counterpart(&the_function, &types)?,
+            &[],
+        );
 
         let expected = quote! {
             #[doc = "This is a doc string"]
@@ -240,6 +506,7 @@ mod tests {
                     false,
                     self.encoder_config.clone(),
                 )
+                .with_proxy(self.through_proxy)
             }
         };
 
@@ -248,6 +515,60 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn expand_contract_method_writing_storage() -> Result<()> {
+        let storage = |arguments: &[&str]| Attribute {
+            name: "storage".to_string(),
+            arguments: arguments.iter().map(ToString::to_string).collect(),
+        };
+        let the_function = UnifiedABIFunction {
+            name: "increment".to_string(),
+            ..Default::default()
+        };
+        let types = [(
+            0,
+            UnifiedTypeDeclaration {
+                type_id: 0,
+                type_field: String::from("()"),
+                ..Default::default()
+            },
+        )]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let the_function = FullABIFunction::from_counterpart(&the_function, &types)?;
+
+        let read_only = expand_fn(&the_function, &[storage(&["read"])])?;
+        let read_write = expand_fn(&the_function, &[storage(&["read", "write"])])?;
+
+        let call = quote! {
+            ::fuels::programs::calls::CallHandler::new_contract_call(
+                self.contract_id.clone(),
+                self.account.clone(),
+                ::fuels::core::codec::encode_fn_selector("increment"),
+                &[],
+                self.log_decoder.clone(),
+                false,
+                self.encoder_config.clone(),
+            )
+            .with_proxy(self.through_proxy)
+        };
+        let expected_read_only = quote! {
+            pub fn increment(&self,) -> ::fuels::programs::calls::CallHandler<A, ::fuels::programs::calls::ContractCall, ()> {
+                #call
+            }
+        };
+        let expected_read_write = quote! {
+            pub fn increment(&self,) -> ::fuels::programs::calls::CallHandler<A, ::fuels::programs::calls::ContractCall, ()> {
+                #call.with_writes_storage(true)
+            }
+        };
+
+        assert_eq!(read_only.to_string(), expected_read_only.to_string());
+        assert_eq!(read_write.to_string(), expected_read_write.to_string());
+
+        Ok(())
+    }
+
     #[test]
     fn expand_contract_method_complex() -> Result<()> {
         // given
@@ -336,7 +657,10 @@ mod tests {
         .collect::<HashMap<_, _>>();
 
         // when
-        let result = expand_fn(&FullABIFunction::from_counterpart(&the_function, &types)?);
+        let result = expand_fn(
+            &FullABIFunction::from_counterpart(&the_function, &types)?,
+            &[],
+        );
 
         //then
 
@@ -359,6 +683,108 @@ mod tests {
                     false,
                     self.encoder_config.clone(),
                 )
+                .with_proxy(self.through_proxy)
+            }
+        };
+
+        assert_eq!(result?.to_string(), expected.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn expand_contract_interface() -> Result<()> {
+        // given
+        let the_function = UnifiedABIFunction {
+            inputs: vec![UnifiedTypeApplication {
+                name: String::from("bimbam"),
+                type_id: 1,
+                ..Default::default()
+            }],
+            name: "hello_world".to_string(),
+            ..Default::default()
+        };
+        let types = [
+            (
+                0,
+                UnifiedTypeDeclaration {
+                    type_id: 0,
+                    type_field: String::from("()"),
+                    ..Default::default()
+                },
+            ),
+            (
+                1,
+                UnifiedTypeDeclaration {
+                    type_id: 1,
+                    type_field: String::from("bool"),
+                    ..Default::default()
+                },
+            ),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let functions = [FullABIFunction::from_counterpart(&the_function, &types)?];
+
+        // when
+        let result = expand_interface(
+            &functions,
+            &ident("MyContractInterface"),
+            &ident("MyContractMethods"),
+            &ident("MyContractStub"),
+        );
+
+        // then
+        let expected = quote! {
+            pub trait MyContractInterface {
+                fn hello_world(&self, bimbam: ::core::primitive::bool) -> impl ::core::future::Future<Output = ::fuels::types::errors::Result<()>> + ::core::marker::Send;
+            }
+
+            impl<A: ::fuels::accounts::Account> MyContractInterface for MyContractMethods<A> {
+                fn hello_world(&self, bimbam: ::core::primitive::bool) -> impl ::core::future::Future<Output = ::fuels::types::errors::Result<()>> + ::core::marker::Send {
+                    let call_handler = MyContractMethods::hello_world(self, bimbam);
+                    async move { call_handler.call().await.map(|response| response.value) }
+                }
+            }
+
+            /// An in-memory implementation of the interface for tests. Responses are programmed with
+            /// synchronous `Fn` closures, so they can't `await`, and calls are not recorded; capture
+            /// shared state, e.g. an `Arc<Mutex<_>>`, in the closures to track them.
+            #[derive(::core::clone::Clone, ::core::default::Default)]
+            pub struct MyContractStub {
+                hello_world: ::core::option::Option<::std::sync::Arc<dyn ::core::ops::Fn(::core::primitive::bool) -> ::fuels::types::errors::Result<()>
+                    + ::core::marker::Send
+                    + ::core::marker::Sync>>,
+            }
+
+            impl MyContractStub {
+                #[doc = ::core::concat!("Programs the response of `", "hello_world", "`.")]
+                pub fn with_hello_world(
+                    mut self,
+                    response: impl ::core::ops::Fn(::core::primitive::bool) -> ::fuels::types::errors::Result<()>
+                        + ::core::marker::Send
+                        + ::core::marker::Sync
+                        + 'static,
+                ) -> Self {
+                    self.hello_world = ::core::option::Option::Some(::std::sync::Arc::new(response));
+                    self
+                }
+            }
+
+            impl MyContractInterface for MyContractStub {
+                fn hello_world(&self, bimbam: ::core::primitive::bool) -> impl ::core::future::Future<Output = ::fuels::types::errors::Result<()>> + ::core::marker::Send {
+                    let response = match &self.hello_world {
+                        ::core::option::Option::Some(response) => response(bimbam),
+                        ::core::option::Option::None => ::core::result::Result::Err(
+                            ::fuels::types::errors::Error::Other(::std::format!(
+                                "no response programmed for `{}`",
+                                "hello_world"
+                            )),
+                        ),
+                    };
+
+                    ::core::future::ready(response)
+                }
             }
         };
 
@@ -366,4 +792,72 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn interface_is_generated_alongside_the_bindings() -> Result<()> {
+        // given
+        let abi = FullProgramABI::from_json_abi(
+            r#"{
+                "programType": "contract",
+                "specVersion": "1",
+                "encodingVersion": "1",
+                "concreteTypes": [ { "type": "()", "concreteTypeId": "01" } ],
+                "metadataTypes": [],
+                "functions": [ { "name": "noop", "inputs": [], "output": "01", "attributes": null } ],
+                "loggedTypes": [],
+                "messagesTypes": [],
+                "configurables": []
+            }"#,
+        )?;
+        let name = ident("MyContract");
+
+        // when
+        let code = contract_bindings(&name, abi, &HashMap::new(), false)?;
+
+        // then
+        let code = code.code().to_string();
+        assert!(code.contains("trait MyContractInterface"));
+        assert!(code.contains("struct MyContractStub"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn views_are_only_generated_for_functions_not_writing_storage() -> Result<()> {
+        // given
+        let json_abi = r#"{
+            "programType": "contract",
+            "specVersion": "1",
+            "encodingVersion": "1",
+            "concreteTypes": [ { "type": "()", "concreteTypeId": "01" } ],
+            "metadataTypes": [],
+            "functions": [
+                { "name": "increment", "inputs": [], "output": "01", "attributes": null },
+                { "name": "read", "inputs": [], "output": "01", "attributes": null },
+                { "name": "noop", "inputs": [], "output": "01", "attributes": null }
+            ],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }"#;
+        let abi = FullProgramABI::from_json_abi(json_abi)?;
+        let storage = |arguments: &[&str]| Attribute {
+            name: "storage".to_string(),
+            arguments: arguments.iter().map(ToString::to_string).collect(),
+        };
+        let function_attributes = HashMap::from([
+            ("increment".to_string(), vec![storage(&["read", "write"])]),
+            ("read".to_string(), vec![storage(&["read"])]),
+        ]);
+
+        // when
+        let views = expand_view_functions(&abi.functions, &function_attributes)?.to_string();
+
+        // then
+        assert!(!views.contains("fn increment"));
+        assert!(views.contains("fn read"));
+        assert!(views.contains("fn noop"));
+
+        Ok(())
+    }
 }
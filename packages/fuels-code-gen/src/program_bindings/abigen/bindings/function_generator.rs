@@ -71,6 +71,14 @@ impl FunctionGenerator {
         quote! {[#(::fuels::core::traits::Tokenizable::into_token(#arg_names)),*]}
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn args(&self) -> &Components {
+        &self.args
+    }
+
     pub fn set_output_type(&mut self, output_type: TokenStream) -> &mut Self {
         self.output_type = output_type;
         self
@@ -0,0 +1,213 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    error::{error, Result},
+    program_bindings::abigen::{Abigen, AbigenTarget},
+};
+
+const DEFAULT_FILE_NAME: &str = "abigen_bindings.rs";
+const HEADER: &str = "// This file is generated by `fuels-code-gen`. Do not edit it manually.\n\n";
+
+/// Writes the bindings of the given targets to a formatted Rust file. Meant to be used from a
+/// build script as an alternative to the `abigen!` macro, so that the generated code can be read
+/// by IDEs and reviewed.
+///
+/// By default the bindings are written to `abigen_bindings.rs` in `OUT_DIR` and can be included
+/// with `include!(concat!(env!("OUT_DIR"), "/abigen_bindings.rs"));`. Use
+/// [`BindingsFile::with_path`] to write them to a checked-in file instead and
+/// [`BindingsFile::check`] to verify that file is up to date.
+#[derive(Debug, Clone)]
+pub struct BindingsFile {
+    targets: Vec<AbigenTarget>,
+    no_std: bool,
+    path: Option<PathBuf>,
+}
+
+impl BindingsFile {
+    pub fn new(targets: Vec<AbigenTarget>) -> Self {
+        Self {
+            targets,
+            no_std: false,
+            path: None,
+        }
+    }
+
+    /// Don't use the Rust std library in the generated code.
+    pub fn with_no_std(mut self, no_std: bool) -> Self {
+        self.no_std = no_std;
+        self
+    }
+
+    /// Path of the generated file. Defaults to `abigen_bindings.rs` in `OUT_DIR`.
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Returns the path the bindings are written to.
+    pub fn path(&self) -> Result<PathBuf> {
+        if let Some(path) = &self.path {
+            return Ok(path.clone());
+        }
+
+        let out_dir = env::var_os("OUT_DIR")
+            .ok_or_else(|| error!("`OUT_DIR` is not set. Is this running from a build script?"))?;
+
+        Ok(Path::new(&out_dir).join(DEFAULT_FILE_NAME))
+    }
+
+    /// Generates the formatted bindings.
+    pub fn render(&self) -> Result<String> {
+        let tokens =
+            Abigen::generate_with_recompile_trigger(self.targets.clone(), self.no_std, false)?;
+        let file = syn::parse2::<syn::File>(tokens)
+            .map_err(|e| error!("generated bindings are not valid Rust: {e}"))?;
+
+        Ok(format!("{HEADER}{}", prettyplease::unparse(&file)))
+    }
+
+    /// Writes the bindings, leaving the file untouched if it is already up to date, and returns
+    /// its path. Emits `cargo:rerun-if-changed` for every ABI file.
+    pub fn write(&self) -> Result<PathBuf> {
+        self.emit_rerun_if_changed();
+
+        let path = self.path()?;
+        let code = self.render()?;
+
+        if fs::read_to_string(&path).ok().as_deref() != Some(code.as_str()) {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(|e| {
+                    error!("failed to create directory {}: ", dir.display()).combine(e)
+                })?;
+            }
+            fs::write(&path, code).map_err(|e| {
+                error!("failed to write bindings to {}: ", path.display()).combine(e)
+            })?;
+        }
+
+        Ok(path)
+    }
+
+    /// Fails if the bindings at [`BindingsFile::path`] differ from the ones that would be
+    /// generated. Emits `cargo:rerun-if-changed` for every ABI file.
+    pub fn check(&self) -> Result<()> {
+        self.emit_rerun_if_changed();
+
+        let path = self.path()?;
+        let existing = fs::read_to_string(&path)
+            .map_err(|e| error!("failed to read bindings from {}: ", path.display()).combine(e))?;
+
+        if existing != self.render()? {
+            return Err(error!(
+                "bindings in {} are out of date, regenerate them with `BindingsFile::write`",
+                path.display()
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn emit_rerun_if_changed(&self) {
+        for path in self
+            .targets
+            .iter()
+            .filter_map(|target| target.source().path())
+        {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::program_bindings::abigen::{Abi, ProgramType};
+
+    const ABI: &str = r#"{
+        "programType": "contract",
+        "specVersion": "1",
+        "encodingVersion": "1",
+        "concreteTypes": [
+            { "type": "u64", "concreteTypeId": "01" },
+            { "type": "struct MyStruct", "concreteTypeId": "02", "metadataTypeId": 0 }
+        ],
+        "metadataTypes": [
+            { "type": "struct MyStruct", "metadataTypeId": 0, "components": [ { "name": "a", "typeId": "01" } ] }
+        ],
+        "functions": [
+            { "name": "get", "inputs": [ { "name": "arg", "concreteTypeId": "02" } ], "output": "01", "attributes": null }
+        ],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }"#;
+
+    fn bindings_file(path: &Path) -> BindingsFile {
+        let target = AbigenTarget::new(
+            "MyContract".to_string(),
+            ABI.parse::<Abi>().unwrap(),
+            ProgramType::Contract,
+        );
+
+        BindingsFile::new(vec![target]).with_path(path)
+    }
+
+    #[test]
+    fn written_bindings_are_formatted() -> Result<()> {
+        // given
+        let dir = tempdir()?;
+        let bindings = bindings_file(&dir.path().join("bindings.rs"));
+
+        // when
+        let path = bindings.write()?;
+
+        // then
+        let code = fs::read_to_string(path)?;
+        assert!(code.starts_with(HEADER));
+        assert!(code.contains("pub struct MyContract<A: ::fuels::accounts::Account> {\n"));
+        assert!(code.contains("pub struct MyStruct {\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_passes_for_up_to_date_bindings() -> Result<()> {
+        // given
+        let dir = tempdir()?;
+        let bindings = bindings_file(&dir.path().join("bindings.rs"));
+        bindings.write()?;
+
+        // when
+        let result = bindings.check();
+
+        // then
+        assert!(result.is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_fails_for_stale_or_missing_bindings() -> Result<()> {
+        // given
+        let dir = tempdir()?;
+        let path = dir.path().join("bindings.rs");
+        let bindings = bindings_file(&path);
+
+        // when
+        let missing = bindings.check();
+        fs::write(&path, "// outdated")?;
+        let stale = bindings.check();
+
+        // then
+        assert!(missing.is_err());
+        let err = stale.expect_err("should fail for stale bindings");
+        assert!(err.to_string().contains("are out of date"));
+
+        Ok(())
+    }
+}
@@ -1,8 +1,15 @@
+use std::collections::HashSet;
+
 use fuel_abi_types::abi::full_program::FullLoggedType;
-use proc_macro2::TokenStream;
+use inflector::Inflector;
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::program_bindings::resolved_type::TypeResolver;
+use crate::{
+    error::Result,
+    program_bindings::resolved_type::{GenericType, ResolvedType, TypeResolver},
+    utils::ident,
+};
 
 pub(crate) fn log_formatters_instantiation_code(
     contract_id: TokenStream,
@@ -51,3 +58,205 @@ fn generate_log_id_log_formatter_pairs(resolved_logs: &[ResolvedLog]) -> Vec<Tok
         })
         .collect()
 }
+
+/// Generates the `{Name}Event` enum holding one variant per logged type, so that the logs of a
+/// call can be decoded in order and handled with an exhaustive `match`.
+pub(crate) fn generate_event_enum(
+    event_name: &Ident,
+    logged_types: &[FullLoggedType],
+) -> Result<TokenStream> {
+    let mut used_names = HashSet::new();
+    let (variants, decode_arms): (Vec<_>, Vec<_>) = logged_types
+        .iter()
+        .map(|logged_type| {
+            let resolved_type = TypeResolver::default().resolve(&logged_type.application)?;
+
+            let base_name = variant_name(&resolved_type);
+            let mut name = base_name.clone();
+            let mut suffix = 1;
+            while !used_names.insert(name.clone()) {
+                suffix += 1;
+                name = format!("{base_name}{suffix}");
+            }
+            let variant = ident(&name);
+            let log_id = &logged_type.log_id;
+
+            Ok((
+                quote! { #variant(#resolved_type) },
+                quote! {
+                    #log_id => ::core::option::Option::Some(
+                        ::fuels::core::codec::try_from_bytes::<#resolved_type>(data, decoder_config)
+                            .map(Self::#variant)
+                    )
+                },
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    Ok(quote! {
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum #event_name {
+            #(#variants),*
+        }
+
+        impl #event_name {
+            /// Decodes the logs of the contracts in `contract_ids` found in `receipts` as events, in
+            /// the order in which they were logged.
+            pub fn decode_events(
+                receipts: &[::fuels::tx::Receipt],
+                contract_ids: &[::fuels::types::ContractId],
+            ) -> ::fuels::types::errors::Result<::std::vec::Vec<::fuels::core::codec::ContractEvent<Self>>> {
+                ::fuels::core::codec::decode_events(receipts, contract_ids, ::core::default::Default::default())
+            }
+        }
+
+        impl ::fuels::core::codec::Event for #event_name {
+            #[allow(unused_variables)]
+            fn decode(
+                log_id: &str,
+                data: &[u8],
+                decoder_config: ::fuels::core::codec::DecoderConfig,
+            ) -> ::core::option::Option<::fuels::types::errors::Result<Self>> {
+                match log_id {
+                    #(#decode_arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    })
+}
+
+/// Derives a variant name from the logged type, e.g. `MyStruct<u64>` becomes `MyStructU64` and
+/// `(u8, bool)` becomes `TupleU8Bool`.
+fn variant_name(resolved_type: &ResolvedType) -> String {
+    match resolved_type {
+        ResolvedType::Unit => "Unit".to_string(),
+        ResolvedType::Primitive(path) => path
+            .ident()
+            .map(|ident| ident.to_string().to_pascal_case())
+            .unwrap_or_default(),
+        ResolvedType::StructOrEnum { path, generics } => {
+            let name = path.ident().map(ToString::to_string).unwrap_or_default();
+            generics
+                .iter()
+                .fold(name, |name, generic| name + &variant_name(generic))
+        }
+        ResolvedType::Array(element, len) => format!("{}Array{len}", variant_name(element)),
+        ResolvedType::Tuple(elements) => {
+            elements.iter().fold("Tuple".to_string(), |name, element| {
+                name + &variant_name(element)
+            })
+        }
+        ResolvedType::Generic(GenericType::Named(name)) => name.to_string().to_pascal_case(),
+        ResolvedType::Generic(GenericType::Constant(constant)) => constant.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_abi_types::abi::full_program::{FullTypeApplication, FullTypeDeclaration};
+
+    use super::*;
+
+    fn logged_type(
+        log_id: &str,
+        type_field: &str,
+        type_arguments: Vec<FullTypeApplication>,
+    ) -> FullLoggedType {
+        FullLoggedType {
+            log_id: log_id.to_string(),
+            application: FullTypeApplication {
+                name: "".to_string(),
+                type_decl: FullTypeDeclaration {
+                    type_field: type_field.to_string(),
+                    components: vec![],
+                    type_parameters: vec![],
+                },
+                type_arguments,
+            },
+        }
+    }
+
+    #[test]
+    fn event_enum_has_a_variant_per_logged_type() -> Result<()> {
+        // given
+        let u64_application = logged_type("0", "u64", vec![]).application;
+        let logged_types = [
+            logged_type("1", "u64", vec![]),
+            logged_type("2", "struct MyStruct", vec![u64_application]),
+            logged_type("3", "struct other::MyStruct", vec![]),
+        ];
+
+        // when
+        let code = generate_event_enum(&ident("MyContractEvent"), &logged_types)?;
+
+        // then
+        let expected = quote! {
+            #[derive(Clone, Debug, PartialEq)]
+            pub enum MyContractEvent {
+                U64(::core::primitive::u64),
+                MyStructU64(self::MyStruct<::core::primitive::u64>),
+                MyStruct(self::other::MyStruct)
+            }
+
+            impl MyContractEvent {
+                /// Decodes the logs of the contracts in `contract_ids` found in `receipts` as events, in
+                /// the order in which they were logged.
+                pub fn decode_events(
+                    receipts: &[::fuels::tx::Receipt],
+                    contract_ids: &[::fuels::types::ContractId],
+                ) -> ::fuels::types::errors::Result<::std::vec::Vec<::fuels::core::codec::ContractEvent<Self>>> {
+                    ::fuels::core::codec::decode_events(receipts, contract_ids, ::core::default::Default::default())
+                }
+            }
+
+            impl ::fuels::core::codec::Event for MyContractEvent {
+                #[allow(unused_variables)]
+                fn decode(
+                    log_id: &str,
+                    data: &[u8],
+                    decoder_config: ::fuels::core::codec::DecoderConfig,
+                ) -> ::core::option::Option<::fuels::types::errors::Result<Self>> {
+                    match log_id {
+                        "1" => ::core::option::Option::Some(
+                            ::fuels::core::codec::try_from_bytes::<::core::primitive::u64>(data, decoder_config)
+                                .map(Self::U64)
+                        ),
+                        "2" => ::core::option::Option::Some(
+                            ::fuels::core::codec::try_from_bytes::<self::MyStruct<::core::primitive::u64> >(data, decoder_config)
+                                .map(Self::MyStructU64)
+                        ),
+                        "3" => ::core::option::Option::Some(
+                            ::fuels::core::codec::try_from_bytes::<self::other::MyStruct>(data, decoder_config)
+                                .map(Self::MyStruct)
+                        ),
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn clashing_variant_names_are_disambiguated() -> Result<()> {
+        // given
+        let logged_types = [
+            logged_type("1", "struct a::Transfer", vec![]),
+            logged_type("2", "struct b::Transfer", vec![]),
+        ];
+
+        // when
+        let code = generate_event_enum(&ident("MyContractEvent"), &logged_types)?.to_string();
+
+        // then
+        assert!(code.contains(&quote! { Transfer(self::a::Transfer) }.to_string()));
+        assert!(code.contains(&quote! { Transfer2(self::b::Transfer) }.to_string()));
+
+        Ok(())
+    }
+}
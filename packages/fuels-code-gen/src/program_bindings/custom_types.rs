@@ -2,6 +2,7 @@ use std::collections::HashSet;
 
 use fuel_abi_types::abi::full_program::FullTypeDeclaration;
 use itertools::Itertools;
+use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::{
@@ -28,10 +29,12 @@ mod utils;
 ///
 /// * `types`: Types you wish to generate Rust code for.
 /// * `shared_types`: Types that are shared between multiple
-///                   contracts/scripts/predicates and thus generated elsewhere.
+///   contracts/scripts/predicates and thus generated elsewhere.
+/// * `derives`: Additional derives for the generated types.
 pub(crate) fn generate_types<'a, T: IntoIterator<Item = &'a FullTypeDeclaration>>(
     types: T,
     shared_types: &HashSet<FullTypeDeclaration>,
+    derives: &[TokenStream],
     no_std: bool,
 ) -> Result<GeneratedCode> {
     types
@@ -41,9 +44,9 @@ pub(crate) fn generate_types<'a, T: IntoIterator<Item = &'a FullTypeDeclaration>
             if shared_types.contains(ttype) {
                 reexport_the_shared_type(ttype, no_std)
             } else if ttype.is_struct_type() {
-                expand_custom_struct(ttype, no_std)
+                expand_custom_struct(ttype, derives, no_std)
             } else {
-                expand_custom_enum(ttype, no_std)
+                expand_custom_enum(ttype, derives, no_std)
             }
         })
         .fold_ok(GeneratedCode::default(), |acc, generated_code| {
@@ -165,7 +168,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual = expand_custom_enum(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_enum(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &[],
+            false,
+        )?;
 
         let expected = quote! {
             #[allow(clippy::enum_variant_names)]
@@ -198,8 +205,12 @@ mod tests {
         };
         let types = [(0, p.clone())].into_iter().collect::<HashMap<_, _>>();
 
-        expand_custom_enum(&FullTypeDeclaration::from_counterpart(&p, &types), false)
-            .expect_err("Was able to construct an enum without variants");
+        expand_custom_enum(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &[],
+            false,
+        )
+        .expect_err("Was able to construct an enum without variants");
 
         Ok(())
     }
@@ -249,7 +260,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual = expand_custom_enum(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_enum(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &[],
+            false,
+        )?;
 
         let expected = quote! {
             #[allow(clippy::enum_variant_names)]
@@ -311,7 +326,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual = expand_custom_enum(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_enum(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &[],
+            false,
+        )?;
 
         let expected = quote! {
             #[allow(clippy::enum_variant_names)]
@@ -385,7 +404,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual = expand_custom_enum(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_enum(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &[],
+            false,
+        )?;
 
         let expected = quote! {
             #[allow(clippy::enum_variant_names)]
@@ -460,8 +483,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual =
-            expand_custom_struct(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_struct(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &[],
+            false,
+        )?;
 
         let expected = quote! {
             #[derive(
@@ -498,6 +524,65 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_expand_custom_struct_with_extra_derives() -> Result<()> {
+        let p = UnifiedTypeDeclaration {
+            type_id: 0,
+            type_field: String::from("struct Cocktail"),
+            components: Some(vec![UnifiedTypeApplication {
+                name: String::from("mojito"),
+                type_id: 1,
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let types = [
+            (0, p.clone()),
+            (
+                1,
+                UnifiedTypeDeclaration {
+                    type_id: 1,
+                    type_field: String::from("u32"),
+                    ..Default::default()
+                },
+            ),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+        let actual = expand_custom_struct(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &[quote! {Hash}, quote! {::serde::Serialize}],
+            false,
+        )?;
+
+        let expected = quote! {
+            #[derive(
+                Clone,
+                Debug,
+                Eq,
+                PartialEq,
+                ::fuels::macros::Parameterize,
+                ::fuels::macros::Tokenizable,
+                ::fuels::macros::TryFrom,
+                Hash,
+                ::serde::Serialize,
+            )]
+            pub struct Cocktail {
+                pub mojito: ::core::primitive::u32,
+            }
+            impl Cocktail {
+                pub fn new(mojito: ::core::primitive::u32,) -> Self {
+                    Self { mojito, }
+                }
+            }
+        };
+
+        assert_eq!(actual.code().to_string(), expected.to_string());
+
+        Ok(())
+    }
+
     #[test]
     fn test_struct_with_no_fields_can_be_constructed() -> Result<()> {
         let p = UnifiedTypeDeclaration {
@@ -508,8 +593,11 @@ mod tests {
         };
         let types = [(0, p.clone())].into_iter().collect::<HashMap<_, _>>();
 
-        let actual =
-            expand_custom_struct(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_struct(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &[],
+            false,
+        )?;
 
         let expected = quote! {
             #[derive(
@@ -577,8 +665,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual =
-            expand_custom_struct(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_struct(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &[],
+            false,
+        )?;
 
         let expected = quote! {
             #[derive(
@@ -619,7 +710,7 @@ mod tests {
         let shared_types = HashSet::from([type_decl.clone()]);
 
         // when
-        let generated_code = generate_types(&[type_decl], &shared_types, false).unwrap();
+        let generated_code = generate_types(&[type_decl], &shared_types, &[], false).unwrap();
 
         // then
         let expected_code = quote! {
@@ -676,6 +676,30 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_resolve_signed_integer() -> Result<()> {
+        test_resolve_first_type(
+            ":: fuels :: types :: I64",
+            &[
+                UnifiedTypeDeclaration {
+                    type_id: 0,
+                    type_field: "struct sway_libs::signed_integers::i64::I64".to_string(),
+                    components: Some(vec![UnifiedTypeApplication {
+                        name: "underlying".to_string(),
+                        type_id: 1,
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+                UnifiedTypeDeclaration {
+                    type_id: 1,
+                    type_field: "u64".to_string(),
+                    ..Default::default()
+                },
+            ],
+        )
+    }
+
     #[test]
     fn test_resolve_enum() -> Result<()> {
         test_resolve_first_type(
@@ -145,6 +145,27 @@ pub(crate) fn sdk_provided_custom_types_lookup() -> HashMap<TypePath, TypePath>
             "std::vm::evm::evm_address::EvmAddress",
             "::fuels::types::EvmAddress",
         ),
+        ("sway_libs::signed_integers::i8::I8", "::fuels::types::I8"),
+        (
+            "sway_libs::signed_integers::i16::I16",
+            "::fuels::types::I16",
+        ),
+        (
+            "sway_libs::signed_integers::i32::I32",
+            "::fuels::types::I32",
+        ),
+        (
+            "sway_libs::signed_integers::i64::I64",
+            "::fuels::types::I64",
+        ),
+        (
+            "sway_libs::signed_integers::i128::I128",
+            "::fuels::types::I128",
+        ),
+        (
+            "sway_libs::signed_integers::i256::I256",
+            "::fuels::types::I256",
+        ),
     ]
     .into_iter()
     .map(|(original_type_path, provided_type_path)| {
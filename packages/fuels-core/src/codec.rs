@@ -1,5 +1,7 @@
 mod abi_decoder;
 mod abi_encoder;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
 mod function_selector;
 mod logs;
 mod utils;
@@ -26,6 +28,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use fuel_tx::Receipt;
+
     use super::*;
     use crate::{
         constants::WORD_SIZE,
@@ -112,4 +116,74 @@ mod tests {
         // then
         assert_eq!(decoded, input);
     }
+
+    #[test]
+    fn events_are_decoded_in_log_order() -> Result<()> {
+        // given
+        #[derive(Debug, PartialEq)]
+        enum TestEvent {
+            Number(u64),
+            Flag(bool),
+        }
+
+        impl Event for TestEvent {
+            fn decode(
+                log_id: &str,
+                data: &[u8],
+                decoder_config: DecoderConfig,
+            ) -> Option<Result<Self>> {
+                match log_id {
+                    "1" => Some(try_from_bytes(data, decoder_config).map(Self::Number)),
+                    "2" => Some(try_from_bytes(data, decoder_config).map(Self::Flag)),
+                    _ => None,
+                }
+            }
+        }
+
+        let log_data = |contract_id: ContractId, rb: u64, data: Vec<u8>| Receipt::LogData {
+            id: contract_id,
+            ra: 0,
+            rb,
+            ptr: 0,
+            len: data.len() as u64,
+            digest: Default::default(),
+            pc: 0,
+            is: 0,
+            data: Some(data),
+        };
+        let first_contract = ContractId::new([1; 32]);
+        let second_contract = ContractId::new([2; 32]);
+        let foreign_contract = ContractId::new([3; 32]);
+        let receipts = [
+            log_data(first_contract, 2, vec![1]),
+            log_data(foreign_contract, 1, 7u64.to_be_bytes().to_vec()),
+            log_data(second_contract, 3, vec![0; 8]),
+            log_data(second_contract, 1, 42u64.to_be_bytes().to_vec()),
+            log_data(foreign_contract, 2, vec![0]),
+        ];
+
+        // when
+        let events = decode_events::<TestEvent>(
+            &receipts,
+            &[first_contract, second_contract],
+            DecoderConfig::default(),
+        )?;
+
+        // then
+        assert_eq!(
+            events,
+            vec![
+                ContractEvent {
+                    contract_id: first_contract,
+                    event: TestEvent::Flag(true),
+                },
+                ContractEvent {
+                    contract_id: second_contract,
+                    event: TestEvent::Number(42),
+                },
+            ]
+        );
+
+        Ok(())
+    }
 }
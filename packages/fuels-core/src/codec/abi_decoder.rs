@@ -44,7 +44,7 @@ impl ABIDecoder {
     /// # Arguments
     ///
     /// * `param_type`: The `ParamType` of the type we expect is encoded
-    ///                  inside `bytes`.
+    ///   inside `bytes`.
     /// * `bytes`:       The bytes to be used in the decoding process.
     /// # Examples
     ///
@@ -87,7 +87,7 @@ impl ABIDecoder {
     /// # Arguments
     ///
     /// * `param_type`: The `ParamType` of the type we expect is encoded
-    ///                  inside `bytes`.
+    ///   inside `bytes`.
     /// * `bytes`:       The bytes to be used in the decoding process.
     /// # Examples
     ///
@@ -0,0 +1,337 @@
+use std::fmt::Debug;
+
+use proptest::{
+    collection::vec,
+    prelude::{any, prop_assert_eq, BoxedStrategy, Just, Strategy, TestCaseError},
+    strategy::Union,
+};
+
+use crate::{
+    codec::{ABIDecoder, ABIEncoder, DecoderConfig, EncoderConfig},
+    traits::{Parameterize, Tokenizable},
+    types::{
+        errors::{error, Result},
+        param_types::{EnumVariants, ParamType},
+        StaticStringToken, Token, U256,
+    },
+};
+
+/// Upper bound on the number of elements generated for vectors. Lower if the tokens would
+/// otherwise exceed the `max_tokens` of the `DecoderConfig`.
+pub const MAX_VECTOR_LEN: usize = 8;
+/// Upper bound on the number of bytes generated for `Bytes`, `RawSlice`, `String` and string
+/// slices.
+pub const MAX_BYTES_LEN: usize = 64;
+
+/// A strategy generating `Token`s of the given `param_type` that the `ABIDecoder` configured with
+/// `config` can decode.
+///
+/// Every variant of an enum, empty and full vectors and strings of the maximum length are
+/// generated, to catch codec bugs only surfacing on odd inputs. Fails if `param_type` is nested
+/// deeper than `config.max_depth` or has more tokens than `config.max_tokens` even with empty
+/// vectors.
+pub fn token_strategy(
+    param_type: &ParamType,
+    config: DecoderConfig,
+) -> Result<BoxedStrategy<Token>> {
+    let depth = depth(param_type);
+    if depth > config.max_depth {
+        return Err(error!(
+            Codec,
+            "`{param_type:?}` has a depth of {depth}, over the `max_depth` of {}", config.max_depth
+        ));
+    }
+
+    let max_vector_len = (0..=MAX_VECTOR_LEN)
+        .rev()
+        .find(|&len| max_token_count(param_type, len) <= config.max_tokens)
+        .ok_or_else(|| {
+            error!(
+                Codec,
+                "`{param_type:?}` has more tokens than the `max_tokens` of {}", config.max_tokens
+            )
+        })?;
+
+    Ok(strategy(param_type, max_vector_len))
+}
+
+/// A strategy generating values of `T`, e.g. a type generated by `abigen!`, through the tokens
+/// of [`token_strategy`].
+pub fn value_strategy<T>(config: DecoderConfig) -> Result<BoxedStrategy<T>>
+where
+    T: Parameterize + Tokenizable + Debug + 'static,
+{
+    Ok(token_strategy(&T::param_type(), config)?
+        .prop_map(|token| {
+            T::from_token(token).expect("generated tokens should conform to the param type")
+        })
+        .boxed())
+}
+
+/// Checks that `token` decodes to itself after being encoded as `param_type`.
+pub fn check_token_round_trip(
+    param_type: &ParamType,
+    token: &Token,
+    config: DecoderConfig,
+) -> std::result::Result<(), TestCaseError> {
+    let encoder = ABIEncoder::new(EncoderConfig {
+        max_depth: config.max_depth,
+        max_tokens: config.max_tokens,
+    });
+    let encoded = encoder
+        .encode(std::slice::from_ref(token))
+        .map_err(|e| TestCaseError::fail(format!("failed to encode `{token:?}`: {e}")))?;
+
+    let decoded = ABIDecoder::new(config)
+        .decode(param_type, &encoded)
+        .map_err(|e| TestCaseError::fail(format!("failed to decode `{token:?}`: {e}")))?;
+
+    prop_assert_eq!(&decoded, token);
+
+    Ok(())
+}
+
+/// Checks that `value` decodes to itself after being encoded.
+pub fn check_round_trip<T>(
+    value: &T,
+    config: DecoderConfig,
+) -> std::result::Result<(), TestCaseError>
+where
+    T: Parameterize + Tokenizable + Clone + PartialEq + Debug,
+{
+    let token = value.clone().into_token();
+    check_token_round_trip(&T::param_type(), &token, config)?;
+
+    let decoded = T::from_token(token)
+        .map_err(|e| TestCaseError::fail(format!("failed to convert the token back: {e}")))?;
+    prop_assert_eq!(&decoded, value);
+
+    Ok(())
+}
+
+fn strategy(param_type: &ParamType, max_vector_len: usize) -> BoxedStrategy<Token> {
+    let children = |param_types: &[ParamType]| {
+        param_types
+            .iter()
+            .map(|param_type| strategy(param_type, max_vector_len))
+            .collect::<Vec<_>>()
+    };
+
+    match param_type {
+        ParamType::Unit => Just(Token::Unit).boxed(),
+        ParamType::Bool => any::<bool>().prop_map(Token::Bool).boxed(),
+        ParamType::U8 => any::<u8>().prop_map(Token::U8).boxed(),
+        ParamType::U16 => any::<u16>().prop_map(Token::U16).boxed(),
+        ParamType::U32 => any::<u32>().prop_map(Token::U32).boxed(),
+        ParamType::U64 => any::<u64>().prop_map(Token::U64).boxed(),
+        ParamType::U128 => any::<u128>().prop_map(Token::U128).boxed(),
+        ParamType::U256 => any::<[u64; 4]>()
+            .prop_map(|words| Token::U256(U256(words)))
+            .boxed(),
+        ParamType::B256 => any::<[u8; 32]>().prop_map(Token::B256).boxed(),
+        ParamType::Bytes => bytes().prop_map(Token::Bytes).boxed(),
+        ParamType::RawSlice => bytes().prop_map(Token::RawSlice).boxed(),
+        ParamType::String => vec(any::<char>(), 0..=MAX_BYTES_LEN / 4)
+            .prop_map(|chars| Token::String(chars.into_iter().collect()))
+            .boxed(),
+        ParamType::StringArray(len) => {
+            let len = *len;
+            ascii_string(len..=len)
+                .prop_map(move |data| Token::StringArray(StaticStringToken::new(data, Some(len))))
+                .boxed()
+        }
+        ParamType::StringSlice => ascii_string(0..=MAX_BYTES_LEN)
+            .prop_map(|data| Token::StringSlice(StaticStringToken::new(data, None)))
+            .boxed(),
+        ParamType::Tuple(param_types) => children(param_types).prop_map(Token::Tuple).boxed(),
+        ParamType::Array(param_type, len) => vec(strategy(param_type, max_vector_len), *len)
+            .prop_map(Token::Array)
+            .boxed(),
+        ParamType::Vector(param_type) => {
+            vec(strategy(param_type, max_vector_len), 0..=max_vector_len)
+                .prop_map(Token::Vector)
+                .boxed()
+        }
+        ParamType::Struct { fields, .. } => {
+            let field_types: Vec<_> = fields
+                .iter()
+                .map(|(_, param_type)| param_type.clone())
+                .collect();
+            children(&field_types).prop_map(Token::Struct).boxed()
+        }
+        ParamType::Enum { enum_variants, .. } => enum_strategy(enum_variants, max_vector_len),
+    }
+}
+
+fn enum_strategy(enum_variants: &EnumVariants, max_vector_len: usize) -> BoxedStrategy<Token> {
+    let variants = enum_variants
+        .param_types()
+        .enumerate()
+        .map(|(discriminant, param_type)| {
+            let enum_variants = enum_variants.clone();
+            strategy(param_type, max_vector_len)
+                .prop_map(move |token| {
+                    Token::Enum(Box::new((
+                        discriminant as u64,
+                        token,
+                        enum_variants.clone(),
+                    )))
+                })
+                .boxed()
+        });
+
+    Union::new(variants).boxed()
+}
+
+fn bytes() -> impl Strategy<Value = Vec<u8>> {
+    vec(any::<u8>(), 0..=MAX_BYTES_LEN)
+}
+
+fn ascii_string(len: impl Into<proptest::collection::SizeRange>) -> impl Strategy<Value = String> {
+    vec(0u8..=0x7f, len).prop_map(|bytes| String::from_utf8(bytes).expect("ascii is valid utf-8"))
+}
+
+// Depth the decoder reaches when decoding `param_type`, see `DecoderConfig::max_depth`.
+fn depth(param_type: &ParamType) -> usize {
+    let max_depth = |param_types: &mut dyn Iterator<Item = &ParamType>| {
+        1 + param_types.map(depth).max().unwrap_or(0)
+    };
+
+    match param_type {
+        ParamType::Tuple(param_types) => max_depth(&mut param_types.iter()),
+        ParamType::Array(param_type, _) | ParamType::Vector(param_type) => {
+            max_depth(&mut std::iter::once(param_type.as_ref()))
+        }
+        ParamType::Struct { fields, .. } => max_depth(&mut fields.iter().map(|(_, pt)| pt)),
+        ParamType::Enum { enum_variants, .. } => max_depth(&mut enum_variants.param_types()),
+        _ => 0,
+    }
+}
+
+// Tokens the decoder counts for a value of `param_type` whose vectors all have `vector_len`
+// elements, see `DecoderConfig::max_tokens`.
+fn max_token_count(param_type: &ParamType, vector_len: usize) -> usize {
+    let count = |param_type| max_token_count(param_type, vector_len);
+
+    let children = match param_type {
+        ParamType::Tuple(param_types) => {
+            param_types.iter().map(count).fold(0, usize::saturating_add)
+        }
+        ParamType::Array(param_type, len) => count(param_type).saturating_mul(*len),
+        ParamType::Vector(param_type) => count(param_type).saturating_mul(vector_len),
+        ParamType::Struct { fields, .. } => fields
+            .iter()
+            .map(|(_, param_type)| count(param_type))
+            .fold(0, usize::saturating_add),
+        ParamType::Enum { enum_variants, .. } => {
+            enum_variants.param_types().map(count).max().unwrap_or(0)
+        }
+        _ => 0,
+    };
+
+    children.saturating_add(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prelude::ProptestConfig, proptest, test_runner::TestRunner};
+
+    use super::*;
+
+    fn odd_param_type() -> ParamType {
+        let enum_variants = EnumVariants::new(vec![
+            ("Empty".to_string(), ParamType::Unit),
+            (
+                "Vector".to_string(),
+                ParamType::Vector(Box::new(ParamType::Vector(Box::new(ParamType::U8)))),
+            ),
+            ("String".to_string(), ParamType::String),
+        ])
+        .unwrap();
+
+        ParamType::Struct {
+            name: "OddStruct".to_string(),
+            fields: vec![
+                (
+                    "generic_enum".to_string(),
+                    ParamType::Enum {
+                        name: "OddEnum".to_string(),
+                        enum_variants,
+                        generics: vec![ParamType::U8],
+                    },
+                ),
+                ("string_array".to_string(), ParamType::StringArray(255)),
+                ("string_slice".to_string(), ParamType::StringSlice),
+                (
+                    "tuple".to_string(),
+                    ParamType::Tuple(vec![ParamType::U256, ParamType::Bytes, ParamType::RawSlice]),
+                ),
+                (
+                    "array".to_string(),
+                    ParamType::Array(Box::new(ParamType::B256), 2),
+                ),
+                ("u128".to_string(), ParamType::U128),
+            ],
+            generics: vec![],
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn generated_tokens_round_trip(
+            token in token_strategy(&odd_param_type(), DecoderConfig::default()).unwrap()
+        ) {
+            check_token_round_trip(&odd_param_type(), &token, DecoderConfig::default())?;
+        }
+
+        #[test]
+        fn generated_values_round_trip(
+            value in value_strategy::<(Vec<u64>, bool, [u16; 3])>(DecoderConfig::default()).unwrap()
+        ) {
+            check_round_trip(&value, DecoderConfig::default())?;
+        }
+    }
+
+    #[test]
+    fn generated_tokens_respect_max_tokens() {
+        // given
+        let param_type = ParamType::Vector(Box::new(ParamType::Vector(Box::new(ParamType::U8))));
+        let config = DecoderConfig {
+            max_tokens: 20,
+            ..Default::default()
+        };
+        let strategy = token_strategy(&param_type, config).unwrap();
+
+        // when
+        let mut runner = TestRunner::default();
+        let result = runner.run(&strategy, |token| {
+            check_token_round_trip(&param_type, &token, config)
+        });
+
+        // then
+        result.expect("all tokens should be decodable with the configured limit");
+    }
+
+    #[test]
+    fn types_over_the_limits_are_rejected() {
+        // given
+        let deep_type = ParamType::Tuple(vec![ParamType::Tuple(vec![ParamType::U8])]);
+        let large_type = ParamType::Array(Box::new(ParamType::U8), 10);
+        let config = DecoderConfig {
+            max_depth: 1,
+            max_tokens: 10,
+        };
+
+        // when
+        let deep_err = token_strategy(&deep_type, config).unwrap_err();
+        let large_err = token_strategy(&large_type, config).unwrap_err();
+
+        // then
+        assert!(deep_err.to_string().contains("over the `max_depth` of 1"));
+        assert!(large_err
+            .to_string()
+            .contains("has more tokens than the `max_tokens` of 10"));
+    }
+}
@@ -182,11 +182,60 @@ impl LogDecoder {
             .collect()
     }
 
+    /// Get all logs from the given receipts decoded as events of type `E`, in the order in which
+    /// they were logged. Only logs of the contracts registered with this decoder are decoded.
+    pub fn decode_events<E: Event>(&self, receipts: &[Receipt]) -> Result<Vec<ContractEvent<E>>> {
+        let contract_ids: Vec<_> = self
+            .log_formatters
+            .keys()
+            .map(|LogId(contract_id, _)| *contract_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        decode_events(receipts, &contract_ids, self.decoder_config)
+    }
+
     pub fn merge(&mut self, log_decoder: LogDecoder) {
         self.log_formatters.extend(log_decoder.log_formatters);
     }
 }
 
+/// Implemented by the `{Name}Event` enums generated by `abigen!`. Each variant holds one of the
+/// types logged by the program.
+pub trait Event: Sized {
+    /// Decodes `data` into the variant logged under `log_id`. Returns `None` if no variant
+    /// corresponds to `log_id`.
+    fn decode(log_id: &str, data: &[u8], decoder_config: DecoderConfig) -> Option<Result<Self>>;
+}
+
+/// An [`Event`] together with the id of the contract that logged it. Logs coming from scripts
+/// have a zeroed contract id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractEvent<E> {
+    pub contract_id: ContractId,
+    pub event: E,
+}
+
+/// Decodes the logs of the contracts in `contract_ids` found in `receipts` as events of type `E`,
+/// preserving the order in which they were logged. Logs of other contracts and logs whose type is
+/// not part of `E` are skipped.
+pub fn decode_events<E: Event>(
+    receipts: &[Receipt],
+    contract_ids: &[ContractId],
+    decoder_config: DecoderConfig,
+) -> Result<Vec<ContractEvent<E>>> {
+    receipts
+        .iter()
+        .extract_log_id_and_data()
+        .filter(|(LogId(contract_id, _), _)| contract_ids.contains(contract_id))
+        .filter_map(|(LogId(contract_id, log_id), data)| {
+            E::decode(&log_id, &data, decoder_config)
+                .map(|event| event.map(|event| ContractEvent { contract_id, event }))
+        })
+        .collect()
+}
+
 trait ExtractLogIdData {
     type Output: Iterator<Item = (LogId, Vec<u8>)>;
     fn extract_log_id_and_data(self) -> Self::Output;
@@ -1,6 +1,7 @@
 use std::{
     fmt::{Display, Formatter},
     str::FromStr,
+    sync::atomic::{AtomicU8, Ordering},
 };
 
 use bech32::{FromBase32, ToBase32, Variant::Bech32m};
@@ -8,13 +9,102 @@ use fuel_tx::{Address, Bytes32, ContractId, ContractIdExt};
 use fuel_types::AssetId;
 
 use crate::types::{
-    errors::{Error, Result},
+    checksum_address::{checksum_encode, is_checksum_valid},
+    errors::{error, Error, Result},
     Bits256,
 };
 
 // Fuel Network human-readable part for bech32 encoding
 pub const FUEL_BECH32_HRP: &str = "fuel";
 
+/// String representations supported for addresses and contract ids.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AddressFormat {
+    /// e.g. `fuel1dved7k25uxadatl7l5kql309jnw07dcn4t3a6x9hm9nxyjcpqqns50p7n2`
+    #[default]
+    Bech32 = 0,
+    /// Lowercase, `0x`-prefixed hex.
+    Hex = 1,
+    /// `0x`-prefixed hex where the case of each letter encodes a checksum.
+    ChecksumHex = 2,
+}
+
+static DISPLAY_DEFAULT: AtomicU8 = AtomicU8::new(AddressFormat::Bech32 as u8);
+
+impl AddressFormat {
+    /// Sets the format [`Display`] uses for [`Bech32Address`] and [`Bech32ContractId`] in the
+    /// whole process. Parsing keeps accepting every format.
+    pub fn set_display_default(format: AddressFormat) {
+        DISPLAY_DEFAULT.store(format as u8, Ordering::Relaxed);
+    }
+
+    /// The format [`Display`] currently uses, [`AddressFormat::Bech32`] unless changed with
+    /// [`AddressFormat::set_display_default`].
+    pub fn display_default() -> AddressFormat {
+        match DISPLAY_DEFAULT.load(Ordering::Relaxed) {
+            1 => AddressFormat::Hex,
+            2 => AddressFormat::ChecksumHex,
+            _ => AddressFormat::Bech32,
+        }
+    }
+}
+
+/// Parses a bech32 string or a 32-byte hex string, with or without the `0x` (or `0X`) prefix.
+/// Mixed-case hex is treated as checksummed and rejected if the checksum doesn't match.
+fn parse_hrp_and_hash(s: &str) -> Result<(String, Bytes32)> {
+    let prefixed = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"));
+    let hex = prefixed.unwrap_or(s);
+    let is_hex =
+        prefixed.is_some() || (hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()));
+
+    if !is_hex {
+        let (hrp, pubkey_hash_base32, _) = bech32::decode(s)?;
+        let pubkey_hash: [u8; Address::LEN] = Vec::<u8>::from_base32(&pubkey_hash_base32)?
+            .as_slice()
+            .try_into()?;
+
+        return Ok((hrp, Bytes32::new(pubkey_hash)));
+    }
+
+    let is_mixed_case =
+        hex.chars().any(|c| c.is_ascii_uppercase()) && hex.chars().any(|c| c.is_ascii_lowercase());
+    if is_mixed_case && !is_checksum_valid(hex) {
+        return Err(error!(Codec, "invalid checksum for address `{s}`"));
+    }
+
+    let mut hash = [0u8; 32];
+    hex::decode_to_slice(hex, &mut hash)
+        .map_err(|e| error!(Codec, "invalid hex address `{s}`: {e}"))?;
+
+    Ok((FUEL_BECH32_HRP.to_string(), Bytes32::new(hash)))
+}
+
+/// Displays an address or contract id in the chosen [`AddressFormat`]. Returned by `display_as`.
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedAddress<'a> {
+    hrp: &'a str,
+    hash: &'a Bytes32,
+    format: AddressFormat,
+}
+
+impl Display for FormattedAddress<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let hex = format!("0x{}", hex::encode(**self.hash));
+
+        let formatted = match self.format {
+            AddressFormat::Bech32 => {
+                bech32::encode(self.hrp, self.hash.to_base32(), Bech32m).unwrap()
+            }
+            AddressFormat::Hex => hex,
+            AddressFormat::ChecksumHex => {
+                checksum_encode(&hex).expect("hex of a 32-byte hash is a valid address")
+            }
+        };
+
+        f.pad(&formatted)
+    }
+}
+
 /// Generate type represented in the Bech32 format,
 /// consisting of a human-readable part (hrp) and a hash (e.g. pubkey-, contract hash)
 macro_rules! bech32type {
@@ -40,6 +130,21 @@ macro_rules! bech32type {
             pub fn hrp(&self) -> &str {
                 &self.hrp
             }
+
+            /// Displays `self` in the given `format`, e.g. `format!("{}", id.display_as(format))`.
+            /// [`Display`] uses [`AddressFormat::display_default`].
+            pub fn display_as(&self, format: AddressFormat) -> FormattedAddress<'_> {
+                FormattedAddress {
+                    hrp: &self.hrp,
+                    hash: &self.hash,
+                    format,
+                }
+            }
+
+            /// Formats `self` as a string in the given `format`.
+            pub fn format_as(&self, format: AddressFormat) -> String {
+                self.display_as(format).to_string()
+            }
         }
 
         impl Default for $i {
@@ -54,26 +159,33 @@ macro_rules! bech32type {
         impl FromStr for $i {
             type Err = Error;
 
+            /// Accepts bech32, plain hex and checksummed hex. See [`AddressFormat`].
             fn from_str(s: &str) -> Result<Self> {
-                let (hrp, pubkey_hash_base32, _) = bech32::decode(s)?;
+                let (hrp, hash) = parse_hrp_and_hash(s)?;
 
-                let pubkey_hash: [u8; Address::LEN] = Vec::<u8>::from_base32(&pubkey_hash_base32)?
-                    .as_slice()
-                    .try_into()?;
+                Ok(Self { hrp, hash })
+            }
+        }
+
+        impl TryFrom<&str> for $i {
+            type Error = Error;
 
-                Ok(Self {
-                    hrp,
-                    hash: Bytes32::new(pubkey_hash),
-                })
+            fn try_from(s: &str) -> Result<Self> {
+                Self::from_str(s)
+            }
+        }
+
+        impl TryFrom<String> for $i {
+            type Error = Error;
+
+            fn try_from(s: String) -> Result<Self> {
+                Self::from_str(&s)
             }
         }
 
         impl Display for $i {
             fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-                let data_base32 = self.hash.to_base32();
-                let encoding = bech32::encode(&self.hrp, &data_base32, Bech32m).unwrap();
-
-                write!(f, "{}", encoding)
+                self.display_as(AddressFormat::display_default()).fmt(f)
             }
         }
     };
@@ -237,4 +349,96 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn parses_bech32_hex_and_checksum_hex() -> Result<()> {
+        // given
+        let bech32 = "fuel1dved7k25uxadatl7l5kql309jnw07dcn4t3a6x9hm9nxyjcpqqns50p7n2";
+        let expected = Bech32Address::from_str(bech32)?;
+        let hex = expected.format_as(AddressFormat::Hex);
+        let checksum_hex = expected.format_as(AddressFormat::ChecksumHex);
+
+        // when
+        let parsed = [
+            Bech32Address::from_str(&hex)?,
+            Bech32Address::from_str(hex.trim_start_matches("0x"))?,
+            Bech32Address::from_str(&hex.to_uppercase().replace("0X", "0x"))?,
+            Bech32Address::from_str(&hex.to_uppercase())?,
+            Bech32Address::from_str(&checksum_hex)?,
+            Bech32Address::from_str(&checksum_hex.replace("0x", "0X"))?,
+        ];
+
+        // then
+        assert!(parsed.iter().all(|address| *address == expected));
+        assert_eq!(
+            hex,
+            "0x6b32df5954e1badeaffefd2c0fc5e594dcff3713aae3dd18b7d966624b010027"
+        );
+        assert_ne!(checksum_hex, hex);
+        assert!(is_checksum_valid(&checksum_hex));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_mixed_case_hex_with_invalid_checksum() {
+        let checksum_hex =
+            Bech32ContractId::new(FUEL_BECH32_HRP, [107; 32]).format_as(AddressFormat::ChecksumHex);
+        let tampered = checksum_hex
+            .chars()
+            .map(|c| match c {
+                'a'..='f' => c.to_ascii_uppercase(),
+                'A'..='F' => c.to_ascii_lowercase(),
+                _ => c,
+            })
+            .collect::<String>();
+
+        let err = Bech32ContractId::from_str(&tampered).expect_err("should fail");
+
+        assert!(err.to_string().contains("invalid checksum"));
+    }
+
+    #[test]
+    fn formats_in_the_requested_format() {
+        let contract_id = Bech32ContractId::from(ContractId::new([0xab; 32]));
+
+        assert_eq!(
+            Bech32ContractId::from_str(&contract_id.format_as(AddressFormat::Bech32)).unwrap(),
+            contract_id
+        );
+        assert_eq!(
+            contract_id.format_as(AddressFormat::Hex),
+            format!("0x{}", "ab".repeat(32))
+        );
+        assert_eq!(
+            contract_id.format_as(AddressFormat::ChecksumHex),
+            checksum_encode(&"ab".repeat(32)).unwrap()
+        );
+        assert_eq!(
+            format!("{:>70}", contract_id.display_as(AddressFormat::Hex)),
+            format!("    0x{}", "ab".repeat(32))
+        );
+    }
+
+    #[test]
+    fn display_uses_the_default_format() -> Result<()> {
+        // given
+        let address = Bech32Address::from(Address::new([0xab; 32]));
+        let hex = address.format_as(AddressFormat::Hex);
+
+        // when
+        AddressFormat::set_display_default(AddressFormat::Hex);
+        let displayed = address.to_string();
+        AddressFormat::set_display_default(AddressFormat::Bech32);
+
+        // then
+        assert_eq!(displayed, hex);
+        assert_eq!(Bech32Address::try_from(displayed)?, address);
+        assert_eq!(
+            address.to_string(),
+            address.format_as(AddressFormat::Bech32)
+        );
+
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@ pub use bits::*;
 pub use bytes::*;
 pub use identity::*;
 pub use raw_slice::*;
+pub use signed_integers::*;
 pub use sized_ascii_string::*;
 pub use u256::*;
 
@@ -9,5 +10,6 @@ mod bits;
 mod bytes;
 mod identity;
 mod raw_slice;
+mod signed_integers;
 mod sized_ascii_string;
 mod u256;
@@ -1,5 +1,6 @@
 use fuel_types::AssetId;
 use fuels_macros::{Parameterize, Tokenizable, TryFrom};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::types::errors::Result;
 
@@ -31,6 +32,19 @@ impl Bits256 {
     }
 }
 
+impl Serialize for Bits256 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", hex::encode(self.0)))
+    }
+}
+
+impl<'de> Deserialize<'de> for Bits256 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        Self::from_hex_str(&hex).map_err(serde::de::Error::custom)
+    }
+}
+
 impl From<AssetId> for Bits256 {
     fn from(value: AssetId) -> Self {
         Self(value.into())
@@ -38,7 +52,9 @@ impl From<AssetId> for Bits256 {
 }
 
 // A simple wrapper around [Bits256; 2] representing the `B512` type.
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Parameterize, Tokenizable, TryFrom)]
+#[derive(
+    Debug, PartialEq, Eq, Copy, Clone, Parameterize, Tokenizable, TryFrom, Serialize, Deserialize,
+)]
 #[FuelsCorePath = "crate"]
 #[FuelsTypesPath = "crate::types"]
 // ANCHOR: b512
@@ -84,6 +100,18 @@ impl EvmAddress {
     }
 }
 
+impl Serialize for EvmAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EvmAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        Bits256::deserialize(deserializer).map(Self::new)
+    }
+}
+
 impl From<Bits256> for EvmAddress {
     fn from(b256: Bits256) -> Self {
         EvmAddress::new(b256)
@@ -161,4 +189,29 @@ mod tests {
 
         assert_eq!(token, Token::Struct(vec![Token::B256(expected_data)]));
     }
+
+    #[test]
+    fn serde_uses_hex_strings() -> Result<()> {
+        // given
+        let b256 = Bits256([1; 32]);
+        let evm_address = EvmAddress::from(Bits256([2; 32]));
+
+        // when
+        let serialized_b256 = serde_json::to_string(&b256)?;
+        let serialized_evm_address = serde_json::to_string(&evm_address)?;
+
+        // then
+        assert_eq!(serialized_b256, format!("\"0x{}\"", "01".repeat(32)));
+        assert_eq!(
+            serialized_evm_address,
+            format!("\"0x{}{}\"", "00".repeat(12), "02".repeat(20))
+        );
+        assert_eq!(serde_json::from_str::<Bits256>(&serialized_b256)?, b256);
+        assert_eq!(
+            serde_json::from_str::<EvmAddress>(&format!("\"0x{}\"", "02".repeat(32)))?,
+            evm_address
+        );
+
+        Ok(())
+    }
 }
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 use crate::types::errors::Result;
 
 #[derive(Debug, PartialEq, Clone, Eq)]
@@ -18,6 +20,19 @@ impl Bytes {
     }
 }
 
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", hex::encode(&self.0)))
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        Self::from_hex_str(&hex).map_err(serde::de::Error::custom)
+    }
+}
+
 impl From<Bytes> for Vec<u8> {
     fn from(bytes: Bytes) -> Vec<u8> {
         bytes.0
@@ -61,4 +76,19 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn serde_uses_hex_strings() -> Result<()> {
+        // given
+        let bytes = Bytes(vec![1, 2, 255]);
+
+        // when
+        let serialized = serde_json::to_string(&bytes)?;
+
+        // then
+        assert_eq!(serialized, "\"0x0102ff\"");
+        assert_eq!(serde_json::from_str::<Bytes>(&serialized)?, bytes);
+
+        Ok(())
+    }
 }
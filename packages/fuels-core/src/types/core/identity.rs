@@ -2,7 +2,10 @@ use fuel_types::{Address, ContractId};
 use fuels_macros::{Parameterize, Tokenizable, TryFrom};
 use serde::{Deserialize, Serialize};
 
-use crate::types::bech32::{Bech32Address, Bech32ContractId};
+use crate::types::{
+    bech32::{AddressFormat, Bech32Address, Bech32ContractId},
+    errors::{error, Error, Result},
+};
 
 #[derive(
     Debug,
@@ -30,6 +33,18 @@ impl Default for Identity {
     }
 }
 
+impl Identity {
+    /// Formats the underlying address or contract id in the given `format`.
+    pub fn format_as(&self, format: AddressFormat) -> String {
+        match self {
+            Identity::Address(address) => Bech32Address::from(*address).format_as(format),
+            Identity::ContractId(contract_id) => {
+                Bech32ContractId::from(*contract_id).format_as(format)
+            }
+        }
+    }
+}
+
 impl AsRef<[u8]> for Identity {
     fn as_ref(&self) -> &[u8] {
         match self {
@@ -83,6 +98,34 @@ impl From<Bech32ContractId> for Identity {
     }
 }
 
+impl TryFrom<Identity> for Bech32Address {
+    type Error = Error;
+
+    fn try_from(identity: Identity) -> Result<Self> {
+        match identity {
+            Identity::Address(address) => Ok(address.into()),
+            Identity::ContractId(_) => Err(error!(
+                Other,
+                "expected an `Identity::Address`, got an `Identity::ContractId`"
+            )),
+        }
+    }
+}
+
+impl TryFrom<Identity> for Bech32ContractId {
+    type Error = Error;
+
+    fn try_from(identity: Identity) -> Result<Self> {
+        match identity {
+            Identity::ContractId(contract_id) => Ok(contract_id.into()),
+            Identity::Address(_) => Err(error!(
+                Other,
+                "expected an `Identity::ContractId`, got an `Identity::Address`"
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::str::FromStr;
@@ -113,4 +156,25 @@ mod test {
         let identity: Identity = bech32_address.clone().into();
         assert_eq!(identity, Identity::Address(bech32_address.clone().into()));
     }
+
+    #[test]
+    fn converts_back_into_bech32_types() {
+        let address = Bech32Address::from(Address::new([1; 32]));
+        let contract_id = Bech32ContractId::from(ContractId::new([2; 32]));
+
+        let from_address = Identity::from(&address);
+        let from_contract_id = Identity::from(&contract_id);
+
+        assert_eq!(Bech32Address::try_from(from_address).unwrap(), address);
+        assert_eq!(
+            Bech32ContractId::try_from(from_contract_id).unwrap(),
+            contract_id
+        );
+        assert!(Bech32Address::try_from(from_contract_id).is_err());
+        assert!(Bech32ContractId::try_from(from_address).is_err());
+        assert_eq!(
+            from_address.format_as(AddressFormat::Hex),
+            format!("0x{}", "01".repeat(32))
+        );
+    }
 }
@@ -0,0 +1,532 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use fuels_macros::{Parameterize, Tokenizable, TryFrom};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::{
+    errors::{error, Error, Result},
+    U256,
+};
+
+// Signed integers from `sway_libs::signed_integers`. Sway stores them in an unsigned `underlying`
+// field, offset by `2^(N-1)` so that `underlying == 2^(N-1)` represents zero. The offset
+// representation is the two's complement with the sign bit flipped, so it also preserves ordering.
+macro_rules! signed_integer {
+    ($name: ident, $signed: ty, $unsigned: ty) => {
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Hash,
+            Parameterize,
+            Tokenizable,
+            TryFrom,
+        )]
+        #[FuelsCorePath = "crate"]
+        #[FuelsTypesPath = "crate::types"]
+        pub struct $name {
+            underlying: $unsigned,
+        }
+
+        impl $name {
+            const OFFSET: $unsigned = 1 << (<$unsigned>::BITS - 1);
+
+            pub const MIN: Self = Self::new(<$signed>::MIN);
+            pub const MAX: Self = Self::new(<$signed>::MAX);
+            pub const ZERO: Self = Self::new(0);
+
+            pub const fn new(value: $signed) -> Self {
+                Self {
+                    underlying: (value as $unsigned) ^ Self::OFFSET,
+                }
+            }
+
+            pub const fn value(self) -> $signed {
+                (self.underlying ^ Self::OFFSET) as $signed
+            }
+
+            /// Creates the integer from its offset representation as stored by the contract.
+            pub const fn from_underlying(underlying: $unsigned) -> Self {
+                Self { underlying }
+            }
+
+            /// Returns the offset representation as stored by the contract.
+            pub const fn underlying(self) -> $unsigned {
+                self.underlying
+            }
+
+            pub const fn is_negative(self) -> bool {
+                self.underlying < Self::OFFSET
+            }
+
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.value().checked_add(rhs.value()).map(Self::new)
+            }
+
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.value().checked_sub(rhs.value()).map(Self::new)
+            }
+
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                self.value().checked_mul(rhs.value()).map(Self::new)
+            }
+
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                self.value().checked_div(rhs.value()).map(Self::new)
+            }
+
+            pub fn checked_neg(self) -> Option<Self> {
+                self.value().checked_neg().map(Self::new)
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl From<$signed> for $name {
+            fn from(value: $signed) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for $signed {
+            fn from(value: $name) -> Self {
+                value.value()
+            }
+        }
+
+        impl From<$name> for I256 {
+            fn from(value: $name) -> Self {
+                I256::from(i128::from(value.value()))
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                self.value().fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                s.parse::<$signed>()
+                    .map(Self::new)
+                    .map_err(|e| error!(Other, "`{s}` is not a valid `{}`: {e}", stringify!($name)))
+            }
+        }
+
+        impl_arithmetic!($name);
+    };
+}
+
+// Serialized as the signed value, e.g. `-5`, not as the offset representation.
+macro_rules! impl_serde_as_number {
+    ($name: ident, $signed: ty) => {
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                self.value().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                <$signed>::deserialize(deserializer).map(Self::new)
+            }
+        }
+    };
+}
+
+// Values wider than 64 bits don't fit into a JSON number and are serialized as decimal strings,
+// like `U256`.
+macro_rules! impl_serde_as_string {
+    ($name: ident) => {
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(&self.to_string())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let value = String::deserialize(deserializer)?;
+
+                value.parse().map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+macro_rules! impl_arithmetic {
+    ($name: ident) => {
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                self.checked_add(rhs).expect("attempt to add with overflow")
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                self.checked_sub(rhs)
+                    .expect("attempt to subtract with overflow")
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                self.checked_mul(rhs)
+                    .expect("attempt to multiply with overflow")
+            }
+        }
+
+        impl Div for $name {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                self.checked_div(rhs)
+                    .expect("attempt to divide by zero or with overflow")
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                self.checked_neg().expect("attempt to negate with overflow")
+            }
+        }
+    };
+}
+
+signed_integer!(I8, i8, u8);
+signed_integer!(I16, i16, u16);
+signed_integer!(I32, i32, u32);
+signed_integer!(I64, i64, u64);
+signed_integer!(I128, i128, u128);
+
+impl_serde_as_number!(I8, i8);
+impl_serde_as_number!(I16, i16);
+impl_serde_as_number!(I32, i32);
+impl_serde_as_number!(I64, i64);
+impl_serde_as_string!(I128);
+impl_serde_as_string!(I256);
+
+/// 256-bit signed integer. Rust has no native counterpart, so the arithmetic is implemented on
+/// top of [`U256`].
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Parameterize, Tokenizable, TryFrom,
+)]
+#[FuelsCorePath = "crate"]
+#[FuelsTypesPath = "crate::types"]
+pub struct I256 {
+    underlying: U256,
+}
+
+impl I256 {
+    const OFFSET: U256 = U256([0, 0, 0, 1 << 63]);
+
+    pub const MIN: Self = Self {
+        underlying: U256([0; 4]),
+    };
+    pub const MAX: Self = Self {
+        underlying: U256([u64::MAX; 4]),
+    };
+    pub const ZERO: Self = Self {
+        underlying: Self::OFFSET,
+    };
+
+    /// Creates the integer from its offset representation as stored by the contract.
+    pub const fn from_underlying(underlying: U256) -> Self {
+        Self { underlying }
+    }
+
+    /// Returns the offset representation as stored by the contract.
+    pub const fn underlying(self) -> U256 {
+        self.underlying
+    }
+
+    pub fn from_twos_complement(value: U256) -> Self {
+        Self {
+            underlying: value ^ Self::OFFSET,
+        }
+    }
+
+    pub fn twos_complement(self) -> U256 {
+        self.underlying ^ Self::OFFSET
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.underlying < Self::OFFSET
+    }
+
+    /// Returns the absolute value as an unsigned integer, which always fits.
+    pub fn unsigned_abs(self) -> U256 {
+        let value = self.twos_complement();
+
+        if self.is_negative() {
+            (!value).overflowing_add(U256::one()).0
+        } else {
+            value
+        }
+    }
+
+    fn from_sign_and_magnitude(is_negative: bool, magnitude: U256) -> Option<Self> {
+        if is_negative && !magnitude.is_zero() {
+            (magnitude <= Self::OFFSET)
+                .then(|| Self::from_twos_complement((!magnitude).overflowing_add(U256::one()).0))
+        } else {
+            (magnitude < Self::OFFSET).then(|| Self::from_twos_complement(magnitude))
+        }
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let sum = Self::from_twos_complement(
+            self.twos_complement()
+                .overflowing_add(rhs.twos_complement())
+                .0,
+        );
+        let overflowed =
+            self.is_negative() == rhs.is_negative() && sum.is_negative() != self.is_negative();
+
+        (!overflowed).then_some(sum)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let difference = Self::from_twos_complement(
+            self.twos_complement()
+                .overflowing_sub(rhs.twos_complement())
+                .0,
+        );
+        let overflowed = self.is_negative() != rhs.is_negative()
+            && difference.is_negative() != self.is_negative();
+
+        (!overflowed).then_some(difference)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let magnitude = self.unsigned_abs().checked_mul(rhs.unsigned_abs())?;
+
+        Self::from_sign_and_magnitude(self.is_negative() != rhs.is_negative(), magnitude)
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs == Self::ZERO {
+            return None;
+        }
+        let magnitude = self.unsigned_abs() / rhs.unsigned_abs();
+
+        Self::from_sign_and_magnitude(self.is_negative() != rhs.is_negative(), magnitude)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Self::from_sign_and_magnitude(!self.is_negative(), self.unsigned_abs())
+    }
+}
+
+impl Default for I256 {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i128> for I256 {
+    fn from(value: i128) -> Self {
+        Self::from_sign_and_magnitude(value < 0, U256::from(value.unsigned_abs()))
+            .expect("an `i128` always fits into an `I256`")
+    }
+}
+
+impl TryFrom<I256> for i128 {
+    type Error = Error;
+
+    fn try_from(value: I256) -> Result<Self> {
+        let magnitude = value.unsigned_abs();
+        let limit = U256::from(i128::MAX as u128) + value.is_negative() as u8;
+        if magnitude > limit {
+            return Err(error!(Other, "`{value}` does not fit into an `i128`"));
+        }
+
+        let magnitude = magnitude.as_u128() as i128;
+        Ok(if value.is_negative() {
+            magnitude.wrapping_neg()
+        } else {
+            magnitude
+        })
+    }
+}
+
+impl Display for I256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+
+        write!(f, "{sign}{}", self.unsigned_abs())
+    }
+}
+
+impl FromStr for I256 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || error!(Other, "`{s}` is not a valid `I256`");
+
+        let (is_negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let magnitude = U256::from_dec_str(digits).map_err(|_| invalid())?;
+
+        Self::from_sign_and_magnitude(is_negative, magnitude).ok_or_else(invalid)
+    }
+}
+
+impl_arithmetic!(I256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        codec::{ABIDecoder, ABIEncoder},
+        traits::{Parameterize, Tokenizable},
+    };
+
+    #[test]
+    fn values_are_stored_with_an_offset() {
+        assert_eq!(I8::new(-128).underlying(), 0);
+        assert_eq!(I8::new(0).underlying(), 128);
+        assert_eq!(I8::new(127).underlying(), 255);
+        assert_eq!(I64::ZERO.underlying(), 1 << 63);
+
+        assert_eq!(I256::MIN.underlying(), U256::zero());
+        assert_eq!(I256::ZERO.underlying(), U256::one() << 255);
+        assert_eq!(I256::from(-1).underlying(), (U256::one() << 255) - 1);
+    }
+
+    #[test]
+    fn converts_from_and_to_rust_integers() -> Result<()> {
+        for value in [i32::MIN, -1, 0, 1, i32::MAX] {
+            assert_eq!(i32::from(I32::from(value)), value);
+        }
+
+        for value in [i128::MIN, -5, 0, 5, i128::MAX] {
+            assert_eq!(i128::try_from(I256::from(value))?, value);
+        }
+        assert!(i128::try_from(I256::MAX).is_err());
+        assert!(i128::try_from(I256::MIN).is_err());
+        assert_eq!(I256::from(I16::new(-300)), I256::from(-300));
+
+        Ok(())
+    }
+
+    #[test]
+    fn arithmetic_follows_rust_semantics() {
+        assert_eq!(I8::new(-100) + I8::new(27), I8::new(-73));
+        assert_eq!(I8::new(-100).checked_sub(I8::new(29)), None);
+        assert_eq!(I8::MIN.checked_neg(), None);
+        assert!(I8::new(-1) < I8::new(0));
+
+        let minus_seven = I256::from(-7);
+        let two = I256::from(2);
+        assert_eq!(minus_seven + two, I256::from(-5));
+        assert_eq!(two - minus_seven, I256::from(9));
+        assert_eq!(minus_seven * two, I256::from(-14));
+        assert_eq!(minus_seven / two, I256::from(-3));
+        assert_eq!(-minus_seven, I256::from(7));
+        assert!(minus_seven < two);
+
+        assert_eq!(I256::MAX.checked_add(I256::from(1)), None);
+        assert_eq!(I256::MIN.checked_sub(I256::from(1)), None);
+        assert_eq!(I256::MIN.checked_div(I256::from(-1)), None);
+        assert_eq!(I256::MIN.checked_neg(), None);
+        assert_eq!(two.checked_div(I256::ZERO), None);
+        assert_eq!(
+            I256::MAX.checked_mul(I256::from(-1)),
+            I256::MIN.checked_add(I256::from(1))
+        );
+    }
+
+    #[test]
+    fn displays_the_signed_value() {
+        assert_eq!(I8::MIN.to_string(), "-128");
+        assert_eq!(I256::from(-42).to_string(), "-42");
+        assert_eq!(I256::ZERO.to_string(), "0");
+        assert_eq!(
+            I256::MAX.to_string(),
+            "57896044618658097711785492504343953926634992332820282019728792003956564819967"
+        );
+    }
+
+    #[test]
+    fn encoding_matches_the_underlying_value() -> Result<()> {
+        // given
+        let value = I64::new(-2);
+
+        // when
+        let encoded = ABIEncoder::default().encode(&[value.into_token()])?;
+        let decoded = I64::from_token(ABIDecoder::default().decode(&I64::param_type(), &encoded)?)?;
+
+        // then
+        assert_eq!(encoded, ((1u64 << 63) - 2).to_be_bytes());
+        assert_eq!(decoded, value);
+
+        Ok(())
+    }
+
+    #[test]
+    fn serializes_the_signed_value() -> Result<()> {
+        assert_eq!(serde_json::to_string(&I8::new(-5))?, "-5");
+        assert_eq!(serde_json::to_string(&I64::MAX)?, i64::MAX.to_string());
+        assert_eq!(serde_json::to_string(&I128::new(-5))?, r#""-5""#);
+        assert_eq!(serde_json::to_string(&I256::from(-5))?, r#""-5""#);
+
+        for value in [I256::MIN, I256::from(-1), I256::ZERO, I256::MAX] {
+            let json = serde_json::to_string(&value)?;
+            assert_eq!(serde_json::from_str::<I256>(&json)?, value);
+        }
+        assert_eq!(serde_json::from_str::<I32>("-7")?, I32::new(-7));
+        assert_eq!(serde_json::from_str::<I128>(r#""-7""#)?, I128::new(-7));
+
+        assert!(serde_json::from_str::<I8>("128").is_err());
+        assert!("-".parse::<I256>().is_err());
+        assert!(I256::MAX
+            .to_string()
+            .replace('7', "8")
+            .parse::<I256>()
+            .is_err());
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 #![allow(clippy::assign_op_pattern)]
+#![allow(clippy::manual_div_ceil)]
 
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use uint::construct_uint;
@@ -355,6 +355,15 @@ macro_rules! impl_tx_builder_trait {
                 Ok(padded_len as u64)
             }
 
+            #[cfg_attr(
+                feature = "tracing",
+                tracing::instrument(
+                    level = "trace",
+                    skip(tx, provider),
+                    fields(gas_price = tracing::field::Empty, max_fee = tracing::field::Empty),
+                    err
+                )
+            )]
             async fn set_max_fee_policy<T: Clone + PoliciesField + Chargeable + Into<$tx_ty>>(
                 tx: &mut T,
                 provider: impl DryRunner,
@@ -378,6 +387,13 @@ macro_rules! impl_tx_builder_trait {
                     consensus_parameters,
                 )?;
 
+                #[cfg(feature = "tracing")]
+                {
+                    let span = tracing::Span::current();
+                    span.record("gas_price", gas_price);
+                    span.record("max_fee", max_fee);
+                }
+
                 tx.policies_mut().set(PolicyType::MaxFee, Some(max_fee));
 
                 Ok(())
@@ -601,6 +617,16 @@ impl_tx_builder_trait!(UploadTransactionBuilder, UploadTransaction);
 impl_tx_builder_trait!(UpgradeTransactionBuilder, UpgradeTransaction);
 
 impl ScriptTransactionBuilder {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "build",
+            level = "debug",
+            skip_all,
+            fields(kind = "script", inputs = self.inputs.len(), outputs = self.outputs.len()),
+            err
+        )
+    )]
     async fn build(mut self, provider: impl DryRunner) -> Result<ScriptTransaction> {
         let is_using_predicates = self.is_using_predicates();
 
@@ -747,6 +773,13 @@ impl ScriptTransactionBuilder {
             dry_run.gas_with_tolerance(self.gas_estimation_tolerance)
         };
 
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            histogram.fuels_script_gas_limit = script_gas_limit,
+            script_gas_limit,
+            "script gas limit set"
+        );
+
         *tx.script_gas_limit_mut() = script_gas_limit;
         Ok(())
     }
@@ -794,6 +827,31 @@ impl ScriptTransactionBuilder {
         self
     }
 
+    /// Spends `messages` as inputs, authorized by signatures of their recipients. Messages
+    /// carrying data make it available to the script, e.g. to process a deposit from the base
+    /// layer. Remember to add the recipients as signers.
+    pub fn with_messages(mut self, messages: impl IntoIterator<Item = Message>) -> Self {
+        self.inputs
+            .extend(messages.into_iter().map(Input::message_signed));
+        self
+    }
+
+    /// Spends `messages` addressed to the predicate with the given `code` as inputs. The
+    /// predicate is run with `data` for every message.
+    pub fn with_predicate_messages(
+        mut self,
+        messages: impl IntoIterator<Item = Message>,
+        code: &[u8],
+        data: &[u8],
+    ) -> Self {
+        self.inputs.extend(
+            messages
+                .into_iter()
+                .map(|message| Input::message_predicate(message, code.to_vec(), data.to_vec())),
+        );
+        self
+    }
+
     pub fn with_gas_estimation_tolerance(mut self, tolerance: f32) -> Self {
         self.gas_estimation_tolerance = tolerance;
         self
@@ -927,6 +985,16 @@ fn add_variable_outputs(tx: &mut fuel_tx::Script, variable_outputs: usize) {
 }
 
 impl CreateTransactionBuilder {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "build",
+            level = "debug",
+            skip_all,
+            fields(kind = "create", inputs = self.inputs.len(), outputs = self.outputs.len()),
+            err
+        )
+    )]
     pub async fn build(mut self, provider: impl DryRunner) -> Result<CreateTransaction> {
         let is_using_predicates = self.is_using_predicates();
 
@@ -1050,6 +1118,16 @@ impl CreateTransactionBuilder {
 }
 
 impl UploadTransactionBuilder {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "build",
+            level = "debug",
+            skip_all,
+            fields(kind = "upload", inputs = self.inputs.len(), outputs = self.outputs.len()),
+            err
+        )
+    )]
     pub async fn build(mut self, provider: impl DryRunner) -> Result<UploadTransaction> {
         let is_using_predicates = self.is_using_predicates();
 
@@ -1185,6 +1263,16 @@ impl UploadTransactionBuilder {
 }
 
 impl UpgradeTransactionBuilder {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "build",
+            level = "debug",
+            skip_all,
+            fields(kind = "upgrade", inputs = self.inputs.len(), outputs = self.outputs.len()),
+            err
+        )
+    )]
     pub async fn build(mut self, provider: impl DryRunner) -> Result<UpgradeTransaction> {
         let is_using_predicates = self.is_using_predicates();
         let tx = match self.build_strategy {
@@ -1521,6 +1609,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn messages_are_added_as_inputs() {
+        // given
+        let signed = given_a_message(vec![1]);
+        let predicate = given_a_message(vec![2]);
+
+        // when
+        let builder = ScriptTransactionBuilder::default()
+            .with_messages([signed.clone()])
+            .with_predicate_messages([predicate.clone()], &[3], &[4]);
+
+        // then
+        assert_eq!(
+            builder.inputs(),
+            &vec![
+                Input::message_signed(signed),
+                Input::message_predicate(predicate, vec![3], vec![4])
+            ]
+        );
+    }
+
     fn given_a_message(data: Vec<u8>) -> Message {
         Message {
             sender: Bech32Address::default(),
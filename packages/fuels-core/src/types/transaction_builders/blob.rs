@@ -136,6 +136,16 @@ impl BlobTransactionBuilder {
         Ok(max_tx_size.saturating_sub(current_tx_size))
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "build",
+            level = "debug",
+            skip_all,
+            fields(kind = "blob", inputs = self.inputs.len(), outputs = self.outputs.len()),
+            err
+        )
+    )]
     pub async fn build(mut self, provider: impl DryRunner) -> Result<BlobTransaction> {
         let is_using_predicates = self.is_using_predicates();
 
@@ -36,6 +36,10 @@ impl<R> ScriptTxEstimator<R> {
 }
 
 impl<R: DryRunner> ScriptTxEstimator<R> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, tx), err)
+    )]
     pub async fn run(
         &mut self,
         mut tx: fuel_tx::Script,
@@ -67,6 +71,15 @@ impl<R: DryRunner> ScriptTxEstimator<R> {
         let dry_run = self.dry_runner.dry_run(tx.clone().into()).await?;
         self.last_dry_run = Some(dry_run);
 
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            monotonic_counter.fuels_estimation_dry_runs = 1_u64,
+            succeeded = dry_run.succeeded,
+            script_gas = dry_run.script_gas,
+            variable_outputs = dry_run.variable_outputs,
+            "estimation dry run finished"
+        );
+
         Ok(dry_run)
     }
 
@@ -5,6 +5,12 @@ use fuel_core_client::client::types::{
     block::{Block as ClientBlock, Header as ClientHeader},
     primitives::Bytes32,
 };
+use fuel_core_types::{
+    blockchain::header::{
+        ApplicationHeader, ConsensusHeader, GeneratedApplicationFields, GeneratedConsensusFields,
+    },
+    tai64::Tai64,
+};
 
 #[derive(Debug, Clone)]
 pub struct Header {
@@ -45,6 +51,42 @@ impl From<ClientHeader> for Header {
     }
 }
 
+impl Header {
+    /// Recomputes the hash of the application part of the header from its fields.
+    pub fn compute_application_hash(&self) -> Bytes32 {
+        ApplicationHeader {
+            da_height: self.da_height.into(),
+            consensus_parameters_version: self.consensus_parameters_version,
+            state_transition_bytecode_version: self.state_transition_bytecode_version,
+            generated: GeneratedApplicationFields {
+                transactions_count: self.transactions_count,
+                message_receipt_count: self.message_receipt_count,
+                transactions_root: self.transactions_root,
+                message_outbox_root: self.message_outbox_root,
+                event_inbox_root: self.event_inbox_root,
+            },
+        }
+        .hash()
+    }
+
+    /// Recomputes the block id from the header fields. Returns `None` if the header has no time.
+    pub fn compute_id(&self) -> Option<Bytes32> {
+        let time = Tai64::from_unix(self.time?.timestamp());
+
+        let id = ConsensusHeader {
+            prev_root: self.prev_root,
+            height: self.height.into(),
+            time,
+            generated: GeneratedConsensusFields {
+                application_hash: self.compute_application_hash(),
+            },
+        }
+        .hash();
+
+        Some(id.into())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Block {
     pub id: Bytes32,
@@ -5,7 +5,7 @@ use std::hash::Hash;
 use fuel_tx::{TxPointer, UtxoId};
 use fuel_types::{Bytes32, ContractId};
 
-use crate::types::coin_type::CoinType;
+use crate::types::{coin_type::CoinType, message::Message};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Input {
@@ -39,6 +39,17 @@ impl Input {
         }
     }
 
+    /// Spends `message`, authorized by a signature of its recipient.
+    pub fn message_signed(message: Message) -> Self {
+        Self::resource_signed(CoinType::Message(message))
+    }
+
+    /// Spends `message` addressed to the predicate with the given `code`, which is run with
+    /// the given `data`.
+    pub fn message_predicate(message: Message, code: Vec<u8>, data: Vec<u8>) -> Self {
+        Self::resource_predicate(CoinType::Message(message), code, data)
+    }
+
     pub fn amount(&self) -> Option<u64> {
         match self {
             Self::ResourceSigned { resource, .. } | Self::ResourcePredicate { resource, .. } => {
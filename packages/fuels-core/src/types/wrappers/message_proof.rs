@@ -3,9 +3,52 @@
 use fuel_core_client::client::types::{
     primitives::Nonce, MerkleProof as ClientMerkleProof, MessageProof as ClientMessageProof,
 };
-use fuel_types::Bytes32;
+use fuel_tx::Input;
+use fuel_types::{Address, Bytes32, MessageId};
 
-use crate::types::{bech32::Bech32Address, block::Header};
+use crate::types::{
+    bech32::Bech32Address,
+    block::Header,
+    errors::{error, Error},
+};
+
+/// Reasons a [`MessageProof`] can fail verification.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum MessageProofError {
+    #[error("header of block {height} has no time, its id cannot be recomputed")]
+    MissingBlockTime { height: u32 },
+    #[error("header of block {height} has id `{expected}` but its fields hash to `{computed}`")]
+    BlockIdMismatch {
+        height: u32,
+        expected: Bytes32,
+        computed: Bytes32,
+    },
+    #[error(
+        "message `{message_id}` is not included in message outbox root `{message_outbox_root}`"
+    )]
+    MessageNotIncluded {
+        message_id: MessageId,
+        message_outbox_root: Bytes32,
+    },
+    #[error("commit block {commit_height} must come after message block {message_height}")]
+    CommitBeforeMessageBlock {
+        message_height: u32,
+        commit_height: u32,
+    },
+    #[error("block proof index {proof_index} does not match message block height {height}")]
+    BlockProofIndexMismatch { proof_index: u64, height: u32 },
+    #[error("block `{block_id}` is not included in block history root `{prev_root}`")]
+    BlockNotIncluded {
+        block_id: Bytes32,
+        prev_root: Bytes32,
+    },
+}
+
+impl From<MessageProofError> for Error {
+    fn from(err: MessageProofError) -> Self {
+        error!(Other, "invalid message proof: {err}")
+    }
+}
 
 #[derive(Debug)]
 pub struct MerkleProof {
@@ -43,6 +86,112 @@ pub struct MessageProof {
     pub data: Vec<u8>,
 }
 
+impl MerkleProof {
+    fn verify(&self, root: &Bytes32, leaf: &[u8], num_leaves: u64) -> bool {
+        let proof_set = self.proof_set.iter().map(|node| **node).collect();
+
+        fuel_merkle::binary::verify(root, &leaf, &proof_set, self.proof_index, num_leaves)
+    }
+}
+
+impl MessageProof {
+    /// Recomputes the id of the proven message from its sender, recipient, nonce, amount and
+    /// data.
+    pub fn message_id(&self) -> MessageId {
+        Input::compute_message_id(
+            &Address::from(&self.sender),
+            &Address::from(&self.recipient),
+            &self.nonce,
+            self.amount,
+            &self.data,
+        )
+    }
+
+    /// Verifies the whole proof without trusting the node that produced it: the ids of both
+    /// headers are recomputed from their fields, the message is checked to be included in the
+    /// message block and the message block to be included in the history committed to by the
+    /// commit block.
+    pub fn verify(&self) -> Result<(), MessageProofError> {
+        verify_header(&self.message_block_header)?;
+        verify_header(&self.commit_block_header)?;
+        self.verify_message_inclusion()?;
+        self.verify_block_inclusion()
+    }
+
+    /// Verifies that the message is included in the `message_outbox_root` of the message block
+    /// header. The header itself is not checked, see [`MessageProof::verify`].
+    pub fn verify_message_inclusion(&self) -> Result<(), MessageProofError> {
+        let header = &self.message_block_header;
+        let message_id = self.message_id();
+
+        if !self.message_proof.verify(
+            &header.message_outbox_root,
+            message_id.as_ref(),
+            header.message_receipt_count.into(),
+        ) {
+            return Err(MessageProofError::MessageNotIncluded {
+                message_id,
+                message_outbox_root: header.message_outbox_root,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that the message block is part of the block history committed to by the
+    /// `prev_root` of the commit block header. The headers themselves are not checked, see
+    /// [`MessageProof::verify`].
+    pub fn verify_block_inclusion(&self) -> Result<(), MessageProofError> {
+        let message_header = &self.message_block_header;
+        let commit_header = &self.commit_block_header;
+
+        if message_header.height >= commit_header.height {
+            return Err(MessageProofError::CommitBeforeMessageBlock {
+                message_height: message_header.height,
+                commit_height: commit_header.height,
+            });
+        }
+
+        if self.block_proof.proof_index != u64::from(message_header.height) {
+            return Err(MessageProofError::BlockProofIndexMismatch {
+                proof_index: self.block_proof.proof_index,
+                height: message_header.height,
+            });
+        }
+
+        if !self.block_proof.verify(
+            &commit_header.prev_root,
+            message_header.id.as_ref(),
+            commit_header.height.into(),
+        ) {
+            return Err(MessageProofError::BlockNotIncluded {
+                block_id: message_header.id,
+                prev_root: commit_header.prev_root,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+fn verify_header(header: &Header) -> Result<(), MessageProofError> {
+    let computed = header
+        .compute_id()
+        .ok_or(MessageProofError::MissingBlockTime {
+            height: header.height,
+        })?;
+
+    if computed != header.id {
+        return Err(MessageProofError::BlockIdMismatch {
+            height: header.height,
+            expected: header.id,
+            computed,
+        });
+    }
+
+    Ok(())
+}
+
 impl From<ClientMessageProof> for MessageProof {
     fn from(client_message_proof: ClientMessageProof) -> Self {
         Self {
@@ -58,3 +207,162 @@ impl From<ClientMessageProof> for MessageProof {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use fuel_merkle::binary::in_memory::MerkleTree;
+
+    use super::*;
+
+    const MESSAGE_INDEX: u64 = 1;
+    const MESSAGE_BLOCK_HEIGHT: u32 = 2;
+    const COMMIT_BLOCK_HEIGHT: u32 = 5;
+
+    fn header(height: u32) -> Header {
+        with_id(Header {
+            id: Bytes32::zeroed(),
+            da_height: 0,
+            transactions_count: 0,
+            message_receipt_count: 0,
+            transactions_root: Bytes32::zeroed(),
+            message_outbox_root: Bytes32::zeroed(),
+            event_inbox_root: Bytes32::zeroed(),
+            consensus_parameters_version: 0,
+            state_transition_bytecode_version: 0,
+            height,
+            prev_root: Bytes32::zeroed(),
+            time: DateTime::from_timestamp(1_700_000_000, 0),
+            application_hash: Bytes32::zeroed(),
+        })
+    }
+
+    fn with_id(mut header: Header) -> Header {
+        header.application_hash = header.compute_application_hash();
+        header.id = header.compute_id().unwrap();
+
+        header
+    }
+
+    fn valid_proof() -> MessageProof {
+        let mut proof = MessageProof {
+            message_proof: MerkleProof {
+                proof_set: vec![],
+                proof_index: MESSAGE_INDEX,
+            },
+            block_proof: MerkleProof {
+                proof_set: vec![],
+                proof_index: MESSAGE_BLOCK_HEIGHT.into(),
+            },
+            message_block_header: header(MESSAGE_BLOCK_HEIGHT),
+            commit_block_header: header(COMMIT_BLOCK_HEIGHT),
+            sender: Address::from([1; 32]).into(),
+            recipient: Address::from([2; 32]).into(),
+            nonce: [3; 32].into(),
+            amount: 100,
+            data: vec![4, 5, 6],
+        };
+
+        let mut messages = MerkleTree::new();
+        for index in 0..3 {
+            if index == MESSAGE_INDEX {
+                messages.push(proof.message_id().as_ref());
+            } else {
+                messages.push(&[index as u8; 32]);
+            }
+        }
+        let (message_outbox_root, message_proof_set) = messages.prove(MESSAGE_INDEX).unwrap();
+        proof.message_proof.proof_set = message_proof_set.into_iter().map(Into::into).collect();
+
+        let mut message_block_header = proof.message_block_header.clone();
+        message_block_header.message_receipt_count = 3;
+        message_block_header.message_outbox_root = message_outbox_root.into();
+        proof.message_block_header = with_id(message_block_header);
+
+        let mut blocks = MerkleTree::new();
+        for height in 0..COMMIT_BLOCK_HEIGHT {
+            if height == MESSAGE_BLOCK_HEIGHT {
+                blocks.push(proof.message_block_header.id.as_ref());
+            } else {
+                blocks.push(header(height).id.as_ref());
+            }
+        }
+        let (prev_root, block_proof_set) = blocks.prove(MESSAGE_BLOCK_HEIGHT.into()).unwrap();
+        proof.block_proof.proof_set = block_proof_set.into_iter().map(Into::into).collect();
+
+        let mut commit_block_header = proof.commit_block_header.clone();
+        commit_block_header.prev_root = prev_root.into();
+        proof.commit_block_header = with_id(commit_block_header);
+
+        proof
+    }
+
+    #[test]
+    fn valid_proof_is_verified() {
+        // given
+        let proof = valid_proof();
+
+        // when
+        let result = proof.verify();
+
+        // then
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn tampered_message_is_rejected() {
+        // given
+        let mut proof = valid_proof();
+        proof.amount += 1;
+
+        // when
+        let result = proof.verify();
+
+        // then
+        assert_eq!(
+            result,
+            Err(MessageProofError::MessageNotIncluded {
+                message_id: proof.message_id(),
+                message_outbox_root: proof.message_block_header.message_outbox_root,
+            })
+        );
+    }
+
+    #[test]
+    fn tampered_header_is_rejected() {
+        // given
+        let mut proof = valid_proof();
+        proof.message_block_header.message_outbox_root = Bytes32::zeroed();
+
+        // when
+        let result = proof.verify();
+
+        // then
+        assert!(matches!(
+            result,
+            Err(MessageProofError::BlockIdMismatch {
+                height: MESSAGE_BLOCK_HEIGHT,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn block_missing_from_history_is_rejected() {
+        // given
+        let mut proof = valid_proof();
+        proof.block_proof.proof_set.reverse();
+
+        // when
+        let result = proof.verify();
+
+        // then
+        assert_eq!(
+            result,
+            Err(MessageProofError::BlockNotIncluded {
+                block_id: proof.message_block_header.id,
+                prev_root: proof.commit_block_header.prev_root,
+            })
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use fuels_code_gen::{Abi, AbigenTarget, ProgramType};
+use fuels_code_gen::{Abi, AbigenTarget};
 use syn::{
     parse::{Parse, ParseStream},
     LitStr, Result,
@@ -14,22 +14,13 @@ impl From<MacroAbigenTargets> for Vec<AbigenTarget> {
 
 impl From<MacroAbigenTarget> for AbigenTarget {
     fn from(macro_target: MacroAbigenTarget) -> Self {
-        AbigenTarget::new(
-            macro_target.name,
-            macro_target.source,
-            macro_target.program_type,
-        )
+        macro_target.0
     }
 }
 
-// Although identical to `AbigenTarget` from fuels-core, due to the orphan rule
-// we cannot implement Parse for the latter.
+// Due to the orphan rule we cannot implement Parse for `AbigenTarget` directly.
 #[derive(Debug)]
-pub(crate) struct MacroAbigenTarget {
-    pub(crate) name: String,
-    pub(crate) source: Abi,
-    pub program_type: ProgramType,
-}
+pub(crate) struct MacroAbigenTarget(AbigenTarget);
 
 pub(crate) struct MacroAbigenTargets {
     targets: Vec<MacroAbigenTarget>,
@@ -51,17 +42,25 @@ impl MacroAbigenTarget {
         let program_type = command.name.try_into()?;
 
         let name_values = UniqueNameValues::new(command.contents)?;
-        name_values.validate_has_no_other_names(&["name", "abi"])?;
+        name_values.validate_has_no_other_names(&["name", "abi", "derives"])?;
 
         let name = name_values.get_as_lit_str("name")?.value();
         let abi_lit_str = name_values.get_as_lit_str("abi")?;
         let source = Self::parse_inline_or_load_abi(abi_lit_str)?;
 
-        Ok(Self {
-            name,
-            source,
-            program_type,
-        })
+        let derives = name_values
+            .try_get("derives")
+            .map(|_| name_values.get_as_lit_str("derives"))
+            .transpose()?;
+
+        let mut target = AbigenTarget::new(name, source, program_type);
+        if let Some(derives) = derives {
+            target = target
+                .with_derives(&derives.value())
+                .map_err(|e| syn::Error::new(derives.span(), e.to_string()))?;
+        }
+
+        Ok(Self(target))
     }
 
     fn parse_inline_or_load_abi(abi_lit_str: &LitStr) -> Result<Abi> {
@@ -24,6 +24,12 @@ mod setup_program_test;
 /// `ABI_SOURCE` is a string literal representing either a path to the JSON ABI
 /// file or the contents of the JSON ABI file itself.
 ///
+/// An optional `derives` attribute lists additional derives for the generated
+/// types, e.g. `derives = "serde, Hash"`.
+///
+/// Contracts additionally get a `<Name>Interface` trait and a `<Name>Stub`
+/// implementing it.
+///
 ///```text
 /// abigen!(Contract(
 ///         name = "MyContract",
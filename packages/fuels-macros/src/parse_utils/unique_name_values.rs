@@ -13,16 +13,26 @@ use crate::parse_utils::{validate_no_duplicates, ErrorsExt};
 #[derive(Debug)]
 pub struct UniqueNameValues {
     span: Span,
-    name_values: HashMap<Ident, Lit>,
+    name_values: HashMap<Ident, Expr>,
 }
 
 impl UniqueNameValues {
     pub fn new(tokens: TokenStream) -> syn::Result<Self> {
+        Self::new_with_expressions(tokens, &[])
+    }
+
+    /// Like [`UniqueNameValues::new`], but the names in `expression_names` may be given any Rust
+    /// expression instead of a literal, see [`UniqueNameValues::try_get_expr`].
+    pub fn new_with_expressions(
+        tokens: TokenStream,
+        expression_names: &[&str],
+    ) -> syn::Result<Self> {
         let name_value_metas = Punctuated::<MetaNameValue, syn::token::Comma>::parse_terminated
             .parse2(tokens)
             .map_err(|e| Error::new(e.span(), "expected name='value'"))?;
         let span = name_value_metas.span();
-        let name_values = Self::extract_name_values(name_value_metas.into_iter())?;
+        let name_values =
+            Self::extract_name_values(name_value_metas.into_iter(), expression_names)?;
 
         let names = name_values.iter().map(|(name, _)| name).collect::<Vec<_>>();
         validate_no_duplicates(&names, |&&name| name.clone())?;
@@ -34,6 +44,13 @@ impl UniqueNameValues {
     }
 
     pub fn try_get(&self, name: &str) -> Option<&Lit> {
+        match self.try_get_expr(name)? {
+            Expr::Lit(expr_lit) => Some(&expr_lit.lit),
+            _ => None,
+        }
+    }
+
+    pub fn try_get_expr(&self, name: &str) -> Option<&Expr> {
         self.name_values.get(&ident(name))
     }
 
@@ -70,9 +87,21 @@ impl UniqueNameValues {
         }
     }
 
+    pub fn try_get_as_bool(&self, name: &str) -> syn::Result<Option<bool>> {
+        match self.try_get(name) {
+            Some(Lit::Bool(lit_bool)) => Ok(Some(lit_bool.value())),
+            Some(value) => Err(Error::new_spanned(
+                value.clone(),
+                format!("expected the attribute '{name}' to have a boolean value"),
+            )),
+            None => Ok(None),
+        }
+    }
+
     fn extract_name_values<T: Iterator<Item = MetaNameValue>>(
         name_value_metas: T,
-    ) -> syn::Result<Vec<(Ident, Lit)>> {
+        expression_names: &[&str],
+    ) -> syn::Result<Vec<(Ident, Expr)>> {
         let (name_values, name_value_errors): (Vec<_>, Vec<Error>) = name_value_metas
             .into_iter()
             .map(|nv| {
@@ -83,11 +112,12 @@ impl UniqueNameValues {
                     )
                 })?;
 
-                let Expr::Lit(expr_lit) = nv.value else {
+                let is_expression = expression_names.contains(&ident.to_string().as_str());
+                if !is_expression && !matches!(nv.value, Expr::Lit(_)) {
                     return Err(Error::new_spanned(nv.value, "expected literal"));
-                };
+                }
 
-                Ok((ident, expr_lit.lit))
+                Ok((ident, nv.value))
             })
             .partition_result();
 
@@ -130,6 +160,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn expressions_are_accepted_for_the_given_names() -> syn::Result<()> {
+        // given
+        let command =
+            Command::parse_single_from_token_stream(quote! {SomeCommand(data = encode(1, 2)?)})?;
+
+        // when
+        let name_values = UniqueNameValues::new_with_expressions(command.contents, &["data"])?;
+
+        // then
+        let expected: Expr = syn::parse_quote! {encode(1, 2)?};
+        assert_eq!(name_values.try_get_expr("data"), Some(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn expressions_are_rejected_for_other_names() -> syn::Result<()> {
+        // given
+        let command = Command::parse_single_from_token_stream(
+            quote! {SomeCommand(data = encode(1, 2)?, name = other())},
+        )?;
+
+        // when
+        let err = UniqueNameValues::new_with_expressions(command.contents, &["data"])
+            .expect_err("should have failed");
+
+        // then
+        assert_eq!(err.to_string(), "expected literal");
+
+        Ok(())
+    }
+
     #[test]
     fn duplicates_cause_errors() {
         // given
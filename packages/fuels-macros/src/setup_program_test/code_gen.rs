@@ -9,8 +9,9 @@ use quote::quote;
 use syn::LitStr;
 
 use crate::setup_program_test::parsing::{
-    AbigenCommand, BuildProfile, DeployContractCommand, InitializeWalletCommand, LoadScriptCommand,
-    SetOptionsCommand, TestProgramCommands,
+    AbigenCommand, BuildProfile, DeployContractCommand, FundCommand, FundRecipient,
+    InitializeWalletCommand, LoadPredicateCommand, LoadScriptCommand, SetOptionsCommand,
+    TestProgramCommands,
 };
 
 pub(crate) fn generate_setup_program_test_code(
@@ -22,18 +23,34 @@ pub(crate) fn generate_setup_program_test_code(
         generate_bindings,
         deploy_contract,
         load_scripts,
+        load_predicates,
+        fund,
     } = commands;
 
-    let SetOptionsCommand { profile } = set_options.unwrap_or_default();
+    let SetOptionsCommand {
+        profile,
+        chain_config,
+    } = set_options.unwrap_or_default();
     let project_lookup = generate_project_lookup(&generate_bindings, profile)?;
     let abigen_code = abigen_code(&project_lookup)?;
-    let wallet_code = wallet_initialization_code(initialize_wallets);
+    let genesis_code = genesis_contract_loading_code(&deploy_contract, &project_lookup);
+    let wallet_code = wallet_initialization_code(
+        initialize_wallets,
+        &deploy_contract,
+        &fund,
+        &load_predicates,
+        &project_lookup,
+        chain_config,
+    );
+    let predicate_code = predicate_loading_code(&load_predicates, &project_lookup);
     let deploy_code = contract_deploying_code(&deploy_contract, &project_lookup);
     let script_code = script_loading_code(&load_scripts, &project_lookup);
 
     Ok(quote! {
        #abigen_code
+       #genesis_code
        #wallet_code
+       #predicate_code
        #deploy_code
        #script_code
     })
@@ -47,7 +64,8 @@ fn generate_project_lookup(
         .targets
         .iter()
         .map(|command| -> syn::Result<_> {
-            let project = Project::new(command.program_type, &command.project, profile.clone())?;
+            let project = Project::new(command.program_type, &command.project, profile.clone())?
+                .with_derives(command.derives.clone());
             Ok((command.name.value(), project))
         })
         .collect::<Result<Vec<_>, _>>()?;
@@ -70,16 +88,26 @@ fn parse_abigen_targets(
             let source = Abi::load_from(project.abi_path())
                 .map_err(|e| syn::Error::new(project.path_span, e.to_string()))?;
 
-            Ok(AbigenTarget::new(
-                name.clone(),
-                source,
-                project.program_type,
-            ))
+            let target = AbigenTarget::new(name.clone(), source, project.program_type);
+
+            match &project.derives {
+                Some(derives) => target
+                    .with_derives(&derives.value())
+                    .map_err(|e| syn::Error::new(derives.span(), e.to_string())),
+                None => Ok(target),
+            }
         })
         .collect()
 }
 
-fn wallet_initialization_code(maybe_command: Option<InitializeWalletCommand>) -> TokenStream {
+fn wallet_initialization_code(
+    maybe_command: Option<InitializeWalletCommand>,
+    deploy_commands: &[DeployContractCommand],
+    fund_commands: &[FundCommand],
+    load_predicates: &[LoadPredicateCommand],
+    project_lookup: &HashMap<String, Project>,
+    chain_config: Option<LitStr>,
+) -> TokenStream {
     let command = if let Some(command) = maybe_command {
         command
     } else {
@@ -93,19 +121,136 @@ fn wallet_initialization_code(maybe_command: Option<InitializeWalletCommand>) ->
     }
 
     let num_wallets = wallet_names.len();
+
+    // With a custom chain config the wallets are funded with its base asset, otherwise they
+    // couldn't pay for transactions
+    let (chain_config_code, wallets_config, chain_config) = match chain_config {
+        Some(name) => {
+            let name = ident(&name.value());
+            (
+                quote! {
+                    let chain_config: ::fuels::test_helpers::ChainConfig =
+                        ::std::convert::Into::into(#name.clone());
+                    let base_asset_id = *chain_config.consensus_parameters.base_asset_id();
+                },
+                quote! {
+                    ::fuels::test_helpers::WalletsConfig::new_multiple_assets(
+                        #num_wallets as u64,
+                        vec![::fuels::test_helpers::AssetConfig {
+                            id: base_asset_id,
+                            num_coins: ::fuels::test_helpers::DEFAULT_NUM_COINS,
+                            coin_amount: ::fuels::test_helpers::DEFAULT_COIN_AMOUNT,
+                        }],
+                    )
+                },
+                quote! { Some(chain_config) },
+            )
+        }
+        None if fund_commands.is_empty() => (
+            quote! {},
+            quote! {
+                ::fuels::test_helpers::WalletsConfig::new(Some(#num_wallets as u64), None, None)
+            },
+            quote! { None },
+        ),
+        None => (
+            quote! {
+                let base_asset_id = ::fuels::types::AssetId::zeroed();
+            },
+            quote! {
+                ::fuels::test_helpers::WalletsConfig::new(Some(#num_wallets as u64), None, None)
+            },
+            quote! { None },
+        ),
+    };
+
+    let genesis_contracts = genesis_contract_names(deploy_commands);
+    let genesis_coins = genesis_coins_code(fund_commands, load_predicates, project_lookup);
+
+    let wallets = if genesis_contracts.is_empty() && fund_commands.is_empty() {
+        quote! {
+            ::fuels::test_helpers::launch_custom_provider_and_get_wallets(
+                #wallets_config,
+                None,
+                #chain_config,
+            )
+            .await
+            .expect("Error while trying to fetch wallets from the custom provider")
+        }
+    } else {
+        quote! {
+            ::fuels::test_helpers::launch_custom_provider_with_genesis_and_get_wallets(
+                #wallets_config,
+                ::fuels::test_helpers::GenesisDeployments::default()
+                    .with_contracts([#(#genesis_contracts.clone()),*])
+                    .with_coins(#genesis_coins),
+                None,
+                #chain_config,
+            )
+            .await
+            .expect("Error while trying to fetch wallets from the custom provider")
+            .0
+        }
+    };
+
     quote! {
-        let [#(#wallet_names),*]: [_; #num_wallets] = ::fuels::test_helpers::launch_custom_provider_and_get_wallets(
-            ::fuels::test_helpers::WalletsConfig::new(Some(#num_wallets as u64), None, None),
-            None,
-            None,
-        )
-        .await
-        .expect("Error while trying to fetch wallets from the custom provider")
+        let [#(#wallet_names),*]: [_; #num_wallets] = {
+            #chain_config_code
+            #wallets
+        }
         .try_into()
         .expect("Should have the exact number of wallets");
     }
 }
 
+// The coins of all `Fund` commands, paid in the base asset unless given an `asset_id`. Uses
+// `base_asset_id` from the wallet initialization code. The predicates are only loaded once the
+// wallets exist, so their addresses are taken from predicates built without data, which doesn't
+// change the address.
+fn genesis_coins_code(
+    commands: &[FundCommand],
+    load_predicates: &[LoadPredicateCommand],
+    project_lookup: &HashMap<String, Project>,
+) -> TokenStream {
+    let coins = commands.iter().map(|command| {
+        let owner = match &command.recipient {
+            FundRecipient::Predicate(name) => {
+                let load_command = load_predicates
+                    .iter()
+                    .find(|load_command| load_command.name == name.value())
+                    .expect("Funded predicates should be loaded");
+                let predicate = predicate_building_code(load_command, project_lookup);
+                quote! { #predicate.address().clone() }
+            }
+            FundRecipient::Address(address) => quote! {
+                <::fuels::types::bech32::Bech32Address as ::std::str::FromStr>::from_str(#address)
+                    .expect("Invalid address in the `Fund` command")
+            },
+        };
+        let asset_id = match &command.asset_id {
+            Some(asset_id) => quote! {
+                <::fuels::types::AssetId as ::std::str::FromStr>::from_str(#asset_id)
+                    .expect("Invalid asset id in the `Fund` command")
+            },
+            None => quote! { base_asset_id },
+        };
+        let FundCommand {
+            num_coins, amount, ..
+        } = command;
+
+        quote! {
+            ::fuels::test_helpers::setup_single_asset_coins(
+                #owner,
+                #asset_id,
+                #num_coins,
+                #amount,
+            )
+        }
+    });
+
+    quote! { [#(#coins),*].into_iter().flatten() }
+}
+
 fn extract_wallet_names(command: &InitializeWalletCommand) -> Vec<Ident> {
     command
         .names
@@ -114,6 +259,77 @@ fn extract_wallet_names(command: &InitializeWalletCommand) -> Vec<Ident> {
         .collect()
 }
 
+fn genesis_contract_names(commands: &[DeployContractCommand]) -> Vec<Ident> {
+    commands
+        .iter()
+        .filter(|command| command.genesis)
+        .map(|command| ident(&command.name))
+        .collect()
+}
+
+// Contracts deployed at genesis have to be loaded before the wallets are initialized, since
+// launching the provider writes them into the genesis state. Until the contract instance is
+// created, the instance name holds the `GenesisContract`.
+fn genesis_contract_loading_code(
+    commands: &[DeployContractCommand],
+    project_lookup: &HashMap<String, Project>,
+) -> TokenStream {
+    commands
+        .iter()
+        .filter(|command| command.genesis)
+        .map(|command| {
+            let contract_instance_name = ident(&command.name);
+            let load_code = contract_loading_code(command, project_lookup);
+
+            quote! {
+                let #contract_instance_name = {
+                    #load_code
+
+                    ::fuels::test_helpers::GenesisContract::new(loaded_contract.code())
+                        .with_salt(loaded_contract.salt())
+                        .with_storage_slots(loaded_contract.storage_slots().to_vec())
+                };
+            }
+        })
+        .reduce(|mut all_code, code| {
+            all_code.extend(code);
+            all_code
+        })
+        .unwrap_or_default()
+}
+
+fn contract_loading_code(
+    command: &DeployContractCommand,
+    project_lookup: &HashMap<String, Project>,
+) -> TokenStream {
+    let project = project_lookup
+        .get(&command.contract.value())
+        .expect("Project should be in lookup");
+    let bin_path = project.bin_path();
+
+    let salt = if command.random_salt {
+        quote! {
+            // Generate random salt for contract deployment.
+            // These lines must be inside the `quote!` macro, otherwise the salt remains
+            // identical between macro compilation, causing contract id collision.
+            ::fuels::test_helpers::generate_random_salt()
+        }
+    } else {
+        quote! { [0; 32] }
+    };
+
+    quote! {
+        let salt: [u8; 32] = #salt;
+        let load_config = ::fuels::programs::contract::LoadConfiguration::default().with_salt(salt);
+
+        let loaded_contract = ::fuels::programs::contract::Contract::load_from(
+            #bin_path,
+            load_config
+        )
+        .expect("Failed to load the contract");
+    }
+}
+
 fn contract_deploying_code(
     commands: &[DeployContractCommand],
     project_lookup: &HashMap<String, Project>,
@@ -124,35 +340,21 @@ fn contract_deploying_code(
             let contract_instance_name = ident(&command.name);
             let contract_struct_name = ident(&command.contract.value());
             let wallet_name = ident(&command.wallet);
-            let random_salt = command.random_salt;
 
-            let project = project_lookup
-                .get(&command.contract.value())
-                .expect("Project should be in lookup");
-            let bin_path = project.bin_path();
+            if command.genesis {
+                return quote! {
+                    let #contract_instance_name = #contract_struct_name::new(
+                        #contract_instance_name.contract_id(),
+                        #wallet_name.clone()
+                    );
+                };
+            }
 
-            let salt = if random_salt {
-                quote! {
-                    // Generate random salt for contract deployment.
-                    // These lines must be inside the `quote!` macro, otherwise the salt remains
-                    // identical between macro compilation, causing contract id collision.
-                    ::fuels::test_helpers::generate_random_salt()
-                }
-            } else {
-                quote! { [0; 32] }
-            };
+            let load_code = contract_loading_code(command, project_lookup);
 
             quote! {
-                let salt: [u8; 32] = #salt;
-
                 let #contract_instance_name = {
-                    let load_config = ::fuels::programs::contract::LoadConfiguration::default().with_salt(salt);
-
-                    let loaded_contract = ::fuels::programs::contract::Contract::load_from(
-                        #bin_path,
-                        load_config
-                    )
-                    .expect("Failed to load the contract");
+                    #load_code
 
                     let contract_id = loaded_contract.deploy_if_not_exists(
                         &#wallet_name,
@@ -199,11 +401,66 @@ fn script_loading_code(
         .unwrap_or_default()
 }
 
+// Predicates are loaded after the wallets are initialized, so that their data can use the wallets.
+fn predicate_loading_code(
+    commands: &[LoadPredicateCommand],
+    project_lookup: &HashMap<String, Project>,
+) -> TokenStream {
+    commands
+        .iter()
+        .map(|command| {
+            let predicate_instance_name = ident(&command.name);
+            let wallet_name = ident(&command.wallet);
+            let predicate = predicate_building_code(command, project_lookup);
+
+            let data = command.data.as_ref().map(|data| {
+                quote! { .with_data(#data) }
+            });
+
+            quote! {
+                let #predicate_instance_name = #predicate
+                    #data
+                    .with_provider(
+                        ::fuels::accounts::ViewOnlyAccount::try_provider(&#wallet_name)
+                            .expect("The wallet of the predicate should have a provider")
+                            .clone()
+                    );
+            }
+        })
+        .reduce(|mut all_code, code| {
+            all_code.extend(code);
+            all_code
+        })
+        .unwrap_or_default()
+}
+
+// The predicate with its configurables, but without data or provider.
+fn predicate_building_code(
+    command: &LoadPredicateCommand,
+    project_lookup: &HashMap<String, Project>,
+) -> TokenStream {
+    let project = project_lookup
+        .get(&command.program.value())
+        .expect("Project should be in lookup");
+    let bin_path = project.bin_path();
+
+    let configurables = command.configurables.as_ref().map(|configurables| {
+        quote! { .with_configurables(#configurables) }
+    });
+
+    quote! {
+        ::fuels::accounts::predicate::Predicate::load_from(#bin_path)
+            .expect("Failed to load the predicate")
+            #configurables
+    }
+}
+
 struct Project {
     program_type: ProgramType,
     path: PathBuf,
     path_span: Span,
     profile: BuildProfile,
+    derives: Option<LitStr>,
 }
 
 impl Project {
@@ -220,9 +477,15 @@ impl Project {
             path,
             path_span: dir.span(),
             profile,
+            derives: None,
         })
     }
 
+    fn with_derives(mut self, derives: Option<LitStr>) -> Self {
+        self.derives = derives;
+        self
+    }
+
     fn compile_file_path(&self, suffix: &str, description: &str) -> String {
         self.path
             .join(
@@ -1,6 +1,7 @@
 pub(crate) use commands::{
-    AbigenCommand, BuildProfile, DeployContractCommand, InitializeWalletCommand, LoadScriptCommand,
-    SetOptionsCommand, TestProgramCommands,
+    AbigenCommand, BuildProfile, DeployContractCommand, FundCommand, FundRecipient,
+    InitializeWalletCommand, LoadPredicateCommand, LoadScriptCommand, SetOptionsCommand,
+    TestProgramCommands,
 };
 
 mod command_parser;
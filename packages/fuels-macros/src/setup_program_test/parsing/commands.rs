@@ -1,7 +1,9 @@
 pub(crate) use abigen::AbigenCommand;
 pub(crate) use deploy_contract::DeployContractCommand;
+pub(crate) use fund::{FundCommand, FundRecipient};
 pub(crate) use initialize_wallet::InitializeWalletCommand;
 use itertools::Itertools;
+pub(crate) use load_predicate::LoadPredicateCommand;
 pub(crate) use load_script::LoadScriptCommand;
 pub(crate) use set_options::{BuildProfile, SetOptionsCommand};
 use syn::{
@@ -13,25 +15,33 @@ use crate::setup_program_test::parsing::{
     command_parser::command_parser,
     validations::{
         extract_the_abigen_command, validate_all_contracts_are_known,
-        validate_all_scripts_are_known, validate_zero_or_one_wallet_command_present,
+        validate_all_predicates_are_known, validate_all_scripts_are_known,
+        validate_chain_config_is_used, validate_funded_predicates_are_loaded,
+        validate_funding_uses_generated_wallets,
+        validate_genesis_deployments_use_generated_wallets,
+        validate_zero_or_one_wallet_command_present,
     },
 };
 
 mod abigen;
 mod deploy_contract;
+mod fund;
 mod initialize_wallet;
+mod load_predicate;
 mod load_script;
 mod set_options;
 
 // Contains the result of parsing the input to the `setup_program_test` macro.
 // Contents represent the users wishes with regards to wallet initialization,
-// bindings generation and contract deployment.
+// bindings generation, contract deployment and predicate funding.
 pub(crate) struct TestProgramCommands {
     pub(crate) set_options: Option<SetOptionsCommand>,
     pub(crate) initialize_wallets: Option<InitializeWalletCommand>,
     pub(crate) generate_bindings: AbigenCommand,
     pub(crate) deploy_contract: Vec<DeployContractCommand>,
     pub(crate) load_scripts: Vec<LoadScriptCommand>,
+    pub(crate) load_predicates: Vec<LoadPredicateCommand>,
+    pub(crate) fund: Vec<FundCommand>,
 }
 
 command_parser!(
@@ -39,7 +49,9 @@ command_parser!(
     Wallets -> InitializeWalletCommand,
     Abigen -> AbigenCommand,
     Deploy -> DeployContractCommand,
-    LoadScript -> LoadScriptCommand
+    LoadScript -> LoadScriptCommand,
+    LoadPredicate -> LoadPredicateCommand,
+    Fund -> FundCommand
 );
 
 impl Parse for TestProgramCommands {
@@ -54,14 +66,38 @@ impl Parse for TestProgramCommands {
 
         validate_all_scripts_are_known(&abigen_command, &parsed_commands.LoadScript)?;
 
+        validate_all_predicates_are_known(&abigen_command, &parsed_commands.LoadPredicate)?;
+
+        validate_funded_predicates_are_loaded(
+            &parsed_commands.LoadPredicate,
+            &parsed_commands.Fund,
+        )?;
+
         validate_zero_or_one_wallet_command_present(&parsed_commands.Wallets)?;
 
+        validate_funding_uses_generated_wallets(
+            parsed_commands.Wallets.last(),
+            &parsed_commands.Fund,
+        )?;
+
+        validate_genesis_deployments_use_generated_wallets(
+            parsed_commands.Wallets.last(),
+            &parsed_commands.Deploy,
+        )?;
+
+        validate_chain_config_is_used(
+            parsed_commands.Options.last(),
+            parsed_commands.Wallets.last(),
+        )?;
+
         Ok(Self {
             set_options: parsed_commands.Options.pop(),
             initialize_wallets: parsed_commands.Wallets.pop(),
             generate_bindings: abigen_command,
             deploy_contract: parsed_commands.Deploy,
             load_scripts: parsed_commands.LoadScript,
+            load_predicates: parsed_commands.LoadPredicate,
+            fund: parsed_commands.Fund,
         })
     }
 }
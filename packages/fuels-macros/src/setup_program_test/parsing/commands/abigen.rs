@@ -11,6 +11,7 @@ pub(crate) struct TargetInfo {
     pub(crate) name: LitStr,
     pub(crate) project: LitStr,
     pub(crate) program_type: ProgramType,
+    pub(crate) derives: Option<LitStr>,
 }
 
 impl TryFrom<Command> for TargetInfo {
@@ -20,15 +21,20 @@ impl TryFrom<Command> for TargetInfo {
         let program_type = command.name.try_into()?;
 
         let name_values = UniqueNameValues::new(command.contents)?;
-        name_values.validate_has_no_other_names(&["name", "project"])?;
+        name_values.validate_has_no_other_names(&["name", "project", "derives"])?;
 
         let name = name_values.get_as_lit_str("name")?.clone();
         let project = name_values.get_as_lit_str("project")?.clone();
+        let derives = name_values
+            .try_get("derives")
+            .map(|_| name_values.get_as_lit_str("derives").cloned())
+            .transpose()?;
 
         Ok(Self {
             name,
             project,
             program_type,
+            derives,
         })
     }
 }
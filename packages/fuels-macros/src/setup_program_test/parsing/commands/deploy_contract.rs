@@ -10,6 +10,7 @@ pub struct DeployContractCommand {
     pub contract: LitStr,
     pub wallet: String,
     pub random_salt: bool,
+    pub genesis: bool,
 }
 
 impl TryFrom<Command> for DeployContractCommand {
@@ -17,7 +18,13 @@ impl TryFrom<Command> for DeployContractCommand {
 
     fn try_from(command: Command) -> Result<Self, Self::Error> {
         let name_values = UniqueNameValues::new(command.contents)?;
-        name_values.validate_has_no_other_names(&["name", "contract", "wallet", "random_salt"])?;
+        name_values.validate_has_no_other_names(&[
+            "name",
+            "contract",
+            "wallet",
+            "random_salt",
+            "genesis",
+        ])?;
 
         let name = name_values.get_as_lit_str("name")?.value();
         let contract = name_values.get_as_lit_str("contract")?.clone();
@@ -26,12 +33,14 @@ impl TryFrom<Command> for DeployContractCommand {
             let Lit::Bool(b) = opt else { return true };
             b.value()
         });
+        let genesis = name_values.try_get_as_bool("genesis")?.unwrap_or(false);
 
         Ok(Self {
             name,
             contract,
             wallet,
             random_salt,
+            genesis,
         })
     }
 }
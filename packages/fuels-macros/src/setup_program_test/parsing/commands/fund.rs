@@ -0,0 +1,90 @@
+use std::convert::TryFrom;
+
+use proc_macro2::Span;
+use syn::{Error, Lit, LitStr};
+
+use crate::parse_utils::{Command, UniqueNameValues};
+
+#[derive(Debug, Clone)]
+pub enum FundRecipient {
+    /// Name of a predicate loaded with `LoadPredicate`.
+    Predicate(LitStr),
+    /// A bech32 or hex address.
+    Address(LitStr),
+}
+
+#[derive(Debug, Clone)]
+pub struct FundCommand {
+    pub span: Span,
+    pub recipient: FundRecipient,
+    pub asset_id: Option<LitStr>,
+    pub num_coins: u64,
+    pub amount: u64,
+}
+
+impl TryFrom<Command> for FundCommand {
+    type Error = Error;
+
+    fn try_from(command: Command) -> Result<Self, Self::Error> {
+        let span = command.name.span();
+        let name_values = UniqueNameValues::new(command.contents)?;
+        name_values.validate_has_no_other_names(&[
+            "predicate",
+            "address",
+            "asset_id",
+            "coins",
+            "amount",
+        ])?;
+
+        let recipient = match (
+            name_values.try_get("predicate"),
+            name_values.try_get("address"),
+        ) {
+            (Some(_), None) => {
+                FundRecipient::Predicate(name_values.get_as_lit_str("predicate")?.clone())
+            }
+            (None, Some(_)) => {
+                FundRecipient::Address(name_values.get_as_lit_str("address")?.clone())
+            }
+            _ => {
+                return Err(Error::new(
+                    span,
+                    "expected exactly one of the attributes 'predicate' or 'address'",
+                ))
+            }
+        };
+
+        let asset_id = match name_values.try_get("asset_id") {
+            Some(_) => Some(name_values.get_as_lit_str("asset_id")?.clone()),
+            None => None,
+        };
+
+        let num_coins = match name_values.try_get("coins") {
+            Some(lit) => parse_u64(lit, "coins")?,
+            None => 1,
+        };
+        let amount = match name_values.try_get("amount") {
+            Some(lit) => parse_u64(lit, "amount")?,
+            None => return Err(Error::new(span, "missing attribute 'amount'")),
+        };
+
+        Ok(Self {
+            span,
+            recipient,
+            asset_id,
+            num_coins,
+            amount,
+        })
+    }
+}
+
+fn parse_u64(lit: &Lit, name: &str) -> syn::Result<u64> {
+    let Lit::Int(lit_int) = lit else {
+        return Err(Error::new_spanned(
+            lit,
+            format!("expected the attribute '{name}' to have an integer value"),
+        ));
+    };
+
+    lit_int.base10_parse()
+}
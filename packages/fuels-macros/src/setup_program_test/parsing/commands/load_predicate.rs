@@ -0,0 +1,46 @@
+use std::convert::TryFrom;
+
+use syn::{Error, Expr, LitStr};
+
+use crate::parse_utils::{Command, UniqueNameValues};
+
+#[derive(Debug, Clone)]
+pub struct LoadPredicateCommand {
+    pub name: String,
+    pub program: LitStr,
+    pub wallet: String,
+    pub data: Option<Expr>,
+    pub configurables: Option<Expr>,
+}
+
+impl TryFrom<Command> for LoadPredicateCommand {
+    type Error = Error;
+
+    fn try_from(command: Command) -> Result<Self, Self::Error> {
+        // `data` and `configurables` are Rust expressions, so that they can use the encoder and
+        // configurables generated by the `Abigen` command as well as the wallets
+        let name_values =
+            UniqueNameValues::new_with_expressions(command.contents, &["data", "configurables"])?;
+        name_values.validate_has_no_other_names(&[
+            "name",
+            "program",
+            "wallet",
+            "data",
+            "configurables",
+        ])?;
+
+        let name = name_values.get_as_lit_str("name")?.value();
+        let program = name_values.get_as_lit_str("program")?.clone();
+        let wallet = name_values.get_as_lit_str("wallet")?.value();
+        let data = name_values.try_get_expr("data").cloned();
+        let configurables = name_values.try_get_expr("configurables").cloned();
+
+        Ok(Self {
+            name,
+            program,
+            wallet,
+            data,
+            configurables,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use std::{convert::TryFrom, fmt, str::FromStr};
 
-use syn::Error;
+use syn::{Error, LitStr};
 
 use crate::parse_utils::{Command, UniqueNameValues};
 
@@ -39,6 +39,7 @@ impl fmt::Display for BuildProfile {
 #[derive(Debug, Clone, Default)]
 pub struct SetOptionsCommand {
     pub profile: BuildProfile,
+    pub chain_config: Option<LitStr>,
 }
 
 impl TryFrom<Command> for SetOptionsCommand {
@@ -46,15 +47,28 @@ impl TryFrom<Command> for SetOptionsCommand {
 
     fn try_from(command: Command) -> Result<Self, Self::Error> {
         let name_values = UniqueNameValues::new(command.contents)?;
-        name_values.validate_has_no_other_names(&["profile"])?;
+        name_values.validate_has_no_other_names(&["profile", "chain_config"])?;
 
-        let profile = name_values.get_as_lit_str("profile")?;
-        let profile = profile
-            .value()
-            .as_str()
-            .parse()
-            .map_err(|msg| Error::new(profile.span(), msg))?;
+        let profile = match name_values.try_get("profile") {
+            Some(_) => {
+                let profile = name_values.get_as_lit_str("profile")?;
+                profile
+                    .value()
+                    .as_str()
+                    .parse()
+                    .map_err(|msg| Error::new(profile.span(), msg))?
+            }
+            None => BuildProfile::default(),
+        };
+
+        let chain_config = match name_values.try_get("chain_config") {
+            Some(_) => Some(name_values.get_as_lit_str("chain_config")?.clone()),
+            None => None,
+        };
 
-        Ok(Self { profile })
+        Ok(Self {
+            profile,
+            chain_config,
+        })
     }
 }
@@ -7,7 +7,8 @@ use syn::{Error, LitStr, Result};
 use crate::{
     parse_utils::ErrorsExt,
     setup_program_test::parsing::{
-        AbigenCommand, DeployContractCommand, InitializeWalletCommand, LoadScriptCommand,
+        AbigenCommand, DeployContractCommand, FundCommand, FundRecipient, InitializeWalletCommand,
+        LoadPredicateCommand, LoadScriptCommand, SetOptionsCommand,
     },
 };
 
@@ -77,6 +78,56 @@ pub(crate) fn validate_all_scripts_are_known(
         .validate_no_errors()
 }
 
+pub(crate) fn validate_all_predicates_are_known(
+    abigen_command: &AbigenCommand,
+    load_commands: &[LoadPredicateCommand],
+) -> Result<()> {
+    extract_predicates_to_load(load_commands)
+        .difference(&names_of_program_bindings(
+            abigen_command,
+            ProgramType::Predicate,
+        ))
+        .flat_map(|unknown_predicate| {
+            [
+                Error::new_spanned(unknown_predicate, "Predicate is unknown"),
+                Error::new(
+                    abigen_command.span,
+                    format!(
+                        "Consider adding: Predicate(name=\"{}\", project=...)",
+                        unknown_predicate.value()
+                    ),
+                ),
+            ]
+        })
+        .validate_no_errors()
+}
+
+pub(crate) fn validate_funded_predicates_are_loaded(
+    load_commands: &[LoadPredicateCommand],
+    fund_commands: &[FundCommand],
+) -> Result<()> {
+    let loaded_predicates: HashSet<&str> = load_commands
+        .iter()
+        .map(|command| command.name.as_str())
+        .collect();
+
+    fund_commands
+        .iter()
+        .filter_map(|command| match &command.recipient {
+            FundRecipient::Predicate(name) if !loaded_predicates.contains(name.value().as_str()) => {
+                Some(Error::new_spanned(
+                    name,
+                    format!(
+                        "Predicate is not loaded, consider adding: LoadPredicate(name=\"{}\", program=..., wallet=...)",
+                        name.value()
+                    ),
+                ))
+            }
+            _ => None,
+        })
+        .validate_no_errors()
+}
+
 pub(crate) fn validate_zero_or_one_wallet_command_present(
     commands: &[InitializeWalletCommand],
 ) -> Result<()> {
@@ -92,6 +143,61 @@ pub(crate) fn validate_zero_or_one_wallet_command_present(
     }
 }
 
+pub(crate) fn validate_genesis_deployments_use_generated_wallets(
+    wallet_command: Option<&InitializeWalletCommand>,
+    deploy_commands: &[DeployContractCommand],
+) -> Result<()> {
+    let generated_wallets: HashSet<String> = wallet_command
+        .map(|command| command.names.iter().map(LitStr::value).collect())
+        .unwrap_or_default();
+
+    deploy_commands
+        .iter()
+        .filter(|command| command.genesis && !generated_wallets.contains(&command.wallet))
+        .map(|command| {
+            Error::new_spanned(
+                &command.contract,
+                format!(
+                    "Contracts deployed at genesis need a wallet from the `Wallets` command, `{}` is not one of them",
+                    command.wallet
+                ),
+            )
+        })
+        .validate_no_errors()
+}
+
+pub(crate) fn validate_funding_uses_generated_wallets(
+    wallet_command: Option<&InitializeWalletCommand>,
+    fund_commands: &[FundCommand],
+) -> Result<()> {
+    if wallet_command.is_some() {
+        return Ok(());
+    }
+
+    fund_commands
+        .iter()
+        .map(|command| {
+            Error::new(
+                command.span,
+                "`Fund` seeds the node launched by the `Wallets` command, add a `Wallets` command",
+            )
+        })
+        .validate_no_errors()
+}
+
+pub(crate) fn validate_chain_config_is_used(
+    options_command: Option<&SetOptionsCommand>,
+    wallet_command: Option<&InitializeWalletCommand>,
+) -> Result<()> {
+    match options_command.and_then(|command| command.chain_config.as_ref()) {
+        Some(chain_config) if wallet_command.is_none() => Err(Error::new_spanned(
+            chain_config,
+            "`chain_config` configures the node launched by the `Wallets` command, add a `Wallets` command",
+        )),
+        _ => Ok(()),
+    }
+}
+
 fn names_of_program_bindings(
     commands: &AbigenCommand,
     program_type: ProgramType,
@@ -107,6 +213,10 @@ fn extract_contracts_to_deploy(commands: &[DeployContractCommand]) -> HashSet<&L
     commands.iter().map(|c| &c.contract).collect()
 }
 
+fn extract_predicates_to_load(commands: &[LoadPredicateCommand]) -> HashSet<&LitStr> {
+    commands.iter().map(|c| &c.program).collect()
+}
+
 fn extract_scripts_to_load(commands: &[LoadScriptCommand]) -> HashSet<&LitStr> {
     commands.iter().map(|c| &c.script).collect()
 }
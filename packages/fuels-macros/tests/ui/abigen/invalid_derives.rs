@@ -0,0 +1,19 @@
+use fuels_macros::abigen;
+
+abigen!(Contract(
+    name = "SomeName",
+    abi = r#"{
+        "programType": "contract",
+        "specVersion": "1",
+        "encodingVersion": "1",
+        "concreteTypes": [],
+        "metadataTypes": [],
+        "functions": [],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }"#,
+    derives = "serde, not a path"
+));
+
+fn main() {}
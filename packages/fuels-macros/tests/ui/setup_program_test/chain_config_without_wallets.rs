@@ -0,0 +1,8 @@
+use fuels_macros::setup_program_test;
+
+setup_program_test!(
+    Options(chain_config = "chain_config"),
+    Abigen(Contract(project = "some_project", name = "SomeContract")),
+);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use fuels_macros::setup_program_test;
+
+setup_program_test!(
+    Wallets("wallet"),
+    Abigen(Predicate(project = "some_project", name = "SomePredicate")),
+    Fund(amount = 100)
+);
+
+fn main() {}
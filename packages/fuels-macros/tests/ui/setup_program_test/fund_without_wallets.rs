@@ -0,0 +1,13 @@
+use fuels_macros::setup_program_test;
+
+setup_program_test!(
+    Abigen(Predicate(project = "some_project", name = "SomePredicate")),
+    LoadPredicate(
+        name = "some_instance",
+        program = "SomePredicate",
+        wallet = "some_wallet"
+    ),
+    Fund(predicate = "some_instance", amount = 100)
+);
+
+fn main() {}
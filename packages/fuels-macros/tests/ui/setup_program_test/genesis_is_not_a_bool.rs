@@ -0,0 +1,14 @@
+use fuels_macros::setup_program_test;
+
+setup_program_test!(
+    Wallets("wallet"),
+    Abigen(Contract(project = "some_project", name = "SomeContract")),
+    Deploy(
+        name = "some_instance",
+        contract = "SomeContract",
+        wallet = "wallet",
+        genesis = "yes"
+    )
+);
+
+fn main() {}
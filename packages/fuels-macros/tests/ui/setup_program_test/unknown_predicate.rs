@@ -0,0 +1,13 @@
+use fuels_macros::setup_program_test;
+
+setup_program_test!(
+    Wallets("wallet"),
+    Abigen(Predicate(project = "some_project", name = "MismatchedName")),
+    LoadPredicate(
+        name = "some_instance",
+        program = "SomeUnknownPredicate",
+        wallet = "wallet"
+    )
+);
+
+fn main() {}
@@ -1,7 +1,9 @@
 use std::{fmt::Debug, marker::PhantomData};
 
-use fuel_tx::{AssetId, Bytes32, Receipt};
-use fuels_accounts::{provider::TransactionCost, Account};
+use fuel_tx::{AssetId, Bytes32, Receipt, TxId};
+use fuels_accounts::{
+    dispatcher::Dispatchable, predicate::Predicate, provider::TransactionCost, Account,
+};
 use fuels_core::{
     codec::{ABIEncoder, DecoderConfig, EncoderConfig, LogDecoder},
     traits::{Parameterize, Tokenizable},
@@ -9,6 +11,7 @@ use fuels_core::{
         bech32::{Bech32Address, Bech32ContractId},
         errors::{error, transaction::Reason, Error, Result},
         input::Input,
+        message::Message,
         output::Output,
         transaction::{ScriptTransaction, Transaction, TxPolicies},
         transaction_builders::{
@@ -23,7 +26,9 @@ use fuels_core::{
 use crate::{
     calls::{
         receipt_parser::ReceiptParser,
-        traits::{ContractDependencyConfigurator, ResponseParser, TransactionTuner},
+        traits::{
+            ContractDependencyConfigurator, InputConfigurator, ResponseParser, TransactionTuner,
+        },
         utils::find_id_of_missing_contract,
         CallParameters, ContractCall, Execution, ScriptCall,
     },
@@ -119,6 +124,43 @@ where
     }
 }
 
+impl<A, C, T> CallHandler<A, C, T>
+where
+    A: Account,
+    C: InputConfigurator,
+{
+    /// Spends `messages` addressed to the calling account as inputs of the transaction. The data
+    /// of the messages is available to the called contract or script, e.g. to process a deposit
+    /// from the base layer. Messages can be looked up by their nonce with
+    /// `Provider::get_message`.
+    pub fn with_messages(mut self, messages: impl IntoIterator<Item = Message>) -> Self {
+        self.call
+            .append_inputs(messages.into_iter().map(Input::message_signed).collect());
+        self
+    }
+
+    /// Spends `messages` addressed to `predicate`, e.g. a relayer predicate, as inputs of the
+    /// transaction. The predicate is run with its data for every message.
+    pub fn with_predicate_messages(
+        mut self,
+        predicate: &Predicate,
+        messages: impl IntoIterator<Item = Message>,
+    ) -> Self {
+        let inputs = messages
+            .into_iter()
+            .map(|message| {
+                Input::message_predicate(
+                    message,
+                    predicate.code().to_vec(),
+                    predicate.data().to_vec(),
+                )
+            })
+            .collect();
+        self.call.append_inputs(inputs);
+        self
+    }
+}
+
 impl<A, C, T> CallHandler<A, C, T>
 where
     A: Account,
@@ -162,12 +204,24 @@ where
     }
 
     /// Call a contract's method on the node, in a state-modifying manner.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(tx_id = tracing::field::Empty),
+            err
+        )
+    )]
     pub async fn call(mut self) -> Result<CallResponse<T>> {
         let tx = self.build_tx().await?;
         let provider = self.account.try_provider()?;
 
         self.cached_tx_id = Some(tx.id(provider.chain_id()));
 
+        #[cfg(feature = "tracing")]
+        record_tx_id(self.cached_tx_id);
+
         let tx_status = provider.send_transaction_and_await_commit(tx).await?;
 
         let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
@@ -175,6 +229,15 @@ where
         self.get_response(receipts)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(tx_id = tracing::field::Empty),
+            err
+        )
+    )]
     pub async fn submit(mut self) -> Result<SubmitResponse<A, C, T>> {
         let tx = self.build_tx().await?;
         let provider = self.account.try_provider()?;
@@ -182,11 +245,18 @@ where
         let tx_id = provider.send_transaction(tx.clone()).await?;
         self.cached_tx_id = Some(tx_id);
 
+        #[cfg(feature = "tracing")]
+        record_tx_id(self.cached_tx_id);
+
         Ok(SubmitResponse::<A, C, T>::new(tx_id, self))
     }
 
     /// Call a contract's method on the node, in a simulated manner, meaning the state of the
     /// blockchain is *not* modified but simulated.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), err)
+    )]
     pub async fn simulate(&mut self, execution: Execution) -> Result<CallResponse<T>> {
         let provider = self.account.try_provider()?;
 
@@ -250,6 +320,26 @@ where
     }
 }
 
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<A, C, T> Dispatchable for CallHandler<A, C, T>
+where
+    A: Account,
+    C: ContractDependencyConfigurator + TransactionTuner + ResponseParser + Send + Sync,
+    T: Tokenizable + Parameterize + Debug + Send + Sync,
+{
+    type Output = CallResponse<T>;
+
+    async fn transaction_builder(&mut self) -> Result<ScriptTransactionBuilder> {
+        Self::transaction_builder(self).await
+    }
+
+    fn into_output(mut self, tx_id: TxId, tx_status: TxStatus) -> Result<Self::Output> {
+        self.cached_tx_id = Some(tx_id);
+
+        self.get_response_from(tx_status)
+    }
+}
+
 impl<A, T> CallHandler<A, ContractCall, T>
 where
     A: Account,
@@ -273,6 +363,9 @@ where
             output_param: T::param_type(),
             is_payable,
             custom_assets: Default::default(),
+            through_proxy: false,
+            writes_storage: false,
+            inputs: vec![],
         };
         CallHandler {
             account,
@@ -286,6 +379,21 @@ where
         }
     }
 
+    /// If enabled, the called contract is treated as an SRC-14 proxy: its current target is
+    /// looked up when the transaction is built and added as an external contract, so that the
+    /// call can be delegated to it.
+    pub fn with_proxy(mut self, through_proxy: bool) -> Self {
+        self.call.through_proxy = through_proxy;
+        self
+    }
+
+    /// Marks the called function as writing to storage. `abigen!` sets this for functions
+    /// declared with `#[storage(write)]`.
+    pub fn with_writes_storage(mut self, writes_storage: bool) -> Self {
+        self.call.writes_storage = writes_storage;
+        self
+    }
+
     /// Adds a custom `asset_id` with its `amount` and an optional `address` to be used for
     /// generating outputs to this contract's call.
     ///
@@ -293,7 +401,7 @@ where
     /// - `asset_id`: The unique identifier of the asset being added.
     /// - `amount`: The amount of the asset being added.
     /// - `address`: The optional account address that the output amount will be sent to.
-    ///              If not provided, the asset will be sent to the users account address.
+    ///   If not provided, the asset will be sent to the users account address.
     ///
     /// Note that this is a builder method, i.e. use it as a chain:
     ///
@@ -421,6 +529,15 @@ where
     }
 
     /// Call contract methods on the node, in a state-modifying manner.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(calls = self.call.len(), tx_id = tracing::field::Empty),
+            err
+        )
+    )]
     pub async fn call<T: Tokenizable + Debug>(mut self) -> Result<CallResponse<T>> {
         let tx = self.build_tx().await?;
 
@@ -428,12 +545,24 @@ where
 
         self.cached_tx_id = Some(tx.id(provider.chain_id()));
 
+        #[cfg(feature = "tracing")]
+        record_tx_id(self.cached_tx_id);
+
         let tx_status = provider.send_transaction_and_await_commit(tx).await?;
 
         let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
         self.get_response(receipts)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(calls = self.call.len(), tx_id = tracing::field::Empty),
+            err
+        )
+    )]
     pub async fn submit(mut self) -> Result<SubmitResponse<A, Vec<ContractCall>, ()>> {
         let tx = self.build_tx().await?;
         let provider = self.account.try_provider()?;
@@ -441,6 +570,9 @@ where
         let tx_id = provider.send_transaction(tx).await?;
         self.cached_tx_id = Some(tx_id);
 
+        #[cfg(feature = "tracing")]
+        record_tx_id(self.cached_tx_id);
+
         Ok(SubmitResponse::<A, Vec<ContractCall>, ()>::new(tx_id, self))
     }
 
@@ -449,6 +581,10 @@ where
     /// It is the same as the [call] method because the API is more user-friendly this way.
     ///
     /// [call]: Self::call
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(calls = self.call.len()), err)
+    )]
     pub async fn simulate<T: Tokenizable + Debug>(
         &mut self,
         execution: Execution,
@@ -473,6 +609,31 @@ where
         self.get_response(receipts)
     }
 
+    /// Runs the calls against the current state of the chain without committing anything. No
+    /// funds are needed to cover the fee. Equivalent to `simulate(Execution::StateReadOnly)`.
+    ///
+    /// The state changes of calls to functions writing to storage are discarded. With the
+    /// `tracing` feature such calls are reported with a warning, [`Self::view_strict`] fails on
+    /// them instead.
+    pub async fn view<T: Tokenizable + Debug>(&mut self) -> Result<CallResponse<T>> {
+        #[cfg(feature = "tracing")]
+        for call in self.call.iter().filter(|call| call.writes_storage) {
+            tracing::warn!(
+                contract_id = %call.contract_id,
+                "viewing a call to a function that writes to storage, its changes are discarded"
+            );
+        }
+
+        self.simulate(Execution::StateReadOnly).await
+    }
+
+    /// Like [`Self::view`], but fails if any of the called functions writes to storage.
+    pub async fn view_strict<T: Tokenizable + Debug>(&mut self) -> Result<CallResponse<T>> {
+        self.call.iter().try_for_each(check_viewable)?;
+
+        self.simulate(Execution::StateReadOnly).await
+    }
+
     /// Simulates a call without needing to resolve the generic for the return type
     async fn simulate_without_decode(&self) -> Result<()> {
         let provider = self.account.try_provider()?;
@@ -529,3 +690,22 @@ where
         self.simulate_without_decode().await.map(|_| self)
     }
 }
+
+#[cfg(feature = "tracing")]
+fn record_tx_id(tx_id: Option<Bytes32>) {
+    if let Some(tx_id) = tx_id {
+        tracing::Span::current().record("tx_id", tracing::field::display(tx_id));
+    }
+}
+
+fn check_viewable(call: &ContractCall) -> Result<()> {
+    if call.writes_storage {
+        return Err(error!(
+            Other,
+            "cannot view a function that writes to storage of contract `{}`, use `call` instead",
+            call.contract_id
+        ));
+    }
+
+    Ok(())
+}
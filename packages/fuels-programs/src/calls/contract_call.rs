@@ -6,6 +6,7 @@ use fuels_core::{
     types::{
         bech32::{Bech32Address, Bech32ContractId},
         errors::Result,
+        input::Input,
         param_types::ParamType,
         Selector,
     },
@@ -24,6 +25,14 @@ pub struct ContractCall {
     pub output_param: ParamType,
     pub is_payable: bool,
     pub custom_assets: HashMap<(AssetId, Option<Bech32Address>), u64>,
+    /// `contract_id` is an SRC-14 proxy whose current target must be included as an external
+    /// contract.
+    pub through_proxy: bool,
+    /// The called function is declared with `#[storage(write)]`.
+    pub writes_storage: bool,
+    /// Additional inputs spent by the transaction, e.g. messages whose data the called contract
+    /// processes.
+    pub inputs: Vec<Input>,
 }
 
 impl ContractCall {
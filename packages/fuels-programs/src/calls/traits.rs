@@ -1,7 +1,9 @@
 mod contract_dep_configurator;
+mod input_configurator;
 mod response_parser;
 mod transaction_tuner;
 
 pub use contract_dep_configurator::*;
+pub use input_configurator::*;
 pub use response_parser::*;
 pub use transaction_tuner::*;
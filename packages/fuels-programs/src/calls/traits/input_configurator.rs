@@ -0,0 +1,19 @@
+use fuels_core::types::input::Input;
+
+use crate::calls::{utils::sealed, ContractCall, ScriptCall};
+
+pub trait InputConfigurator: sealed::Sealed {
+    fn append_inputs(&mut self, inputs: Vec<Input>);
+}
+
+impl InputConfigurator for ContractCall {
+    fn append_inputs(&mut self, inputs: Vec<Input>) {
+        self.inputs.extend(inputs)
+    }
+}
+
+impl InputConfigurator for ScriptCall {
+    fn append_inputs(&mut self, inputs: Vec<Input>) {
+        self.inputs.extend(inputs)
+    }
+}
@@ -1,4 +1,8 @@
-use std::{collections::HashSet, iter, vec};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    iter, vec,
+};
 
 use fuel_abi_types::error_codes::FAILED_TRANSFER_TO_ADDRESS_SIGNAL;
 use fuel_asm::{op, RegId};
@@ -23,7 +27,7 @@ use fuels_core::{
 };
 use itertools::{chain, Itertools};
 
-use crate::calls::ContractCall;
+use crate::{calls::ContractCall, contract::query_proxy_target};
 
 #[derive(Default)]
 /// Specifies offsets of [`Opcode::CALL`][`fuel_asm::Opcode::CALL`] parameters stored in the script
@@ -45,6 +49,53 @@ pub(crate) async fn transaction_builder_from_contract_calls(
     tx_policies: TxPolicies,
     variable_outputs: VariableOutputPolicy,
     account: &impl Account,
+) -> Result<ScriptTransactionBuilder> {
+    let calls = resolve_proxy_targets(calls, account).await?;
+
+    transaction_builder_from_resolved_calls(&calls, tx_policies, variable_outputs, account).await
+}
+
+/// Adds the current target of every call made through an SRC-14 proxy to the call's external
+/// contracts.
+async fn resolve_proxy_targets<'a>(
+    calls: &'a [ContractCall],
+    account: &impl Account,
+) -> Result<Cow<'a, [ContractCall]>> {
+    if !calls.iter().any(|call| call.through_proxy) {
+        return Ok(Cow::Borrowed(calls));
+    }
+
+    let mut targets: HashMap<Bech32ContractId, Bech32ContractId> = HashMap::new();
+    let mut resolved = calls.to_vec();
+    for call in resolved.iter_mut().filter(|call| call.through_proxy) {
+        let target = match targets.get(&call.contract_id) {
+            Some(target) => target.clone(),
+            None => {
+                let target = query_proxy_target(&call.contract_id, account)
+                    .await?
+                    .ok_or_else(|| {
+                        error!(Other, "proxy `{}` has no target set", call.contract_id)
+                    })?;
+                targets.insert(call.contract_id.clone(), target.clone());
+                target
+            }
+        };
+
+        if !call.external_contracts.contains(&target) {
+            call.external_contracts.push(target);
+        }
+    }
+
+    Ok(Cow::Owned(resolved))
+}
+
+/// Creates a [`ScriptTransactionBuilder`] from contract calls whose proxies, if any, have already
+/// been resolved.
+pub(crate) async fn transaction_builder_from_resolved_calls(
+    calls: &[ContractCall],
+    tx_policies: TxPolicies,
+    variable_outputs: VariableOutputPolicy,
+    account: &impl Account,
 ) -> Result<ScriptTransactionBuilder> {
     let calls_instructions_len = compute_calls_instructions_len(calls)?;
     let provider = account.try_provider()?;
@@ -65,6 +116,7 @@ pub(crate) async fn transaction_builder_from_contract_calls(
             .await?;
         asset_inputs.extend(resources);
     }
+    asset_inputs.extend(calls.iter().flat_map(|call| call.inputs.clone()).unique());
 
     let (inputs, outputs) = get_transaction_inputs_outputs(
         calls,
@@ -469,6 +521,9 @@ mod test {
             output_param: ParamType::Unit,
             is_payable: false,
             custom_assets: Default::default(),
+            through_proxy: false,
+            writes_storage: false,
+            inputs: Default::default(),
         }
     }
 
@@ -690,6 +745,36 @@ mod test {
         )
     }
 
+    #[tokio::test]
+    async fn calls_not_through_a_proxy_are_left_untouched() -> Result<()> {
+        // given
+        let calls = [new_contract_call_with_random_id()];
+        let wallet = WalletUnlocked::new_random(None);
+
+        // when
+        let resolved = resolve_proxy_targets(&calls, &wallet).await?;
+
+        // then
+        assert!(matches!(resolved, Cow::Borrowed(_)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resolving_proxy_target_requires_provider() {
+        // given
+        let mut call = new_contract_call_with_random_id();
+        call.through_proxy = true;
+        let calls = [call];
+        let wallet = WalletUnlocked::new_random(None);
+
+        // when
+        let result = resolve_proxy_targets(&calls, &wallet).await;
+
+        // then
+        assert!(result.is_err());
+    }
+
     mod compute_calls_instructions_len {
         use fuel_asm::Instruction;
         use fuels_core::types::param_types::{EnumVariants, ParamType};
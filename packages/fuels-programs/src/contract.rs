@@ -43,6 +43,19 @@ pub use regular::*;
 mod loader;
 pub use loader::*;
 
+mod deployment;
+pub use deployment::*;
+
+mod verification;
+pub use verification::*;
+
+mod proxy;
+pub(crate) use proxy::query_proxy_target;
+pub use proxy::Src14Proxy;
+
+mod src20;
+pub use src20::*;
+
 fn compute_contract_id_and_state_root(
     binary: &[u8],
     salt: &Salt,
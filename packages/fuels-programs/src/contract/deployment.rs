@@ -0,0 +1,881 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    path::{Path, PathBuf},
+};
+
+use fuel_abi_types::abi::unified_program::UnifiedProgramABI;
+use fuel_tx::{ContractId, Salt};
+use fuels_accounts::Account;
+use fuels_core::{
+    codec::{ABIEncoder, EncoderConfig},
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Error, Result},
+        param_types::ParamType,
+        transaction::TxPolicies,
+        StaticStringToken, Token, U256,
+    },
+    Configurables,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{BlobsNotUploaded, Contract, LoadConfiguration, Loader, Regular, StorageConfiguration};
+
+/// A declarative description of a set of contracts to deploy.
+///
+/// Configurable values are given as plain TOML/JSON values and are encoded according to the
+/// type found in the contract's ABI. Any value of the form `{ "$contract" = "<name>" }` is
+/// replaced with the id of the manifest entry called `<name>`, which is then deployed first. Sway
+/// identifiers cannot contain `$`, so the marker never clashes with a struct field or an enum
+/// variant.
+///
+/// ```toml
+/// [contracts.registry]
+/// binary = "out/registry.bin"
+///
+/// [contracts.token]
+/// binary = "out/token.bin"
+/// abi = "out/token-abi.json"
+/// salt = "0x0000000000000000000000000000000000000000000000000000000000000001"
+/// mode = { loader = { max_words_per_blob = 10000 } }
+///
+/// [contracts.token.configurables]
+/// DECIMALS = 9
+/// REGISTRY = { "$contract" = "registry" }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeploymentManifest {
+    pub contracts: BTreeMap<String, ContractManifest>,
+    /// Relative paths of the entries are resolved against this directory.
+    #[serde(skip)]
+    base_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractManifest {
+    pub binary: PathBuf,
+    /// Required if any configurables are set.
+    #[serde(default)]
+    pub abi: Option<PathBuf>,
+    /// If not given, the storage slots are autoloaded from next to the binary.
+    #[serde(default)]
+    pub storage_slots: Option<PathBuf>,
+    #[serde(default)]
+    pub salt: Salt,
+    #[serde(default)]
+    pub configurables: BTreeMap<String, Value>,
+    #[serde(default)]
+    pub mode: DeploymentMode,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentMode {
+    #[default]
+    Regular,
+    Loader {
+        max_words_per_blob: usize,
+    },
+}
+
+impl DeploymentManifest {
+    /// Loads a manifest from a `.toml` or `.json` file. Relative paths inside the manifest are
+    /// resolved against the directory containing it.
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| error!(IO, "failed to read deployment manifest {path:?}: {e}"))?;
+
+        let manifest = match file_format(path)? {
+            FileFormat::Toml => Self::from_toml_str(&contents)?,
+            FileFormat::Json => Self::from_json_str(&contents)?,
+        };
+
+        let base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        Ok(manifest.with_base_dir(base_dir))
+    }
+
+    pub fn from_toml_str(contents: &str) -> Result<Self> {
+        toml::from_str(contents)
+            .map_err(|e| error!(Other, "failed to parse deployment manifest: {e}"))
+    }
+
+    pub fn from_json_str(contents: &str) -> Result<Self> {
+        serde_json::from_str(contents)
+            .map_err(|e| error!(Other, "failed to parse deployment manifest: {e}"))
+    }
+
+    pub fn with_base_dir(mut self, base_dir: impl Into<PathBuf>) -> Self {
+        self.base_dir = base_dir.into();
+        self
+    }
+
+    /// Names of the contracts in the order they need to be deployed so that every contract is
+    /// deployed after the contracts its configurables reference.
+    pub fn deployment_order(&self) -> Result<Vec<String>> {
+        let mut dependencies = BTreeMap::new();
+        for (name, contract) in &self.contracts {
+            let mut references = BTreeSet::new();
+            for value in contract.configurables.values() {
+                collect_references(value, &mut references);
+            }
+
+            if let Some(unknown) = references
+                .iter()
+                .find(|reference| !self.contracts.contains_key(*reference))
+            {
+                return Err(error!(
+                    Other,
+                    "contract `{name}` references unknown contract `{unknown}`"
+                ));
+            }
+
+            dependencies.insert(name.as_str(), references);
+        }
+
+        let mut order = vec![];
+        while !dependencies.is_empty() {
+            let ready: Vec<_> = dependencies
+                .iter()
+                .filter(|(_, references)| references.is_empty())
+                .map(|(name, _)| *name)
+                .collect();
+
+            if ready.is_empty() {
+                let cycle = dependencies.keys().copied().collect::<Vec<_>>().join(", ");
+                return Err(error!(Other, "contracts have circular references: {cycle}"));
+            }
+
+            for name in ready {
+                dependencies.remove(name);
+                for references in dependencies.values_mut() {
+                    references.remove(name);
+                }
+                order.push(name.to_string());
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Loads all binaries, encodes the configurables and computes the resulting contract ids
+    /// without touching the network.
+    pub fn plan(&self) -> Result<DeploymentPlan> {
+        let mut contract_ids = HashMap::new();
+        let mut contracts = vec![];
+
+        for name in self.deployment_order()? {
+            let manifest = &self.contracts[&name];
+            let contract = self
+                .load_contract(manifest, &contract_ids)
+                .map_err(|e| with_context(e, format!("contract `{name}`")))?;
+
+            contract_ids.insert(name.clone(), contract.contract_id());
+            contracts.push((name, contract));
+        }
+
+        Ok(DeploymentPlan {
+            contracts,
+            lockfile: None,
+        })
+    }
+
+    fn load_contract(
+        &self,
+        manifest: &ContractManifest,
+        contract_ids: &HashMap<String, ContractId>,
+    ) -> Result<PlannedContract> {
+        let storage = match &manifest.storage_slots {
+            Some(path) => StorageConfiguration::default()
+                .with_autoload(false)
+                .add_slot_overrides_from_file(self.resolve(path))?,
+            None => StorageConfiguration::default(),
+        };
+
+        let configurables = self.encode_configurables(manifest, contract_ids)?;
+
+        let config = LoadConfiguration::default()
+            .with_storage_configuration(storage)
+            .with_salt(manifest.salt)
+            .with_configurables(configurables);
+
+        let contract = Contract::load_from(self.resolve(&manifest.binary), config)?;
+
+        Ok(match manifest.mode {
+            DeploymentMode::Regular => PlannedContract::Regular(contract),
+            DeploymentMode::Loader { max_words_per_blob } => {
+                PlannedContract::Loader(contract.convert_to_loader(max_words_per_blob)?)
+            }
+        })
+    }
+
+    fn encode_configurables(
+        &self,
+        manifest: &ContractManifest,
+        contract_ids: &HashMap<String, ContractId>,
+    ) -> Result<Configurables> {
+        if manifest.configurables.is_empty() {
+            return Ok(Configurables::default());
+        }
+
+        let abi_path = manifest
+            .abi
+            .as_ref()
+            .ok_or_else(|| error!(Other, "an `abi` is required to set configurables"))?;
+        let abi_path = self.resolve(abi_path);
+        let abi = std::fs::read_to_string(&abi_path)
+            .map_err(|e| error!(IO, "failed to read abi {abi_path:?}: {e}"))?;
+        let abi = UnifiedProgramABI::from_json_abi(&abi)
+            .map_err(|e| error!(Other, "failed to parse abi {abi_path:?}: {e}"))?;
+
+        let type_lookup = abi
+            .types
+            .iter()
+            .map(|decl| (decl.type_id, decl.clone()))
+            .collect();
+        let abi_configurables = abi.configurables.unwrap_or_default();
+
+        let encoder = ABIEncoder::new(EncoderConfig::default());
+        let offsets_with_data = manifest
+            .configurables
+            .iter()
+            .map(|(name, value)| {
+                let configurable = abi_configurables
+                    .iter()
+                    .find(|configurable| &configurable.name == name)
+                    .ok_or_else(|| error!(Other, "abi has no configurable named `{name}`"))?;
+
+                let param_type =
+                    ParamType::try_from_type_application(&configurable.application, &type_lookup)?;
+                let token = tokenize(value, &param_type, contract_ids)
+                    .map_err(|e| error!(Other, "configurable `{name}`: {e}"))?;
+
+                Ok((configurable.offset, encoder.encode(&[token])?))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Configurables::new(offsets_with_data))
+    }
+
+    fn resolve(&self, path: &Path) -> PathBuf {
+        self.base_dir.join(path)
+    }
+}
+
+const REFERENCE_KEY: &str = "$contract";
+
+fn reference(value: &Value) -> Option<&str> {
+    match value {
+        Value::Object(map) if map.len() == 1 => map.get(REFERENCE_KEY).and_then(Value::as_str),
+        _ => None,
+    }
+}
+
+/// Prefixes the message of `err` with `context`, keeping the kind of the error.
+fn with_context(err: Error, context: String) -> Error {
+    match err {
+        Error::IO(msg) => Error::IO(format!("{context}: {msg}")),
+        Error::Codec(msg) => Error::Codec(format!("{context}: {msg}")),
+        Error::Provider(msg) => Error::Provider(format!("{context}: {msg}")),
+        Error::Other(msg) => Error::Other(format!("{context}: {msg}")),
+        Error::Transaction(_) => err,
+    }
+}
+
+fn collect_references(value: &Value, references: &mut BTreeSet<String>) {
+    if let Some(name) = reference(value) {
+        references.insert(name.to_string());
+        return;
+    }
+
+    match value {
+        Value::Array(values) => values
+            .iter()
+            .for_each(|value| collect_references(value, references)),
+        Value::Object(map) => map
+            .values()
+            .for_each(|value| collect_references(value, references)),
+        _ => {}
+    }
+}
+
+/// Converts a manifest value into a token of the given type.
+fn tokenize(
+    value: &Value,
+    param_type: &ParamType,
+    contract_ids: &HashMap<String, ContractId>,
+) -> Result<Token> {
+    let mismatch = || {
+        error!(
+            Other,
+            "expected a value of type `{param_type:?}`, got `{value}`"
+        )
+    };
+
+    if let Some(name) = reference(value) {
+        let id = contract_ids
+            .get(name)
+            .ok_or_else(|| error!(Other, "contract `{name}` has not been planned yet"))?;
+
+        return match param_type {
+            ParamType::B256 => Ok(Token::B256(**id)),
+            ParamType::Struct { fields, .. } if is_b256_wrapper(fields) => {
+                Ok(Token::Struct(vec![Token::B256(**id)]))
+            }
+            _ => Err(mismatch()),
+        };
+    }
+
+    let as_u64 = || {
+        value
+            .as_u64()
+            .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+            .ok_or_else(mismatch)
+    };
+    let narrow = |max: u64| {
+        let value = as_u64()?;
+        (value <= max).then_some(value).ok_or_else(mismatch)
+    };
+
+    let token = match param_type {
+        ParamType::Unit => Token::Unit,
+        ParamType::Bool => Token::Bool(value.as_bool().ok_or_else(mismatch)?),
+        ParamType::U8 => Token::U8(narrow(u8::MAX.into())? as u8),
+        ParamType::U16 => Token::U16(narrow(u16::MAX.into())? as u16),
+        ParamType::U32 => Token::U32(narrow(u32::MAX.into())? as u32),
+        ParamType::U64 => Token::U64(as_u64()?),
+        ParamType::U128 => Token::U128(match value {
+            Value::String(s) => s.parse().map_err(|_| mismatch())?,
+            _ => as_u64()?.into(),
+        }),
+        ParamType::U256 => Token::U256(match value {
+            Value::String(s) => U256::from_dec_str(s).map_err(|_| mismatch())?,
+            _ => as_u64()?.into(),
+        }),
+        ParamType::B256 => Token::B256(parse_b256(value).ok_or_else(mismatch)?),
+        ParamType::StringArray(len) => Token::StringArray(StaticStringToken::new(
+            value.as_str().ok_or_else(mismatch)?.to_string(),
+            Some(*len),
+        )),
+        ParamType::Struct { fields, .. } => match value {
+            Value::String(_) if is_b256_wrapper(fields) => {
+                Token::Struct(vec![Token::B256(parse_b256(value).ok_or_else(mismatch)?)])
+            }
+            Value::Object(map) => Token::Struct(
+                fields
+                    .iter()
+                    .map(|(field, field_type)| {
+                        let value = map
+                            .get(field)
+                            .ok_or_else(|| error!(Other, "missing struct field `{field}`"))?;
+                        tokenize(value, field_type, contract_ids)
+                    })
+                    .collect::<Result<_>>()?,
+            ),
+            _ => return Err(mismatch()),
+        },
+        ParamType::Enum { enum_variants, .. } => {
+            let (variant, value) = match value {
+                Value::String(variant) => (variant.as_str(), &Value::Null),
+                Value::Object(map) if map.len() == 1 => {
+                    let (variant, value) = map.iter().next().expect("has one entry");
+                    (variant.as_str(), value)
+                }
+                _ => return Err(mismatch()),
+            };
+
+            let (discriminant, (_, variant_type)) = enum_variants
+                .variants()
+                .iter()
+                .enumerate()
+                .find(|(_, (name, _))| name == variant)
+                .ok_or_else(|| error!(Other, "unknown enum variant `{variant}`"))?;
+
+            let token = if *variant_type == ParamType::Unit {
+                Token::Unit
+            } else {
+                tokenize(value, variant_type, contract_ids)?
+            };
+
+            Token::Enum(Box::new((
+                discriminant as u64,
+                token,
+                enum_variants.clone(),
+            )))
+        }
+        ParamType::Tuple(types) => {
+            let values = value.as_array().ok_or_else(mismatch)?;
+            if values.len() != types.len() {
+                return Err(mismatch());
+            }
+
+            Token::Tuple(
+                values
+                    .iter()
+                    .zip(types)
+                    .map(|(value, param_type)| tokenize(value, param_type, contract_ids))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        ParamType::Array(element_type, len) => {
+            let values = value.as_array().ok_or_else(mismatch)?;
+            if values.len() != *len {
+                return Err(mismatch());
+            }
+
+            Token::Array(
+                values
+                    .iter()
+                    .map(|value| tokenize(value, element_type, contract_ids))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        _ => {
+            return Err(error!(
+                Other,
+                "configurables of type `{param_type:?}` are not supported in deployment manifests"
+            ))
+        }
+    };
+
+    Ok(token)
+}
+
+/// `ContractId`, `Address`, `AssetId` and similar structs wrapping a single `b256`.
+fn is_b256_wrapper(fields: &[(String, ParamType)]) -> bool {
+    matches!(fields, [(_, ParamType::B256)])
+}
+
+fn parse_b256(value: &Value) -> Option<[u8; 32]> {
+    let hex = value.as_str()?;
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+
+    let mut bytes = [0; 32];
+    (hex.len() == 64).then_some(())?;
+    for (byte, chunk) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+    }
+
+    Some(bytes)
+}
+
+#[derive(Debug, Clone)]
+pub enum PlannedContract {
+    Regular(Contract<Regular>),
+    Loader(Contract<Loader<BlobsNotUploaded>>),
+}
+
+impl PlannedContract {
+    pub fn contract_id(&self) -> ContractId {
+        match self {
+            Self::Regular(contract) => contract.contract_id(),
+            Self::Loader(contract) => contract.contract_id(),
+        }
+    }
+
+    async fn deploy_if_not_exists(
+        self,
+        account: &impl Account,
+        tx_policies: TxPolicies,
+    ) -> Result<Bech32ContractId> {
+        match self {
+            Self::Regular(contract) => contract.deploy_if_not_exists(account, tx_policies).await,
+            Self::Loader(contract) => contract.deploy_if_not_exists(account, tx_policies).await,
+        }
+    }
+}
+
+/// Contracts of a [`DeploymentManifest`] with their code and ids fully resolved, in deployment
+/// order.
+#[derive(Debug, Clone)]
+pub struct DeploymentPlan {
+    contracts: Vec<(String, PlannedContract)>,
+    lockfile: Option<PathBuf>,
+}
+
+impl DeploymentPlan {
+    pub fn contracts(&self) -> &[(String, PlannedContract)] {
+        &self.contracts
+    }
+
+    /// The lockfile this plan will produce once deployed.
+    pub fn lock(&self) -> DeploymentLock {
+        let contracts = self
+            .contracts
+            .iter()
+            .map(|(name, contract)| {
+                let locked = LockedContract {
+                    contract_id: contract.contract_id(),
+                };
+                (name.clone(), locked)
+            })
+            .collect();
+
+        DeploymentLock { contracts }
+    }
+
+    /// Makes [`deploy`](Self::deploy) save the lockfile to `path` after every successful
+    /// deployment, so that the ids of the contracts deployed so far are recorded even if a later
+    /// deployment fails.
+    pub fn with_lockfile(mut self, path: impl Into<PathBuf>) -> Self {
+        self.lockfile = Some(path.into());
+        self
+    }
+
+    /// Deploys all contracts in order. Contracts that already exist on chain are skipped, so an
+    /// interrupted deployment can simply be run again. Returns the lock of the deployed contracts.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "info", skip_all, fields(contracts = self.contracts.len()), err)
+    )]
+    pub async fn deploy(
+        self,
+        account: &impl Account,
+        tx_policies: TxPolicies,
+    ) -> Result<DeploymentLock> {
+        let Self {
+            contracts,
+            lockfile,
+        } = self;
+
+        let mut lock = DeploymentLock::default();
+        for (name, contract) in contracts {
+            let contract_id = contract.contract_id();
+            contract
+                .deploy_if_not_exists(account, tx_policies)
+                .await
+                .map_err(|e| with_context(e, format!("failed to deploy contract `{name}`")))?;
+
+            lock.contracts.insert(name, LockedContract { contract_id });
+            if let Some(path) = &lockfile {
+                lock.save(path)?;
+            }
+        }
+
+        Ok(lock)
+    }
+}
+
+/// The resulting ids of a deployed [`DeploymentPlan`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeploymentLock {
+    pub contracts: BTreeMap<String, LockedContract>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedContract {
+    pub contract_id: ContractId,
+}
+
+impl DeploymentLock {
+    pub fn contract_id(&self, name: &str) -> Option<Bech32ContractId> {
+        self.contracts
+            .get(name)
+            .map(|locked| locked.contract_id.into())
+    }
+
+    /// Loads a lockfile from a `.toml` or `.json` file.
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| error!(IO, "failed to read deployment lock {path:?}: {e}"))?;
+
+        match file_format(path)? {
+            FileFormat::Toml => toml::from_str(&contents)
+                .map_err(|e| error!(Other, "failed to parse deployment lock: {e}")),
+            FileFormat::Json => serde_json::from_str(&contents)
+                .map_err(|e| error!(Other, "failed to parse deployment lock: {e}")),
+        }
+    }
+
+    /// Writes the lockfile as TOML or JSON depending on the extension of `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let contents = match file_format(path)? {
+            FileFormat::Toml => toml::to_string_pretty(self)
+                .map_err(|e| error!(Other, "failed to serialize deployment lock: {e}"))?,
+            FileFormat::Json => serde_json::to_string_pretty(self)?,
+        };
+
+        std::fs::write(path, contents)
+            .map_err(|e| error!(IO, "failed to write deployment lock {path:?}: {e}"))
+    }
+}
+
+enum FileFormat {
+    Toml,
+    Json,
+}
+
+fn file_format(path: &Path) -> Result<FileFormat> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => Ok(FileFormat::Toml),
+        Some("json") => Ok(FileFormat::Json),
+        _ => Err(error!(
+            Other,
+            "{path:?} must have a `.toml` or `.json` extension"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    const ABI: &str = r#"{
+        "programType": "contract",
+        "specVersion": "1",
+        "encodingVersion": "1",
+        "concreteTypes": [
+            { "type": "u64", "concreteTypeId": "01" },
+            { "type": "struct std::contract_id::ContractId", "concreteTypeId": "02", "metadataTypeId": 0 }
+        ],
+        "metadataTypes": [
+            { "type": "struct std::contract_id::ContractId", "metadataTypeId": 0, "components": [ { "name": "bits", "typeId": 1 } ] },
+            { "type": "b256", "metadataTypeId": 1 }
+        ],
+        "functions": [],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": [
+            { "name": "REGISTRY", "concreteTypeId": "02", "offset": 8 },
+            { "name": "DECIMALS", "concreteTypeId": "01", "offset": 40 }
+        ]
+    }"#;
+
+    fn manifest(toml: &str) -> DeploymentManifest {
+        DeploymentManifest::from_toml_str(toml).unwrap()
+    }
+
+    #[test]
+    fn referenced_contracts_are_deployed_first() -> Result<()> {
+        // given
+        let manifest = manifest(
+            r#"
+            [contracts.a]
+            binary = "a.bin"
+            configurables = { X = { "$contract" = "c" } }
+
+            [contracts.b]
+            binary = "b.bin"
+            configurables = { Y = [{ "$contract" = "a" }, { "$contract" = "c" }] }
+
+            [contracts.c]
+            binary = "c.bin"
+            "#,
+        );
+
+        // when
+        let order = manifest.deployment_order()?;
+
+        // then
+        assert_eq!(order, ["c", "a", "b"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn circular_references_are_rejected() {
+        // given
+        let manifest = manifest(
+            r#"
+            [contracts.a]
+            binary = "a.bin"
+            configurables = { X = { "$contract" = "b" } }
+
+            [contracts.b]
+            binary = "b.bin"
+            configurables = { X = { "$contract" = "a" } }
+            "#,
+        );
+
+        // when
+        let err = manifest
+            .deployment_order()
+            .expect_err("should have failed because of the cycle");
+
+        // then
+        assert_eq!(err.to_string(), "contracts have circular references: a, b");
+    }
+
+    #[test]
+    fn unknown_references_are_rejected() {
+        // given
+        let manifest = manifest(
+            r#"
+            [contracts.a]
+            binary = "a.bin"
+            configurables = { X = { "$contract" = "missing" } }
+            "#,
+        );
+
+        // when
+        let err = manifest
+            .deployment_order()
+            .expect_err("should have failed because of the unknown reference");
+
+        // then
+        assert_eq!(
+            err.to_string(),
+            "contract `a` references unknown contract `missing`"
+        );
+    }
+
+    #[test]
+    fn struct_fields_named_contract_are_not_references() -> Result<()> {
+        // given
+        let manifest = manifest(
+            r#"
+            [contracts.a]
+            binary = "a.bin"
+            configurables = { X = { contract = "b" } }
+
+            [contracts.b]
+            binary = "b.bin"
+            configurables = { X = { "$contract" = "a" } }
+            "#,
+        );
+
+        // when
+        let order = manifest.deployment_order()?;
+
+        // then
+        assert_eq!(order, ["a", "b"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn planning_errors_keep_their_kind() {
+        // given
+        let manifest = manifest(
+            r#"
+            [contracts.a]
+            binary = "missing.bin"
+            "#,
+        );
+
+        // when
+        let err = manifest
+            .plan()
+            .expect_err("should have failed because the binary is missing");
+
+        // then
+        let Error::IO(msg) = err else {
+            panic!("expected an io error, got: {err:?}");
+        };
+        assert!(msg.starts_with("contract `a`: "));
+    }
+
+    #[test]
+    fn configurables_are_encoded_with_resolved_contract_ids() -> Result<()> {
+        // given
+        let dir = tempdir()?;
+        std::fs::write(dir.path().join("registry.bin"), [1; 16])?;
+        std::fs::write(dir.path().join("token.bin"), [0; 48])?;
+        std::fs::write(dir.path().join("token-abi.json"), ABI)?;
+        let manifest_path = dir.path().join("deployment.toml");
+        std::fs::write(
+            &manifest_path,
+            r#"
+            [contracts.registry]
+            binary = "registry.bin"
+            storage_slots = "registry-storage.json"
+
+            [contracts.token]
+            binary = "token.bin"
+            abi = "token-abi.json"
+            storage_slots = "token-storage.json"
+            mode = { loader = { max_words_per_blob = 2 } }
+
+            [contracts.token.configurables]
+            REGISTRY = { "$contract" = "registry" }
+            DECIMALS = 9
+            "#,
+        )?;
+        for storage in ["registry-storage.json", "token-storage.json"] {
+            std::fs::write(dir.path().join(storage), "[]")?;
+        }
+
+        // when
+        let plan = DeploymentManifest::load_from(&manifest_path)?.plan()?;
+
+        // then
+        let [(registry_name, PlannedContract::Regular(registry)), (token_name, PlannedContract::Loader(token))] =
+            plan.contracts()
+        else {
+            panic!("unexpected plan: {plan:?}");
+        };
+        assert_eq!(
+            (registry_name.as_str(), token_name.as_str()),
+            ("registry", "token")
+        );
+
+        let token_code = token.clone().revert_to_regular().code();
+        assert_eq!(token_code[8..40], *registry.contract_id());
+        assert_eq!(token_code[40..48], 9u64.to_be_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn configurables_require_an_abi() -> Result<()> {
+        // given
+        let dir = tempdir()?;
+        std::fs::write(dir.path().join("a.bin"), [0; 8])?;
+        std::fs::write(dir.path().join("a-storage.json"), "[]")?;
+        let manifest = manifest(
+            r#"
+            [contracts.a]
+            binary = "a.bin"
+            storage_slots = "a-storage.json"
+            configurables = { X = 1 }
+            "#,
+        )
+        .with_base_dir(dir.path());
+
+        // when
+        let err = manifest
+            .plan()
+            .expect_err("should have failed because there is no abi");
+
+        // then
+        assert_eq!(
+            err.to_string(),
+            "contract `a`: an `abi` is required to set configurables"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn lock_roundtrips_through_toml_and_json() -> Result<()> {
+        // given
+        let dir = tempdir()?;
+        let lock = DeploymentLock {
+            contracts: BTreeMap::from([(
+                "token".to_string(),
+                LockedContract {
+                    contract_id: ContractId::from([3; 32]),
+                },
+            )]),
+        };
+
+        for file in ["deployment.lock.toml", "deployment.lock.json"] {
+            let path = dir.path().join(file);
+
+            // when
+            lock.save(&path)?;
+            let loaded = DeploymentLock::load_from(&path)?;
+
+            // then
+            assert_eq!(loaded, lock);
+        }
+
+        Ok(())
+    }
+}
@@ -175,6 +175,15 @@ impl Contract<Loader<BlobsNotUploaded>> {
 
     /// Uploads the blobs associated with this contract. Calling `deploy` on the result will only
     /// deploy the loader contract.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "info",
+            skip_all,
+            fields(blobs = self.code.as_blobs.blobs.len()),
+            err
+        )
+    )]
     pub async fn upload_blobs(
         self,
         account: &impl Account,
@@ -192,6 +201,9 @@ impl Contract<Loader<BlobsNotUploaded>> {
                 continue;
             }
 
+            #[cfg(feature = "tracing")]
+            tracing::debug!(blob_id = %Bytes32::from(id), size = blob.len(), "uploading blob");
+
             let mut tb = BlobTransactionBuilder::default()
                 .with_blob(blob)
                 .with_tx_policies(tx_policies)
@@ -215,6 +227,9 @@ impl Contract<Loader<BlobsNotUploaded>> {
                     {
                         return Err(err);
                     }
+
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(blob_id = %Bytes32::from(id), "blob already uploaded");
                 }
             }
 
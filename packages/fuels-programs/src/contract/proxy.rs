@@ -0,0 +1,137 @@
+use fuel_tx::ContractId;
+use fuels_accounts::Account;
+use fuels_core::{
+    codec::{encode_fn_selector, DecoderConfig, EncoderConfig, LogDecoder},
+    traits::{Parameterize, Tokenizable},
+    types::{
+        bech32::Bech32ContractId,
+        errors::Result,
+        transaction::TxPolicies,
+        transaction_builders::{BuildableTransaction, ScriptBuildStrategy, VariableOutputPolicy},
+        Token,
+    },
+};
+
+use crate::{
+    calls::{
+        receipt_parser::ReceiptParser, utils::transaction_builder_from_resolved_calls, CallHandler,
+        CallParameters, ContractCall,
+    },
+    contract::{Contract, Regular},
+};
+
+/// Handle to an SRC-14 proxy contract.
+///
+/// Calls made through the proxy are delegated to its current target. Bindings of the target's ABI
+/// can be pointed at the proxy's id and told to route through it with `with_proxy(true)`; the
+/// target is then looked up when the transaction is built.
+#[derive(Debug, Clone)]
+pub struct Src14Proxy<A> {
+    proxy_id: Bech32ContractId,
+    account: A,
+}
+
+impl<A: Account + Clone> Src14Proxy<A> {
+    pub fn new(proxy_id: impl Into<Bech32ContractId>, account: A) -> Self {
+        Self {
+            proxy_id: proxy_id.into(),
+            account,
+        }
+    }
+
+    pub fn id(&self) -> &Bech32ContractId {
+        &self.proxy_id
+    }
+
+    /// Deploys the `proxy` contract and initializes it. The proxy takes its owner and its first
+    /// target from the initial values set through its configurables.
+    pub async fn deploy(
+        proxy: Contract<Regular>,
+        account: A,
+        tx_policies: TxPolicies,
+    ) -> Result<Self> {
+        let proxy_id = proxy.deploy(&account, tx_policies).await?;
+        let proxy = Self::new(proxy_id, account);
+
+        proxy
+            .initialize()
+            .with_tx_policies(tx_policies)
+            .call()
+            .await?;
+
+        Ok(proxy)
+    }
+
+    /// Returns the contract the proxy currently delegates calls to, if any.
+    pub async fn target(&self) -> Result<Option<Bech32ContractId>> {
+        query_proxy_target(&self.proxy_id, &self.account).await
+    }
+
+    /// Points the proxy to `new_target`. Only the proxy's owner is allowed to do this.
+    pub fn set_target(&self, new_target: &Bech32ContractId) -> CallHandler<A, ContractCall, ()> {
+        self.call(
+            "set_proxy_target",
+            &[ContractId::from(new_target).into_token()],
+        )
+    }
+
+    /// Sets the proxy's owner and target from the initial values given in its configurables.
+    pub fn initialize(&self) -> CallHandler<A, ContractCall, ()> {
+        self.call("initialize_proxy", &[])
+    }
+
+    fn call(&self, fn_name: &str, args: &[Token]) -> CallHandler<A, ContractCall, ()> {
+        CallHandler::new_contract_call(
+            self.proxy_id.clone(),
+            self.account.clone(),
+            encode_fn_selector(fn_name),
+            args,
+            LogDecoder::new(Default::default()),
+            false,
+            EncoderConfig::default(),
+        )
+    }
+}
+
+/// Queries the target of the SRC-14 proxy `proxy_id` without modifying any state.
+pub(crate) async fn query_proxy_target(
+    proxy_id: &Bech32ContractId,
+    account: &impl Account,
+) -> Result<Option<Bech32ContractId>> {
+    let output_param = Option::<ContractId>::param_type();
+    let call = ContractCall {
+        contract_id: proxy_id.clone(),
+        encoded_args: Ok(vec![]),
+        encoded_selector: encode_fn_selector("proxy_target"),
+        call_parameters: CallParameters::default(),
+        external_contracts: vec![],
+        output_param: output_param.clone(),
+        is_payable: false,
+        custom_assets: Default::default(),
+        through_proxy: false,
+        writes_storage: false,
+        inputs: vec![],
+    };
+
+    let provider = account.try_provider()?;
+    let tx = transaction_builder_from_resolved_calls(
+        &[call],
+        TxPolicies::default(),
+        VariableOutputPolicy::default(),
+        account,
+    )
+    .await?
+    .with_build_strategy(ScriptBuildStrategy::StateReadOnly)
+    .build(provider)
+    .await?;
+
+    let receipts = provider
+        .dry_run_opt(tx, false, Some(0))
+        .await?
+        .take_receipts_checked(None)?;
+
+    let token = ReceiptParser::new(&receipts, DecoderConfig::default())
+        .parse_call(proxy_id, &output_param)?;
+
+    Ok(Option::<ContractId>::from_token(token)?.map(Into::into))
+}
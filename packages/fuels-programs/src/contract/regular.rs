@@ -134,6 +134,16 @@ impl Contract<Regular> {
     /// Deploys a compiled contract to a running node.
     /// To deploy a contract, you need an account with enough assets to pay for deployment.
     /// This account will also receive the change.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "deploy_contract",
+            level = "info",
+            skip_all,
+            fields(contract_id = %self.contract_id()),
+            err
+        )
+    )]
     pub async fn deploy(
         self,
         account: &impl Account,
@@ -179,6 +189,9 @@ impl Contract<Regular> {
         let contract_id = Bech32ContractId::from(self.contract_id());
         let provider = account.try_provider()?;
         if provider.contract_exists(&contract_id).await? {
+            #[cfg(feature = "tracing")]
+            tracing::info!(%contract_id, "contract already deployed, skipping");
+
             Ok(contract_id)
         } else {
             self.deploy(account, tx_policies).await
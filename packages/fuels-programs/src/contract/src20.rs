@@ -0,0 +1,261 @@
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+    sync::Arc,
+};
+
+use fuel_tx::AssetId;
+use fuels_accounts::{Account, ViewOnlyAccount};
+use fuels_core::{
+    codec::{encode_fn_selector, EncoderConfig, LogDecoder},
+    traits::{Parameterize, Tokenizable},
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Result},
+        Bits256,
+    },
+};
+use tokio::sync::OnceCell;
+
+use crate::calls::{CallHandler, Execution};
+
+/// Metadata an SRC-20 contract exposes for one of its assets. Fields the contract doesn't know
+/// about the asset are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetMetadata {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+}
+
+/// Handle to a native asset minted by an SRC-20 contract.
+///
+/// The asset is identified by the minting contract and its sub id. Metadata is queried with
+/// read-only calls the first time it is needed and cached afterwards; the total supply is always
+/// queried anew since it changes as the asset is minted and burned.
+#[derive(Debug, Clone)]
+pub struct Src20Asset<A> {
+    contract_id: Bech32ContractId,
+    sub_id: Bits256,
+    asset_id: AssetId,
+    account: A,
+    metadata: Arc<OnceCell<AssetMetadata>>,
+}
+
+impl<A: Account + Clone> Src20Asset<A> {
+    pub fn new(contract_id: impl Into<Bech32ContractId>, sub_id: Bits256, account: A) -> Self {
+        let contract_id = contract_id.into();
+        let asset_id = contract_id.asset_id(&sub_id);
+
+        Self {
+            contract_id,
+            sub_id,
+            asset_id,
+            account,
+            metadata: Default::default(),
+        }
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        &self.contract_id
+    }
+
+    pub fn sub_id(&self) -> Bits256 {
+        self.sub_id
+    }
+
+    pub fn asset_id(&self) -> AssetId {
+        self.asset_id
+    }
+
+    /// Returns the asset's `name`, `symbol` and `decimals`, querying the contract only the
+    /// first time. Concurrent callers wait for the same query instead of each issuing their own.
+    pub async fn metadata(&self) -> Result<AssetMetadata> {
+        self.metadata
+            .get_or_try_init(|| async {
+                Ok(AssetMetadata {
+                    name: self.query("name").await?,
+                    symbol: self.query("symbol").await?,
+                    decimals: self.query("decimals").await?,
+                })
+            })
+            .await
+            .cloned()
+    }
+
+    /// Returns the current total supply of the asset, or `None` if the contract doesn't know
+    /// the asset.
+    pub async fn total_supply(&self) -> Result<Option<u64>> {
+        self.query("total_supply").await
+    }
+
+    /// Returns the amount of the asset owned by `account`.
+    pub async fn balance_of(&self, account: &impl ViewOnlyAccount) -> Result<u64> {
+        account.get_asset_balance(&self.asset_id).await
+    }
+
+    /// Formats a raw `amount` in the asset's decimal units, e.g. `1500000000` with 9 decimals
+    /// becomes `"1.5"`. Assets without decimals are formatted as whole numbers.
+    pub async fn format_amount(&self, amount: u64) -> Result<String> {
+        let decimals = self.metadata().await?.decimals.unwrap_or_default();
+
+        Ok(format_units(amount, decimals))
+    }
+
+    /// Parses an amount given in the asset's decimal units into a raw amount. The inverse of
+    /// [`Src20Asset::format_amount`].
+    pub async fn parse_amount(&self, amount: &str) -> Result<u64> {
+        let decimals = self.metadata().await?.decimals.unwrap_or_default();
+
+        parse_units(amount, decimals)
+    }
+
+    async fn query<T: Tokenizable + Parameterize + Debug>(&self, fn_name: &str) -> Result<T> {
+        let response = CallHandler::new_contract_call(
+            self.contract_id.clone(),
+            self.account.clone(),
+            encode_fn_selector(fn_name),
+            &[self.asset_id.into_token()],
+            LogDecoder::new(Default::default()),
+            false,
+            EncoderConfig::default(),
+        )
+        .simulate(Execution::StateReadOnly)
+        .await?;
+
+        Ok(response.value)
+    }
+}
+
+/// Balance of a single asset, together with its metadata if the asset is a known SRC-20 asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetBalance {
+    pub asset_id: AssetId,
+    pub amount: u64,
+    pub metadata: AssetMetadata,
+}
+
+impl AssetBalance {
+    /// The amount in the asset's decimal units, see [`format_units`].
+    pub fn formatted_amount(&self) -> String {
+        format_units(self.amount, self.metadata.decimals.unwrap_or_default())
+    }
+}
+
+impl Display for AssetBalance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.metadata.symbol {
+            Some(symbol) => write!(f, "{} {symbol}", self.formatted_amount()),
+            None => write!(f, "{} {}", self.formatted_amount(), self.asset_id),
+        }
+    }
+}
+
+/// Lists all balances of `account`, sorted by asset id. Balances of any of the given `assets`
+/// are enriched with the asset's metadata, other balances have empty metadata.
+pub async fn get_balances_with_metadata<A: Account + Clone>(
+    account: &impl ViewOnlyAccount,
+    assets: &[Src20Asset<A>],
+) -> Result<Vec<AssetBalance>> {
+    let mut balances = Vec::new();
+    for (asset_id, amount) in account.get_balances().await? {
+        let asset_id = AssetId::from_str(&asset_id)
+            .map_err(|e| error!(Codec, "could not parse asset id `{asset_id}`: {e}"))?;
+
+        let metadata = match assets.iter().find(|asset| asset.asset_id() == asset_id) {
+            Some(asset) => asset.metadata().await?,
+            None => AssetMetadata::default(),
+        };
+
+        balances.push(AssetBalance {
+            asset_id,
+            amount,
+            metadata,
+        });
+    }
+    balances.sort_by_key(|balance| balance.asset_id);
+
+    Ok(balances)
+}
+
+/// Formats a raw `amount` as a decimal number with `decimals` fractional digits, leaving out
+/// trailing zeros, e.g. `format_units(1_500_000_000, 9)` is `"1.5"`.
+pub fn format_units(amount: u64, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    let digits = format!("{amount:0>width$}", width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+/// Parses a decimal number with at most `decimals` fractional digits into a raw amount, e.g.
+/// `parse_units("1.5", 9)` is `1_500_000_000`.
+pub fn parse_units(amount: &str, decimals: u8) -> Result<u64> {
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+
+    let is_number = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if (integer.is_empty() && fraction.is_empty()) || !is_number(integer) || !is_number(fraction) {
+        return Err(error!(Other, "`{amount}` is not a valid decimal amount"));
+    }
+
+    let decimals = decimals as usize;
+    if fraction.len() > decimals {
+        return Err(error!(
+            Other,
+            "`{amount}` has more than {decimals} fractional digits"
+        ));
+    }
+
+    format!("{integer}{fraction:0<decimals$}")
+        .parse()
+        .map_err(|_| error!(Other, "`{amount}` does not fit into a `u64`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amounts_are_formatted_in_decimal_units() {
+        assert_eq!(format_units(1_500_000_000, 9), "1.5");
+        assert_eq!(format_units(1_000_000_000, 9), "1");
+        assert_eq!(format_units(1, 9), "0.000000001");
+        assert_eq!(format_units(0, 9), "0");
+        assert_eq!(format_units(42, 0), "42");
+        assert_eq!(
+            format_units(u64::MAX, 30),
+            "0.000000000018446744073709551615"
+        );
+    }
+
+    #[test]
+    fn amounts_are_parsed_from_decimal_units() -> Result<()> {
+        assert_eq!(parse_units("1.5", 9)?, 1_500_000_000);
+        assert_eq!(parse_units("1", 9)?, 1_000_000_000);
+        assert_eq!(parse_units(".5", 1)?, 5);
+        assert_eq!(parse_units("0.000000001", 9)?, 1);
+        assert_eq!(parse_units("42", 0)?, 42);
+
+        for amount in [0, 1, 1_500_000_000, u64::MAX] {
+            assert_eq!(parse_units(&format_units(amount, 9), 9)?, amount);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_amounts_are_rejected() {
+        let err = |amount, decimals| parse_units(amount, decimals).unwrap_err().to_string();
+
+        assert!(err("", 9).contains("is not a valid decimal amount"));
+        assert!(err("1.2.3", 9).contains("is not a valid decimal amount"));
+        assert!(err("-1", 9).contains("is not a valid decimal amount"));
+        assert!(err("1.55", 1).contains("has more than 1 fractional digits"));
+        assert!(err("18446744073709551616", 0).contains("does not fit into a `u64`"));
+    }
+}
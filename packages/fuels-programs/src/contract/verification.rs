@@ -0,0 +1,456 @@
+use fuel_tx::{Bytes32, Contract as FuelContract, ContractId, Salt};
+use fuels_accounts::provider::{DeployedContract, Provider};
+use fuels_core::types::{
+    bech32::Bech32ContractId,
+    errors::{error, Result},
+    transaction_builders::BlobId,
+};
+
+use super::{loader_contract_asm, BlobsNotUploaded, BlobsUploaded, Contract, Loader, Regular};
+
+/// A value computed from the local artifacts next to the one found on chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparison<T> {
+    pub expected: T,
+    pub actual: T,
+}
+
+impl<T: PartialEq> Comparison<T> {
+    pub fn matches(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+/// Result of comparing the (effective) contract code byte by byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeComparison {
+    pub expected_len: usize,
+    pub actual_len: usize,
+    /// Offset of the first differing byte, if any.
+    pub first_mismatch: Option<usize>,
+}
+
+impl CodeComparison {
+    fn new(expected: &[u8], actual: &[u8]) -> Self {
+        let first_mismatch = expected
+            .iter()
+            .zip(actual)
+            .position(|(expected, actual)| expected != actual)
+            .or_else(|| (expected.len() != actual.len()).then(|| expected.len().min(actual.len())));
+
+        Self {
+            expected_len: expected.len(),
+            actual_len: actual.len(),
+            first_mismatch,
+        }
+    }
+
+    pub fn matches(&self) -> bool {
+        self.first_mismatch.is_none()
+    }
+}
+
+/// Present if the deployed contract is a loader.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoaderReport {
+    pub blob_ids: Vec<BlobId>,
+    /// Blobs referenced by the loader that the node doesn't know about.
+    pub missing_blobs: Vec<BlobId>,
+}
+
+/// Outcome of [`Contract::verify_deployment`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationReport {
+    /// The id computed locally vs. the id that was verified.
+    pub contract_id: Comparison<ContractId>,
+    pub salt: Comparison<Salt>,
+    /// Root of the deployed bytecode as is, i.e. of the loader if the contract is a loader.
+    pub code_root: Comparison<Bytes32>,
+    /// The locally computed state root. It cannot be read from the node, but the contract id
+    /// commits to it, see [`VerificationReport::state_root_matches`].
+    pub state_root: Bytes32,
+    /// Whether the on-chain contract id can be derived from the deployed bytecode, the deployed
+    /// salt and the local state root, i.e. whether the contract was deployed with the same
+    /// storage slots.
+    pub state_root_matches: bool,
+    /// Comparison of the effective code. For loaders this is the code contained in their blobs.
+    /// `None` if some blobs are missing.
+    pub code: Option<CodeComparison>,
+    pub loader: Option<LoaderReport>,
+}
+
+impl VerificationReport {
+    /// Whether everything matches and, for loaders, all blobs are available on chain.
+    pub fn is_verified(&self) -> bool {
+        self.contract_id.matches()
+            && self.salt.matches()
+            && self.code_root.matches()
+            && self.state_root_matches
+            && self.code.is_some_and(|code| code.matches())
+            && self
+                .loader
+                .iter()
+                .all(|loader| loader.missing_blobs.is_empty())
+    }
+}
+
+struct Expected {
+    contract_id: ContractId,
+    code_root: Bytes32,
+    state_root: Bytes32,
+    salt: Salt,
+    code: ExpectedCode,
+}
+
+enum ExpectedCode {
+    /// The code that actually runs, i.e. the content of the blobs for loaders.
+    Effective(Vec<u8>),
+    /// Only the loader's code is known locally since the blobs were uploaded earlier.
+    LoaderOnly(Vec<u8>),
+}
+
+impl Contract<Regular> {
+    /// Compares the contract deployed at `contract_id` against this contract's code, salt and
+    /// storage slots. If the deployed contract is a loader, its blobs are fetched and their
+    /// content is compared against this contract's code.
+    pub async fn verify_deployment(
+        &self,
+        contract_id: &Bech32ContractId,
+        provider: &Provider,
+    ) -> Result<VerificationReport> {
+        let expected = Expected {
+            contract_id: self.contract_id(),
+            code_root: self.code_root(),
+            state_root: self.state_root(),
+            salt: self.salt,
+            code: ExpectedCode::Effective(self.code()),
+        };
+
+        verify(expected, contract_id, provider).await
+    }
+}
+
+impl Contract<Loader<BlobsNotUploaded>> {
+    /// Same as [`Contract::<Regular>::verify_deployment`], the effective code being the content
+    /// of the blobs.
+    pub async fn verify_deployment(
+        &self,
+        contract_id: &Bech32ContractId,
+        provider: &Provider,
+    ) -> Result<VerificationReport> {
+        let code = self
+            .blobs()
+            .iter()
+            .flat_map(|blob| blob.bytes())
+            .copied()
+            .collect();
+
+        let expected = Expected {
+            contract_id: self.contract_id(),
+            code_root: self.code_root(),
+            state_root: self.state_root(),
+            salt: self.salt,
+            code: ExpectedCode::Effective(code),
+        };
+
+        verify(expected, contract_id, provider).await
+    }
+}
+
+impl Contract<Loader<BlobsUploaded>> {
+    /// Same as [`Contract::<Regular>::verify_deployment`]. Since the blobs aren't known locally,
+    /// only the loader code itself is compared.
+    pub async fn verify_deployment(
+        &self,
+        contract_id: &Bech32ContractId,
+        provider: &Provider,
+    ) -> Result<VerificationReport> {
+        let expected = Expected {
+            contract_id: self.contract_id(),
+            code_root: self.code_root(),
+            state_root: self.state_root(),
+            salt: self.salt,
+            code: ExpectedCode::LoaderOnly(self.code()),
+        };
+
+        verify(expected, contract_id, provider).await
+    }
+}
+
+async fn verify(
+    expected: Expected,
+    contract_id: &Bech32ContractId,
+    provider: &Provider,
+) -> Result<VerificationReport> {
+    let deployed = provider
+        .contract(contract_id)
+        .await?
+        .ok_or_else(|| error!(Other, "no contract deployed at `{contract_id}`"))?;
+
+    let mut blobs = vec![];
+    if let Some(blob_ids) = blob_ids_from_loader_code(&deployed.bytecode) {
+        for id in blob_ids {
+            let blob = provider.blob(id).await?;
+            blobs.push((id, blob.map(Vec::from)));
+        }
+    }
+
+    Ok(build_report(expected, &deployed, blobs))
+}
+
+/// `blobs` holds the ids and, if found on chain, the contents of the blobs referenced by the
+/// deployed loader. Empty if the deployed contract is not a loader.
+fn build_report(
+    expected: Expected,
+    deployed: &DeployedContract,
+    blobs: Vec<(BlobId, Option<Vec<u8>>)>,
+) -> VerificationReport {
+    let deployed_id = ContractId::from(&deployed.contract_id);
+    let fuel_contract = FuelContract::from(deployed.bytecode.as_slice());
+    let deployed_code_root = fuel_contract.root();
+    let derived_id = fuel_contract.id(&deployed.salt, &deployed_code_root, &expected.state_root);
+
+    let loader = (!blobs.is_empty()).then(|| LoaderReport {
+        blob_ids: blobs.iter().map(|(id, _)| *id).collect(),
+        missing_blobs: blobs
+            .iter()
+            .filter(|(_, blob)| blob.is_none())
+            .map(|(id, _)| *id)
+            .collect(),
+    });
+
+    let code = match &expected.code {
+        ExpectedCode::LoaderOnly(loader_code) => {
+            Some(CodeComparison::new(loader_code, &deployed.bytecode))
+        }
+        ExpectedCode::Effective(code) if blobs.is_empty() => {
+            Some(CodeComparison::new(code, &deployed.bytecode))
+        }
+        ExpectedCode::Effective(code) => blobs
+            .into_iter()
+            .map(|(_, blob)| blob)
+            .collect::<Option<Vec<_>>>()
+            .map(|blobs| CodeComparison::new(code, &blobs.concat())),
+    };
+
+    VerificationReport {
+        contract_id: Comparison {
+            expected: expected.contract_id,
+            actual: deployed_id,
+        },
+        salt: Comparison {
+            expected: expected.salt,
+            actual: deployed.salt,
+        },
+        code_root: Comparison {
+            expected: expected.code_root,
+            actual: deployed_code_root,
+        },
+        state_root: expected.state_root,
+        state_root_matches: derived_id == deployed_id,
+        code,
+        loader,
+    }
+}
+
+/// Recovers the blob ids if `code` was generated by [`loader_contract_asm`].
+fn blob_ids_from_loader_code(code: &[u8]) -> Option<Vec<BlobId>> {
+    const BLOB_ID_SIZE: usize = 32;
+    let instructions_len = loader_contract_asm(&[]).ok()?.len();
+
+    let ids_len = code.len().checked_sub(instructions_len)?;
+    if ids_len == 0 || ids_len % BLOB_ID_SIZE != 0 {
+        return None;
+    }
+
+    let blob_ids: Vec<BlobId> = code[instructions_len..]
+        .chunks_exact(BLOB_ID_SIZE)
+        .map(|id| id.try_into().expect("chunks are exactly 32 bytes"))
+        .collect();
+
+    (loader_contract_asm(&blob_ids).ok()? == code).then_some(blob_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::StorageSlot;
+    use fuels_core::types::transaction_builders::Blob;
+
+    use super::*;
+
+    fn deployed(contract_id: ContractId, bytecode: Vec<u8>, salt: Salt) -> DeployedContract {
+        DeployedContract {
+            contract_id: contract_id.into(),
+            bytecode,
+            salt,
+        }
+    }
+
+    fn expected(contract: &Contract<Regular>) -> Expected {
+        Expected {
+            contract_id: contract.contract_id(),
+            code_root: contract.code_root(),
+            state_root: contract.state_root(),
+            salt: contract.salt(),
+            code: ExpectedCode::Effective(contract.code()),
+        }
+    }
+
+    #[test]
+    fn blob_ids_are_recovered_from_loader_code() -> Result<()> {
+        // given
+        let blob_ids = vec![[1; 32], [2; 32]];
+        let code = loader_contract_asm(&blob_ids)?;
+
+        // when
+        let recovered = blob_ids_from_loader_code(&code);
+
+        // then
+        assert_eq!(recovered, Some(blob_ids));
+        assert_eq!(blob_ids_from_loader_code(b"some fake contract code"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn identical_deployment_is_verified() {
+        // given
+        let slots = vec![StorageSlot::new([1; 32].into(), [2; 32].into())];
+        let contract = Contract::regular(b"some code".to_vec(), Salt::zeroed(), slots);
+        let deployed = deployed(contract.contract_id(), contract.code(), Salt::zeroed());
+
+        // when
+        let report = build_report(expected(&contract), &deployed, vec![]);
+
+        // then
+        assert!(report.is_verified());
+        assert_eq!(report.loader, None);
+    }
+
+    #[test]
+    fn different_storage_slots_are_detected() {
+        // given
+        let code = b"some code".to_vec();
+        let local = Contract::regular(
+            code.clone(),
+            Salt::zeroed(),
+            vec![StorageSlot::new([1; 32].into(), [2; 32].into())],
+        );
+        let on_chain = Contract::regular(code, Salt::zeroed(), vec![]);
+        let deployed = deployed(on_chain.contract_id(), on_chain.code(), Salt::zeroed());
+
+        // when
+        let report = build_report(expected(&local), &deployed, vec![]);
+
+        // then
+        assert!(!report.is_verified());
+        assert!(!report.state_root_matches);
+        assert!(!report.contract_id.matches());
+        assert!(report.salt.matches());
+        assert!(report.code_root.matches());
+        assert!(report.code.unwrap().matches());
+    }
+
+    #[test]
+    fn code_mismatch_reports_first_differing_byte() {
+        // given
+        let local = Contract::regular(b"some code".to_vec(), Salt::zeroed(), vec![]);
+        let on_chain = Contract::regular(b"some coda".to_vec(), Salt::from([1; 32]), vec![]);
+        let deployed = deployed(on_chain.contract_id(), on_chain.code(), on_chain.salt());
+
+        // when
+        let report = build_report(expected(&local), &deployed, vec![]);
+
+        // then
+        assert!(!report.salt.matches());
+        assert!(!report.code_root.matches());
+        assert!(report.state_root_matches);
+        assert_eq!(
+            report.code,
+            Some(CodeComparison {
+                expected_len: 9,
+                actual_len: 9,
+                first_mismatch: Some(8),
+            })
+        );
+    }
+
+    #[test]
+    fn loader_blobs_are_compared_against_the_regular_code() -> Result<()> {
+        // given
+        let code = vec![7; 24];
+        let local = Contract::regular(code.clone(), Salt::zeroed(), vec![]);
+        let loader = local.clone().convert_to_loader(1)?;
+        let blobs: Vec<_> = loader
+            .blobs()
+            .iter()
+            .map(|blob| (blob.id(), Some(blob.bytes().to_vec())))
+            .collect();
+        let deployed = deployed(loader.contract_id(), loader.code(), Salt::zeroed());
+
+        // when
+        let report = build_report(expected(&local), &deployed, blobs);
+
+        // then
+        assert!(report.code.unwrap().matches());
+        assert!(!report.contract_id.matches());
+        assert_eq!(
+            report.loader,
+            Some(LoaderReport {
+                blob_ids: loader.blob_ids(),
+                missing_blobs: vec![],
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_blobs_are_reported() -> Result<()> {
+        // given
+        let blob = Blob::new(vec![7; 8]);
+        let loader = Contract::loader_from_blobs(vec![blob.clone()], Salt::zeroed(), vec![])?;
+        let deployed = deployed(loader.contract_id(), loader.code(), Salt::zeroed());
+        let expected = Expected {
+            contract_id: loader.contract_id(),
+            code_root: loader.code_root(),
+            state_root: loader.state_root(),
+            salt: Salt::zeroed(),
+            code: ExpectedCode::Effective(blob.bytes().to_vec()),
+        };
+
+        // when
+        let report = build_report(expected, &deployed, vec![(blob.id(), None)]);
+
+        // then
+        assert!(!report.is_verified());
+        assert_eq!(report.code, None);
+        assert_eq!(report.loader.unwrap().missing_blobs, vec![blob.id()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loader_with_missing_blobs_is_not_verified() -> Result<()> {
+        // given
+        let blob = Blob::new(vec![7; 8]);
+        let loader = Contract::loader_from_blobs(vec![blob.clone()], Salt::zeroed(), vec![])?;
+        let deployed = deployed(loader.contract_id(), loader.code(), Salt::zeroed());
+        let expected = Expected {
+            contract_id: loader.contract_id(),
+            code_root: loader.code_root(),
+            state_root: loader.state_root(),
+            salt: Salt::zeroed(),
+            code: ExpectedCode::LoaderOnly(loader.code()),
+        };
+
+        // when
+        let report = build_report(expected, &deployed, vec![(blob.id(), None)]);
+
+        // then
+        assert!(report.code.unwrap().matches());
+        assert!(!report.is_verified());
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use fuel_tx::{Bytes32, Receipt};
 use fuels_core::{
-    codec::{LogDecoder, LogResult},
+    codec::{ContractEvent, Event, LogDecoder, LogResult},
     traits::{Parameterize, Tokenizable},
     types::errors::Result,
 };
@@ -54,4 +54,9 @@ impl<D> CallResponse<D> {
     pub fn decode_logs_with_type<T: Tokenizable + Parameterize + 'static>(&self) -> Result<Vec<T>> {
         self.log_decoder.decode_logs_with_type::<T>(&self.receipts)
     }
+
+    /// Decode all logs as events of type `E`, e.g. the `{Name}Event` enum generated by `abigen!`.
+    pub fn decode_events<E: Event>(&self) -> Result<Vec<ContractEvent<E>>> {
+        self.log_decoder.decode_events::<E>(&self.receipts)
+    }
 }
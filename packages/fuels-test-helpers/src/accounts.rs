@@ -2,12 +2,16 @@ use std::mem::size_of;
 
 use fuel_crypto::SecretKey;
 use fuels_accounts::wallet::WalletUnlocked;
-use fuels_core::types::errors::Result;
+use fuels_core::types::{bech32::Bech32ContractId, errors::Result};
+use rand::{rngs::StdRng, SeedableRng};
 
 use crate::{
+    custom_assets_coins,
     node_types::{ChainConfig, NodeConfig},
-    setup_custom_assets_coins, setup_test_provider,
+    setup_test_provider_with_genesis,
+    test_rng::with_test_rng,
     wallets_config::*,
+    GenesisDeployments,
 };
 
 /// Launches a local Fuel node, instantiates a provider, and returns a wallet.
@@ -55,11 +59,70 @@ pub async fn launch_custom_provider_and_get_wallets(
     node_config: Option<NodeConfig>,
     chain_config: Option<ChainConfig>,
 ) -> Result<Vec<WalletUnlocked>> {
+    let (wallets, _) = launch_custom_provider_with_genesis_and_get_wallets(
+        wallet_config,
+        GenesisDeployments::default(),
+        node_config,
+        chain_config,
+    )
+    .await?;
+
+    Ok(wallets)
+}
+
+/// Like [`launch_custom_provider_and_get_wallets`], but also writes the contracts and blobs of
+/// `deployments` into the genesis state of the node. Returns the wallets together with the ids
+/// of the deployed contracts.
+pub async fn launch_custom_provider_with_genesis_and_get_wallets(
+    wallet_config: WalletsConfig,
+    deployments: GenesisDeployments,
+    node_config: Option<NodeConfig>,
+    chain_config: Option<ChainConfig>,
+) -> Result<(Vec<WalletUnlocked>, Vec<Bech32ContractId>)> {
+    let coins_of = |wallets: &[WalletUnlocked], rng: &mut StdRng| {
+        wallets
+            .iter()
+            .flat_map(|wallet| custom_assets_coins(wallet.address(), wallet_config.assets(), rng))
+            .collect::<Vec<_>>()
+    };
+
+    // a seeded config gets a generator of its own, leaving the one of the test untouched
+    let (mut wallets, all_coins) = match wallet_config.seed() {
+        Some(seed) => {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let wallets = (0..wallet_config.num_wallets())
+                .map(|_| {
+                    let private_key = SecretKey::random(&mut rng);
+                    WalletUnlocked::new_from_private_key(private_key, None)
+                })
+                .collect::<Vec<_>>();
+            let coins = coins_of(&wallets, &mut rng);
+            (wallets, coins)
+        }
+        None => {
+            let wallets = counter_wallets(wallet_config.num_wallets());
+            let coins = with_test_rng(|rng| coins_of(&wallets, rng));
+            (wallets, coins)
+        }
+    };
+
+    let (provider, contract_ids) =
+        setup_test_provider_with_genesis(all_coins, vec![], deployments, node_config, chain_config)
+            .await?;
+
+    for wallet in &mut wallets {
+        wallet.set_provider(provider.clone());
+    }
+
+    Ok((wallets, contract_ids))
+}
+
+fn counter_wallets(num_wallets: u64) -> Vec<WalletUnlocked> {
     const SIZE_SECRET_KEY: usize = size_of::<SecretKey>();
     const PADDING_BYTES: usize = SIZE_SECRET_KEY - size_of::<u64>();
     let mut secret_key: [u8; SIZE_SECRET_KEY] = [0; SIZE_SECRET_KEY];
 
-    let mut wallets: Vec<_> = (1..=wallet_config.num_wallets())
+    (1..=num_wallets)
         .map(|wallet_counter| {
             secret_key[PADDING_BYTES..].copy_from_slice(&wallet_counter.to_be_bytes());
 
@@ -69,20 +132,7 @@ pub async fn launch_custom_provider_and_get_wallets(
                 None,
             )
         })
-        .collect();
-
-    let all_coins = wallets
-        .iter()
-        .flat_map(|wallet| setup_custom_assets_coins(wallet.address(), wallet_config.assets()))
-        .collect::<Vec<_>>();
-
-    let provider = setup_test_provider(all_coins, vec![], node_config, chain_config).await?;
-
-    for wallet in &mut wallets {
-        wallet.set_provider(provider.clone());
-    }
-
-    Ok(wallets)
+        .collect()
 }
 
 #[cfg(test)]
@@ -91,10 +141,13 @@ mod tests {
     use fuel_tx::{ConsensusParameters, TxParameters};
     use fuel_types::AssetId;
     use fuels_accounts::ViewOnlyAccount;
-    use fuels_core::types::{coin_type::CoinType, errors::Result};
+    use fuels_core::types::{coin::Coin, coin_type::CoinType, errors::Result};
     use rand::Fill;
 
-    use crate::{launch_custom_provider_and_get_wallets, AssetConfig, WalletsConfig};
+    use crate::{
+        launch_custom_provider_and_get_wallets, seed_test_rng, setup_single_asset_coins,
+        AssetConfig, WalletsConfig,
+    };
 
     #[tokio::test]
     async fn test_wallet_config() -> Result<()> {
@@ -191,6 +244,57 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn seeded_wallets_are_reproducible() -> Result<()> {
+        // given
+        let config = || WalletsConfig::new(Some(2), Some(2), Some(100)).with_seed(42);
+
+        // when
+        let first = launch_custom_provider_and_get_wallets(config(), None, None).await?;
+        let second = launch_custom_provider_and_get_wallets(config(), None, None).await?;
+
+        // then
+        for (first, second) in first.iter().zip(&second) {
+            assert_eq!(first.address(), second.address());
+
+            let utxo_ids = |coins: Vec<Coin>| {
+                coins
+                    .into_iter()
+                    .map(|coin| coin.utxo_id)
+                    .collect::<Vec<_>>()
+            };
+            let first_coins = utxo_ids(first.get_coins(AssetId::zeroed()).await?);
+            let second_coins = utxo_ids(second.get_coins(AssetId::zeroed()).await?);
+            assert_eq!(first_coins, second_coins);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn seeded_wallets_leave_the_test_generator_untouched() -> Result<()> {
+        // given
+        let draw_utxo_id =
+            |owner| setup_single_asset_coins(owner, AssetId::zeroed(), 1, 1)[0].utxo_id;
+        seed_test_rng(7);
+        let wallets = launch_custom_provider_and_get_wallets(
+            WalletsConfig::new(Some(1), Some(1), Some(100)).with_seed(42),
+            None,
+            None,
+        )
+        .await?;
+        let owner = wallets[0].address();
+
+        // when
+        let drawn_after_setup = draw_utxo_id(owner);
+
+        // then
+        seed_test_rng(7);
+        assert_eq!(drawn_after_setup, draw_utxo_id(owner));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn generated_wallets_with_custom_chain_config() -> Result<()> {
         let mut consensus_parameters = ConsensusParameters::default();
@@ -0,0 +1,220 @@
+use fuel_core_chain_config::ChainConfig;
+use fuel_tx::{
+    ConsensusParameters, ContractParameters, FeeParameters, GasCosts, PredicateParameters,
+    ScriptParameters, TxParameters,
+};
+use fuel_types::{AssetId, ChainId};
+
+/// Gas costs of all operations, to pick from when testing how programs behave under different
+/// costs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GasCostsPreset {
+    /// The gas costs `fuel-core` uses by default.
+    #[default]
+    Default,
+    /// All operations cost nothing.
+    Free,
+    /// All operations cost one unit of gas.
+    Unit,
+}
+
+impl From<GasCostsPreset> for GasCosts {
+    fn from(preset: GasCostsPreset) -> Self {
+        match preset {
+            GasCostsPreset::Default => GasCosts::default(),
+            GasCostsPreset::Free => GasCosts::free(),
+            GasCostsPreset::Unit => GasCosts::unit(),
+        }
+    }
+}
+
+/// Builds the `ChainConfig` of a test node.
+///
+/// Starts from the configuration `setup_test_provider` uses when given no `ChainConfig`, i.e. the
+/// local testnet with increased transaction and contract size limits, and changes only what is
+/// set on the builder.
+///
+/// ```
+/// use fuels_test_helpers::{ChainConfigBuilder, GasCostsPreset};
+///
+/// let chain_config = ChainConfigBuilder::default()
+///     .with_max_inputs(3)
+///     .with_gas_costs(GasCostsPreset::Unit)
+///     .with_block_gas_limit(1_000_000)
+///     .build();
+///
+/// assert_eq!(chain_config.consensus_parameters.tx_params().max_inputs(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChainConfigBuilder {
+    chain_config: ChainConfig,
+}
+
+impl Default for ChainConfigBuilder {
+    fn default() -> Self {
+        let mut consensus_parameters = ConsensusParameters::default();
+        let tx_params = TxParameters::default().with_max_size(10_000_000);
+        // on a best effort basis, if we're given an old core we won't fail only because we couldn't
+        // set the limit here
+        let _ = consensus_parameters.set_block_transaction_size_limit(10_000_000);
+
+        let contract_params = ContractParameters::default().with_contract_max_size(1_000_000);
+        consensus_parameters.set_tx_params(tx_params);
+        consensus_parameters.set_contract_params(contract_params);
+
+        Self {
+            chain_config: ChainConfig {
+                consensus_parameters,
+                ..ChainConfig::local_testnet()
+            },
+        }
+    }
+}
+
+impl ChainConfigBuilder {
+    /// Starts from the given `chain_config` instead of the default one.
+    pub fn from_chain_config(chain_config: ChainConfig) -> Self {
+        Self { chain_config }
+    }
+
+    pub fn with_chain_name(mut self, chain_name: impl Into<String>) -> Self {
+        self.chain_config.chain_name = chain_name.into();
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: impl Into<ChainId>) -> Self {
+        self.consensus_parameters().set_chain_id(chain_id.into());
+        self
+    }
+
+    /// Sets the asset used to pay for transactions. Coins given to the node should be of this
+    /// asset for wallets to be able to pay fees.
+    pub fn with_base_asset_id(mut self, base_asset_id: AssetId) -> Self {
+        self.consensus_parameters().set_base_asset_id(base_asset_id);
+        self
+    }
+
+    pub fn with_tx_params(mut self, tx_params: TxParameters) -> Self {
+        self.consensus_parameters().set_tx_params(tx_params);
+        self
+    }
+
+    /// Changes only the maximum number of inputs of the current `TxParameters`.
+    pub fn with_max_inputs(self, max_inputs: u16) -> Self {
+        let tx_params = self.tx_params().with_max_inputs(max_inputs);
+        self.with_tx_params(tx_params)
+    }
+
+    /// Changes only the maximum number of outputs of the current `TxParameters`.
+    pub fn with_max_outputs(self, max_outputs: u16) -> Self {
+        let tx_params = self.tx_params().with_max_outputs(max_outputs);
+        self.with_tx_params(tx_params)
+    }
+
+    /// Changes only the maximum gas per transaction of the current `TxParameters`.
+    pub fn with_max_gas_per_tx(self, max_gas_per_tx: u64) -> Self {
+        let tx_params = self.tx_params().with_max_gas_per_tx(max_gas_per_tx);
+        self.with_tx_params(tx_params)
+    }
+
+    pub fn with_predicate_params(mut self, predicate_params: PredicateParameters) -> Self {
+        self.consensus_parameters()
+            .set_predicate_params(predicate_params);
+        self
+    }
+
+    pub fn with_script_params(mut self, script_params: ScriptParameters) -> Self {
+        self.consensus_parameters().set_script_params(script_params);
+        self
+    }
+
+    pub fn with_contract_params(mut self, contract_params: ContractParameters) -> Self {
+        self.consensus_parameters()
+            .set_contract_params(contract_params);
+        self
+    }
+
+    pub fn with_fee_params(mut self, fee_params: FeeParameters) -> Self {
+        self.consensus_parameters().set_fee_params(fee_params);
+        self
+    }
+
+    /// Sets the gas costs, either one of the [`GasCostsPreset`]s or custom `GasCosts`.
+    pub fn with_gas_costs(mut self, gas_costs: impl Into<GasCosts>) -> Self {
+        self.consensus_parameters().set_gas_costs(gas_costs.into());
+        self
+    }
+
+    pub fn with_block_gas_limit(mut self, block_gas_limit: u64) -> Self {
+        self.consensus_parameters()
+            .set_block_gas_limit(block_gas_limit);
+        self
+    }
+
+    /// Sets the maximum size of all transactions in a block. Ignored by cores that don't support
+    /// the limit.
+    pub fn with_block_transaction_size_limit(mut self, size_limit: u64) -> Self {
+        let _ = self
+            .consensus_parameters()
+            .set_block_transaction_size_limit(size_limit);
+        self
+    }
+
+    pub fn build(self) -> ChainConfig {
+        self.chain_config
+    }
+
+    fn consensus_parameters(&mut self) -> &mut ConsensusParameters {
+        &mut self.chain_config.consensus_parameters
+    }
+
+    fn tx_params(&self) -> TxParameters {
+        *self.chain_config.consensus_parameters.tx_params()
+    }
+}
+
+impl From<ChainConfigBuilder> for ChainConfig {
+    fn from(builder: ChainConfigBuilder) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_set_parameters_are_changed() {
+        // given
+        let default_config = ChainConfigBuilder::default().build();
+        let fee_params = FeeParameters::default().with_gas_per_byte(2);
+
+        // when
+        let chain_config = ChainConfigBuilder::default()
+            .with_chain_name("custom")
+            .with_chain_id(42)
+            .with_base_asset_id(AssetId::from([1; 32]))
+            .with_max_inputs(3)
+            .with_fee_params(fee_params)
+            .with_gas_costs(GasCostsPreset::Free)
+            .with_block_gas_limit(1_000)
+            .build();
+
+        // then
+        let params = &chain_config.consensus_parameters;
+        let default_params = &default_config.consensus_parameters;
+
+        assert_eq!(chain_config.chain_name, "custom");
+        assert_eq!(params.chain_id(), ChainId::new(42));
+        assert_eq!(*params.base_asset_id(), AssetId::from([1; 32]));
+        assert_eq!(params.tx_params().max_inputs(), 3);
+        assert_eq!(
+            params.tx_params().max_size(),
+            default_params.tx_params().max_size()
+        );
+        assert_eq!(*params.fee_params(), fee_params);
+        assert_eq!(*params.gas_costs(), GasCosts::free());
+        assert_eq!(params.block_gas_limit(), 1_000);
+        assert_eq!(params.contract_params(), default_params.contract_params());
+    }
+}
@@ -1,17 +1,26 @@
 use std::{
+    collections::VecDeque,
     net::{IpAddr, SocketAddr},
-    path::PathBuf,
+    path::{Path, PathBuf},
+    process::Stdio,
+    sync::{Arc, Mutex},
     time::Duration,
 };
 
-use fuel_core_chain_config::{ChainConfig, SnapshotWriter, StateConfig};
+use fuel_core_chain_config::{ChainConfig, SnapshotMetadata, SnapshotWriter, StateConfig};
 use fuel_core_client::client::FuelClient;
 use fuel_core_services::State;
 use fuel_core_types::blockchain::header::LATEST_STATE_TRANSITION_VERSION;
 use fuels_core::{error, types::errors::Result as FuelResult};
 use portpicker::{is_free, pick_unused_port};
 use tempfile::{tempdir, TempDir};
-use tokio::{process::Command, spawn, task::JoinHandle, time::sleep};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, BufReader},
+    process::Command,
+    spawn,
+    task::JoinHandle,
+    time::sleep,
+};
 
 use crate::node_types::{DbType, NodeConfig, Trigger};
 
@@ -45,10 +54,7 @@ impl ExtendedConfig {
             DbType::InMemory => args.push("in-memory".to_string()),
             DbType::RocksDb(path_to_db) => {
                 args.push("rocks-db".to_string());
-                let path = path_to_db.as_ref().cloned().unwrap_or_else(|| {
-                    PathBuf::from(std::env::var("HOME").expect("HOME env var missing"))
-                        .join(".fuel/db")
-                });
+                let path = rocksdb_path(path_to_db);
                 args.push("--db-path".to_string());
                 args.push(path.to_string_lossy().to_string());
             }
@@ -119,6 +125,7 @@ impl ExtendedConfig {
 pub struct FuelService {
     pub bound_address: SocketAddr,
     handle: JoinHandle<()>,
+    logs: NodeLogs,
 }
 
 impl FuelService {
@@ -153,12 +160,14 @@ impl FuelService {
         };
 
         let addr = extended_config.node_config.addr;
-        let handle = run_node(extended_config).await?;
+        let logs = NodeLogs::default();
+        let handle = run_node(extended_config, logs.clone()).await?;
         server_health_check(addr).await?;
 
         Ok(FuelService {
             bound_address,
             handle,
+            logs,
         })
     }
 
@@ -166,6 +175,53 @@ impl FuelService {
         self.handle.abort();
         Ok(State::Stopped)
     }
+
+    pub fn logs(&self) -> String {
+        self.logs.contents()
+    }
+}
+
+impl Drop for FuelService {
+    fn drop(&mut self) {
+        // aborting drops the child process, which kills it
+        self.handle.abort();
+    }
+}
+
+/// Output of a `fuel-core` process, captured line by line. Only the last
+/// [`NodeLogs::MAX_LINES`] lines are kept so that long-running nodes don't grow without bound.
+#[derive(Debug, Clone, Default)]
+struct NodeLogs(Arc<Mutex<VecDeque<String>>>);
+
+impl NodeLogs {
+    const MAX_LINES: usize = 10_000;
+
+    fn contents(&self) -> String {
+        self.0
+            .lock()
+            .expect("node logs lock poisoned")
+            .iter()
+            .map(|line| format!("{line}\n"))
+            .collect()
+    }
+
+    fn push(&self, line: String) {
+        let mut lines = self.0.lock().expect("node logs lock poisoned");
+        if lines.len() == Self::MAX_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    fn capture(&self, output: impl AsyncRead + Unpin + Send + 'static) {
+        let logs = self.clone();
+        spawn(async move {
+            let mut lines = BufReader::new(output).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                logs.push(line);
+            }
+        });
+    }
 }
 
 async fn server_health_check(address: SocketAddr) -> FuelResult<()> {
@@ -195,10 +251,44 @@ fn get_socket_address() -> FuelResult<SocketAddr> {
     Ok(SocketAddr::new(address, free_port))
 }
 
-async fn run_node(extended_config: ExtendedConfig) -> FuelResult<JoinHandle<()>> {
-    let args = extended_config.args_vec()?;
-    let tempdir = extended_config.write_temp_snapshot_files()?;
+/// Path of the RocksDB database of a node, `~/.fuel/db` unless given.
+pub(crate) fn rocksdb_path(path: &Option<PathBuf>) -> PathBuf {
+    path.clone().unwrap_or_else(|| {
+        PathBuf::from(std::env::var("HOME").expect("HOME env var missing")).join(".fuel/db")
+    })
+}
+
+/// Reads the state stored in the RocksDB database at `db_path` using `fuel-core snapshot`. The
+/// database is locked while a node uses it, so the node has to be stopped first.
+pub(crate) async fn read_state_config(db_path: &Path) -> FuelResult<StateConfig> {
+    let snapshot_dir = tempdir()?;
+
+    let output = Command::new(fuel_core_binary()?)
+        .arg("snapshot")
+        .arg("--db-path")
+        .arg(db_path)
+        .arg("--output-directory")
+        .arg(snapshot_dir.path())
+        .arg("everything")
+        .output()
+        .await
+        .map_err(|e| error!(IO, "could not run `fuel-core snapshot`: {e}"))?;
+
+    if !output.status.success() {
+        return Err(error!(
+            Other,
+            "`fuel-core snapshot` failed with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    SnapshotMetadata::read(snapshot_dir.path())
+        .and_then(StateConfig::from_snapshot_metadata)
+        .map_err(|e| error!(Other, "could not read the snapshot of the node: {e}"))
+}
 
+fn fuel_core_binary() -> FuelResult<PathBuf> {
     let binary_name = "fuel-core";
 
     let paths = which::which_all(binary_name)
@@ -216,19 +306,61 @@ async fn run_node(extended_config: ExtendedConfig) -> FuelResult<JoinHandle<()>>
         );
     }
 
-    let mut command = Command::new(path);
-    let running_node = command.args(args).kill_on_drop(true).output();
+    Ok(path.clone())
+}
+
+async fn run_node(extended_config: ExtendedConfig, logs: NodeLogs) -> FuelResult<JoinHandle<()>> {
+    let args = extended_config.args_vec()?;
+    let tempdir = extended_config.write_temp_snapshot_files()?;
+
+    let path = fuel_core_binary()?;
+
+    let mut running_node = Command::new(&path)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| error!(IO, "could not start `{}`: {e}", path.display()))?;
+
+    if let Some(stdout) = running_node.stdout.take() {
+        logs.capture(stdout);
+    }
+    if let Some(stderr) = running_node.stderr.take() {
+        logs.capture(stderr);
+    }
 
     let join_handle = spawn(async move {
         // ensure drop is not called on the tmp dir and it lives throughout the lifetime of the node
         let _unused = tempdir;
-        let result = running_node
-            .await
-            .expect("error: could not find `fuel-core` in PATH`");
-        let stdout = String::from_utf8_lossy(&result.stdout);
-        let stderr = String::from_utf8_lossy(&result.stderr);
-        eprintln!("the exit status from the fuel binary was: {result:?}, stdout: {stdout}, stderr: {stderr}");
+        let status = running_node.wait().await;
+        eprintln!(
+            "the exit status from the fuel binary was: {status:?}, output: {}",
+            logs.contents()
+        );
     });
 
     Ok(join_handle)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn node_logs_keep_only_the_last_lines() {
+        // given
+        let logs = NodeLogs::default();
+
+        // when
+        for i in 0..NodeLogs::MAX_LINES + 2 {
+            logs.push(i.to_string());
+        }
+
+        // then
+        let contents = logs.contents();
+        assert_eq!(contents.lines().count(), NodeLogs::MAX_LINES);
+        assert!(contents.starts_with("2\n"));
+        assert!(contents.ends_with(&format!("{}\n", NodeLogs::MAX_LINES + 1)));
+    }
+}
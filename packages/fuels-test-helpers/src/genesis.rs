@@ -0,0 +1,236 @@
+use fuel_core_chain_config::{
+    BlobConfig, ContractBalanceConfig, ContractConfig, ContractStateConfig, StateConfig,
+};
+use fuel_tx::{AssetId, Bytes32, Contract, ContractId, Salt, StorageSlot};
+use fuels_core::types::{
+    bech32::Bech32ContractId,
+    coin::Coin,
+    transaction_builders::{Blob, BlobId},
+};
+
+use crate::utils::into_coin_configs;
+
+/// A contract written directly into the genesis state of a test node, so that it is available
+/// from the first block without a deploy transaction.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GenesisContract {
+    code: Vec<u8>,
+    salt: Salt,
+    storage_slots: Vec<StorageSlot>,
+    balances: Vec<(AssetId, u64)>,
+}
+
+impl GenesisContract {
+    pub fn new(code: Vec<u8>) -> Self {
+        Self {
+            code,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_salt(mut self, salt: impl Into<Salt>) -> Self {
+        self.salt = salt.into();
+        self
+    }
+
+    pub fn with_storage_slots(mut self, storage_slots: Vec<StorageSlot>) -> Self {
+        self.storage_slots = storage_slots;
+        self
+    }
+
+    /// Gives the contract an initial balance of `amount` of `asset_id`.
+    pub fn with_balance(mut self, asset_id: AssetId, amount: u64) -> Self {
+        self.balances.push((asset_id, amount));
+        self
+    }
+
+    /// The id the contract would get if it were deployed with a transaction.
+    pub fn contract_id(&self) -> Bech32ContractId {
+        self.compute_id().into()
+    }
+
+    fn compute_id(&self) -> ContractId {
+        let contract = Contract::from(self.code.as_slice());
+        let state_root = Contract::initial_state_root(self.storage_slots.iter());
+
+        contract.id(&self.salt, &contract.root(), &state_root)
+    }
+
+    fn into_config(self, output_index: u16) -> ContractConfig {
+        ContractConfig {
+            contract_id: self.compute_id(),
+            code: self.code,
+            tx_id: Bytes32::zeroed(),
+            output_index,
+            tx_pointer_block_height: Default::default(),
+            tx_pointer_tx_idx: 0,
+            states: self
+                .storage_slots
+                .into_iter()
+                .map(|slot| ContractStateConfig {
+                    key: *slot.key(),
+                    value: slot.value().to_vec(),
+                })
+                .collect(),
+            balances: self
+                .balances
+                .into_iter()
+                .map(|(asset_id, amount)| ContractBalanceConfig { asset_id, amount })
+                .collect(),
+        }
+    }
+}
+
+/// Contracts, blobs and additional coins to be written into the genesis state of a test node.
+///
+/// Deploying at genesis skips the deploy transactions, which makes setting up tests with many
+/// or large contracts considerably faster. The deployed contracts keep the ids they would get
+/// from a deploy transaction.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GenesisDeployments {
+    contracts: Vec<GenesisContract>,
+    blobs: Vec<Blob>,
+    coins: Vec<Coin>,
+}
+
+impl GenesisDeployments {
+    pub fn with_contract(mut self, contract: GenesisContract) -> Self {
+        self.contracts.push(contract);
+        self
+    }
+
+    pub fn with_contracts(mut self, contracts: impl IntoIterator<Item = GenesisContract>) -> Self {
+        self.contracts.extend(contracts);
+        self
+    }
+
+    pub fn with_blob(mut self, blob: Blob) -> Self {
+        self.blobs.push(blob);
+        self
+    }
+
+    pub fn with_blobs(mut self, blobs: impl IntoIterator<Item = Blob>) -> Self {
+        self.blobs.extend(blobs);
+        self
+    }
+
+    /// Adds coins, e.g. to fund predicates, on top of the ones the node is started with.
+    pub fn with_coins(mut self, coins: impl IntoIterator<Item = Coin>) -> Self {
+        self.coins.extend(coins);
+        self
+    }
+
+    /// Ids of the contracts, in the order they were added.
+    pub fn contract_ids(&self) -> Vec<Bech32ContractId> {
+        self.contracts
+            .iter()
+            .map(GenesisContract::contract_id)
+            .collect()
+    }
+
+    /// Ids of the blobs, in the order they were added.
+    pub fn blob_ids(&self) -> Vec<BlobId> {
+        self.blobs.iter().map(Blob::id).collect()
+    }
+
+    pub(crate) fn write_into(self, state_config: &mut StateConfig) {
+        let first_output_index = state_config.contracts.len();
+        state_config
+            .contracts
+            .extend(self.contracts.into_iter().enumerate().map(|(i, contract)| {
+                let output_index = u16::try_from(first_output_index + i)
+                    .expect("too many contracts in the genesis state");
+                contract.into_config(output_index)
+            }));
+
+        state_config
+            .blobs
+            .extend(self.blobs.into_iter().map(|blob| BlobConfig {
+                blob_id: blob.id().into(),
+                payload: blob.bytes().to_vec(),
+            }));
+
+        state_config.coins.extend(into_coin_configs(self.coins));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn genesis_contracts_get_the_ids_of_deployed_contracts() {
+        // given
+        let code = vec![1, 2, 3, 4];
+        let salt = Salt::from([1; 32]);
+        let storage_slots = vec![StorageSlot::new([2; 32].into(), [3; 32].into())];
+
+        let contract = Contract::from(code.as_slice());
+        let state_root = Contract::initial_state_root(storage_slots.iter());
+        let expected_id = contract.id(&salt, &contract.root(), &state_root);
+
+        // when
+        let genesis_contract = GenesisContract::new(code)
+            .with_salt(salt)
+            .with_storage_slots(storage_slots);
+
+        // then
+        assert_eq!(genesis_contract.contract_id(), expected_id.into());
+    }
+
+    #[test]
+    fn deployments_are_written_into_the_state_config() {
+        // given
+        let asset_id = AssetId::from([5; 32]);
+        let first = GenesisContract::new(vec![1]).with_balance(asset_id, 100);
+        let second = GenesisContract::new(vec![2])
+            .with_storage_slots(vec![StorageSlot::new([2; 32].into(), [3; 32].into())]);
+        let blob = Blob::new(vec![4, 5, 6]);
+        let coin = Coin {
+            amount: 10,
+            asset_id,
+            ..Default::default()
+        };
+
+        let deployments = GenesisDeployments::default()
+            .with_contracts([first, second])
+            .with_blob(blob.clone())
+            .with_coins([coin]);
+        let contract_ids = deployments.contract_ids();
+
+        let mut state_config = StateConfig::default();
+
+        // when
+        deployments.write_into(&mut state_config);
+
+        // then
+        let written_ids: Vec<Bech32ContractId> = state_config
+            .contracts
+            .iter()
+            .map(|contract| contract.contract_id.into())
+            .collect();
+        assert_eq!(written_ids, contract_ids);
+
+        let [first, second] = &state_config.contracts[..] else {
+            panic!("expected two contracts");
+        };
+        assert_eq!(
+            first.balances,
+            vec![ContractBalanceConfig {
+                asset_id,
+                amount: 100
+            }]
+        );
+        assert_eq!(first.output_index, 0);
+        assert_eq!(second.output_index, 1);
+        assert_eq!(second.states[0].value, vec![3; 32]);
+
+        assert_eq!(state_config.blobs.len(), 1);
+        assert_eq!(state_config.blobs[0].blob_id, blob.id().into());
+        assert_eq!(state_config.blobs[0].payload, blob.bytes());
+
+        assert_eq!(state_config.coins.len(), 1);
+        assert_eq!(state_config.coins[0].amount, 10);
+        assert_eq!(state_config.coins[0].asset_id, asset_id);
+    }
+}
@@ -3,19 +3,23 @@ extern crate core;
 
 #[cfg(feature = "fuels-accounts")]
 pub use accounts::*;
-use fuel_tx::{Bytes32, ConsensusParameters, ContractParameters, TxParameters, UtxoId};
+pub use chain_config_builder::*;
+use fuel_tx::{Bytes32, UtxoId};
 use fuel_types::{AssetId, Nonce};
 use fuels_accounts::provider::Provider;
 use fuels_core::types::{
-    bech32::Bech32Address,
+    bech32::{Bech32Address, Bech32ContractId},
     coin::{Coin, CoinStatus},
     errors::Result,
     message::{Message, MessageStatus},
 };
+pub use genesis::*;
 pub use node_types::*;
-use rand::{rngs::StdRng, Fill, Rng, SeedableRng};
+use rand::{Fill, Rng};
 use utils::{into_coin_configs, into_message_configs};
 pub use wallets_config::*;
+mod chain_config_builder;
+mod genesis;
 mod node_types;
 
 #[cfg(not(feature = "fuel-core-lib"))]
@@ -27,6 +31,15 @@ mod accounts;
 pub use service::*;
 mod service;
 
+pub use snapshot::*;
+mod snapshot;
+
+pub use test_node::*;
+mod test_node;
+
+pub use test_rng::*;
+mod test_rng;
+
 mod utils;
 mod wallets_config;
 
@@ -34,61 +47,83 @@ mod wallets_config;
 /// asset IDs. `AssetId`. Each UTXO (=coin) contains `amount_per_coin` amount of a random asset. The
 /// output of this function can be used with `setup_test_provider` to get a client with some
 /// pre-existing coins, with `num_asset` different asset ids. Note that one of the assets is the
-/// base asset to pay for gas.
+/// base asset to pay for gas. The asset ids and UTXO ids can be made reproducible with
+/// [`seed_test_rng`].
 pub fn setup_multiple_assets_coins(
-    owner: &Bech32Address,
+    owner: impl Into<Bech32Address>,
     num_asset: u64,
     coins_per_asset: u64,
     amount_per_coin: u64,
 ) -> (Vec<Coin>, Vec<AssetId>) {
-    let mut rng = rand::thread_rng();
     // Create `num_asset-1` asset ids so there is `num_asset` in total with the base asset
-    let asset_ids = (0..(num_asset - 1))
-        .map(|_| {
-            let mut random_asset_id = AssetId::zeroed();
-            random_asset_id
-                .try_fill(&mut rng)
-                .expect("failed to fill with random data");
-            random_asset_id
-        })
-        .chain([AssetId::zeroed()])
-        .collect::<Vec<AssetId>>();
+    let asset_ids = with_test_rng(|rng| {
+        (0..(num_asset - 1))
+            .map(|_| {
+                let mut random_asset_id = AssetId::zeroed();
+                random_asset_id
+                    .try_fill(rng)
+                    .expect("failed to fill with random data");
+                random_asset_id
+            })
+            .chain([AssetId::zeroed()])
+            .collect::<Vec<AssetId>>()
+    });
 
+    let owner = owner.into();
     let coins = asset_ids
         .iter()
-        .flat_map(|id| setup_single_asset_coins(owner, *id, coins_per_asset, amount_per_coin))
+        .flat_map(|id| setup_single_asset_coins(&owner, *id, coins_per_asset, amount_per_coin))
         .collect::<Vec<Coin>>();
 
     (coins, asset_ids)
 }
 
 /// Create a vector of UTXOs with the provided AssetIds, num_coins, and amount_per_coin
-pub fn setup_custom_assets_coins(owner: &Bech32Address, assets: &[AssetConfig]) -> Vec<Coin> {
-    let coins = assets
+pub fn setup_custom_assets_coins(
+    owner: impl Into<Bech32Address>,
+    assets: &[AssetConfig],
+) -> Vec<Coin> {
+    let owner = owner.into();
+    with_test_rng(|rng| custom_assets_coins(&owner, assets, rng))
+}
+
+pub(crate) fn custom_assets_coins(
+    owner: &Bech32Address,
+    assets: &[AssetConfig],
+    rng: &mut impl Rng,
+) -> Vec<Coin> {
+    assets
         .iter()
         .flat_map(|asset| {
-            setup_single_asset_coins(owner, asset.id, asset.num_coins, asset.coin_amount)
+            single_asset_coins(owner, asset.id, asset.num_coins, asset.coin_amount, rng)
         })
-        .collect::<Vec<Coin>>();
-    coins
+        .collect()
 }
 
 /// Create a vector of `num_coins` UTXOs containing `amount_per_coin` amount of asset `asset_id`.
 /// The output of this function can be used with `setup_test_provider` to get a client with some
 /// pre-existing coins, but with only one asset ID.
 pub fn setup_single_asset_coins(
-    owner: &Bech32Address,
+    owner: impl Into<Bech32Address>,
     asset_id: AssetId,
     num_coins: u64,
     amount_per_coin: u64,
 ) -> Vec<Coin> {
-    let mut rng = rand::thread_rng();
+    let owner = owner.into();
+    with_test_rng(|rng| single_asset_coins(&owner, asset_id, num_coins, amount_per_coin, rng))
+}
 
+fn single_asset_coins(
+    owner: &Bech32Address,
+    asset_id: AssetId,
+    num_coins: u64,
+    amount_per_coin: u64,
+    rng: &mut impl Rng,
+) -> Vec<Coin> {
     let coins: Vec<Coin> = (1..=num_coins)
         .map(|_i| {
             let mut r = Bytes32::zeroed();
-            r.try_fill(&mut rng)
-                .expect("failed to fill with random data");
+            r.try_fill(rng).expect("failed to fill with random data");
             let utxo_id = UtxoId::new(r, 0);
 
             Coin {
@@ -106,15 +141,15 @@ pub fn setup_single_asset_coins(
 }
 
 pub fn setup_single_message(
-    sender: &Bech32Address,
-    recipient: &Bech32Address,
+    sender: impl Into<Bech32Address>,
+    recipient: impl Into<Bech32Address>,
     amount: u64,
     nonce: Nonce,
     data: Vec<u8>,
 ) -> Message {
     Message {
-        sender: sender.clone(),
-        recipient: recipient.clone(),
+        sender: sender.into(),
+        recipient: recipient.into(),
         nonce,
         amount,
         data,
@@ -123,23 +158,69 @@ pub fn setup_single_message(
     }
 }
 
+/// Create a message from `sender` on the base layer carrying `data` to `recipient`, e.g. a
+/// deposit to be processed by a contract, with a random nonce. The output of this function can
+/// be used with `setup_test_provider` to seed the message and spent with
+/// `CallHandler::with_messages` or `CallHandler::with_predicate_messages`.
+pub fn setup_message_with_data(
+    sender: impl Into<Bech32Address>,
+    recipient: impl Into<Bech32Address>,
+    amount: u64,
+    data: Vec<u8>,
+) -> Message {
+    let nonce = Nonce::new(with_test_rng(|rng| rng.gen()));
+
+    setup_single_message(sender, recipient, amount, nonce, data)
+}
+
+/// Starts a node with the given `coins` and `messages` and returns a [`TestNode`] handle owning
+/// it. Use [`setup_test_provider`] instead if the node should simply run until the end of the
+/// test process.
+pub async fn setup_test_node(
+    coins: Vec<Coin>,
+    messages: Vec<Message>,
+    node_config: Option<NodeConfig>,
+    chain_config: Option<ChainConfig>,
+) -> Result<TestNode> {
+    let (node_config, chain_config, state_config) =
+        test_node_configs(coins, messages, node_config, chain_config);
+
+    TestNode::start(node_config, chain_config, state_config).await
+}
+
 pub async fn setup_test_provider(
     coins: Vec<Coin>,
     messages: Vec<Message>,
     node_config: Option<NodeConfig>,
     chain_config: Option<ChainConfig>,
 ) -> Result<Provider> {
-    let node_config = node_config.unwrap_or_default();
-    let chain_config = chain_config.unwrap_or_else(testnet_chain_config);
+    let (provider, _) = setup_test_provider_with_genesis(
+        coins,
+        messages,
+        GenesisDeployments::default(),
+        node_config,
+        chain_config,
+    )
+    .await?;
+
+    Ok(provider)
+}
 
-    let coin_configs = into_coin_configs(coins);
-    let message_configs = into_message_configs(messages);
+/// Like [`setup_test_provider`], but also writes the contracts and blobs of `deployments` into
+/// the genesis state of the node. Returns the ids of the deployed contracts, in the order they
+/// were added to `deployments`.
+pub async fn setup_test_provider_with_genesis(
+    coins: Vec<Coin>,
+    messages: Vec<Message>,
+    deployments: GenesisDeployments,
+    node_config: Option<NodeConfig>,
+    chain_config: Option<ChainConfig>,
+) -> Result<(Provider, Vec<Bech32ContractId>)> {
+    let (node_config, chain_config, mut state_config) =
+        test_node_configs(coins, messages, node_config, chain_config);
 
-    let state_config = StateConfig {
-        coins: coin_configs,
-        messages: message_configs,
-        ..StateConfig::local_testnet()
-    };
+    let contract_ids = deployments.contract_ids();
+    deployments.write_into(&mut state_config);
 
     let srv = FuelService::start(node_config, chain_config, state_config).await?;
 
@@ -150,29 +231,32 @@ pub async fn setup_test_provider(
         let () = futures::future::pending().await;
     });
 
-    Provider::from(address).await
+    Ok((Provider::from(address).await?, contract_ids))
 }
 
-// Testnet ChainConfig with increased tx size and contract size limits
-fn testnet_chain_config() -> ChainConfig {
-    let mut consensus_parameters = ConsensusParameters::default();
-    let tx_params = TxParameters::default().with_max_size(10_000_000);
-    // on a best effort basis, if we're given an old core we won't fail only because we couldn't
-    // set the limit here
-    let _ = consensus_parameters.set_block_transaction_size_limit(10_000_000);
-
-    let contract_params = ContractParameters::default().with_contract_max_size(1_000_000);
-    consensus_parameters.set_tx_params(tx_params);
-    consensus_parameters.set_contract_params(contract_params);
-
-    ChainConfig {
-        consensus_parameters,
-        ..ChainConfig::local_testnet()
-    }
+fn test_node_configs(
+    coins: Vec<Coin>,
+    messages: Vec<Message>,
+    node_config: Option<NodeConfig>,
+    chain_config: Option<ChainConfig>,
+) -> (NodeConfig, ChainConfig, StateConfig) {
+    let node_config = node_config.unwrap_or_default();
+    let chain_config = chain_config.unwrap_or_else(|| ChainConfigBuilder::default().build());
+
+    let coin_configs = into_coin_configs(coins);
+    let message_configs = into_message_configs(messages);
+
+    let state_config = StateConfig {
+        coins: coin_configs,
+        messages: message_configs,
+        ..StateConfig::local_testnet()
+    };
+
+    (node_config, chain_config, state_config)
 }
 
 pub fn generate_random_salt() -> [u8; 32] {
-    StdRng::from_entropy().gen()
+    with_test_rng(|rng| rng.gen())
 }
 
 #[cfg(test)]
@@ -180,7 +264,7 @@ mod tests {
     use std::net::{Ipv4Addr, SocketAddr};
 
     use fuel_tx::{ConsensusParameters, ContractParameters, FeeParameters, TxParameters};
-    use fuels_core::types::bech32::FUEL_BECH32_HRP;
+    use fuels_core::types::{bech32::FUEL_BECH32_HRP, transaction_builders::Blob};
 
     use super::*;
 
@@ -305,6 +389,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_setup_message_with_data() {
+        let sender = Bech32Address::default();
+        let recipient = Bech32Address::new(FUEL_BECH32_HRP, [1; 32]);
+
+        let first = setup_message_with_data(&sender, &recipient, 10, vec![1, 2]);
+        let second = setup_message_with_data(&sender, &recipient, 10, vec![1, 2]);
+
+        assert_eq!(first.recipient, recipient);
+        assert_eq!(first.amount, 10);
+        assert_eq!(first.data, vec![1, 2]);
+        assert_ne!(first.nonce, second.nonce);
+    }
+
+    #[test]
+    fn seeded_setup_functions_are_reproducible() {
+        // given
+        let owner = Bech32Address::new(FUEL_BECH32_HRP, [1; 32]);
+        let setup = |seed| {
+            seed_test_rng(seed);
+            let (coins, asset_ids) = setup_multiple_assets_coins(&owner, 3, 2, 100);
+            let message = setup_message_with_data(&owner, &owner, 100, vec![]);
+            (coins, asset_ids, message.nonce, generate_random_salt())
+        };
+
+        // when
+        let first = setup(42);
+        let second = setup(42);
+        let other_seed = setup(43);
+
+        // then
+        assert_eq!(first, second);
+        assert_ne!(first.0, other_seed.0);
+        assert_ne!(first.1, other_seed.1);
+        assert_ne!(first.2, other_seed.2);
+        assert_ne!(first.3, other_seed.3);
+    }
+
     #[tokio::test]
     async fn test_setup_test_provider_custom_config() -> Result<()> {
         let socket = SocketAddr::new(Ipv4Addr::new(127, 0, 0, 1).into(), 4000);
@@ -386,4 +508,58 @@ mod tests {
         );
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_node_can_be_stopped_and_restarted() -> Result<()> {
+        // given
+        let mut node = setup_test_node(vec![], vec![], None, None).await?;
+        let address = node.address();
+
+        // when
+        node.stop().await?;
+
+        // then
+        assert!(!node.is_running());
+        assert!(node.provider().await.is_err());
+
+        // when
+        node.restart().await?;
+
+        // then
+        assert!(node.is_running());
+        assert_eq!(node.address(), address);
+        node.provider().await?.chain_info().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_setup_test_provider_with_genesis_deployments() -> Result<()> {
+        // given
+        let asset_id = AssetId::from([1; 32]);
+        let contract = GenesisContract::new(vec![1, 2, 3, 4]).with_balance(asset_id, 100);
+        let blob = Blob::new(vec![5, 6, 7]);
+        let deployments = GenesisDeployments::default()
+            .with_contract(contract)
+            .with_blob(blob.clone());
+
+        // when
+        let (provider, contract_ids) =
+            setup_test_provider_with_genesis(vec![], vec![], deployments, None, None).await?;
+
+        // then
+        let [contract_id] = &contract_ids[..] else {
+            panic!("expected one contract id");
+        };
+        assert!(provider.contract_exists(contract_id).await?);
+        assert_eq!(
+            provider
+                .get_contract_asset_balance(contract_id, asset_id)
+                .await?,
+            100
+        );
+        assert!(provider.blob_exists(blob.id()).await?);
+
+        Ok(())
+    }
 }
@@ -57,6 +57,29 @@ impl FuelService {
         self.bound_address
     }
 
+    /// Output of the node so far, limited to its last 10 000 lines. Only available when running
+    /// the `fuel-core` binary, a node started in-process with the `fuel-core-lib` feature logs
+    /// through `tracing` instead.
+    pub fn logs(&self) -> Option<String> {
+        #[cfg(feature = "fuel-core-lib")]
+        let logs = None;
+
+        #[cfg(not(feature = "fuel-core-lib"))]
+        let logs = Some(self.service.logs());
+
+        logs
+    }
+
+    /// Reads the current state of the node from its database.
+    #[cfg(feature = "fuel-core-lib")]
+    pub fn read_state_config(&self) -> Result<StateConfig> {
+        self.service
+            .shared
+            .database
+            .read_state_config()
+            .map_err(|err| error!(Other, "could not read the state of the node: {err}"))
+    }
+
     #[cfg(feature = "fuel-core-lib")]
     fn service_config(
         node_config: NodeConfig,
@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use fuel_core_chain_config::{
+    ChainConfig, SnapshotMetadata, SnapshotReader, SnapshotWriter, StateConfig,
+};
+use fuel_core_types::blockchain::header::LATEST_STATE_TRANSITION_VERSION;
+use fuels_core::types::errors::{error, Result};
+
+use crate::{NodeConfig, TestNode};
+
+/// The chain configuration and state of a test node at some block height.
+///
+/// Snapshots let expensive fixtures, e.g. deployed contracts, funded wallets and initialized
+/// storage, be built once and reused: take a snapshot with [`TestNode::snapshot`], write it to a
+/// directory and start a new node from it in every test that needs the fixture. Nodes started
+/// from a snapshot continue the chain: their genesis block follows the block the snapshot was
+/// taken at.
+#[derive(Debug, Clone)]
+pub struct ChainSnapshot {
+    chain_config: ChainConfig,
+    state_config: StateConfig,
+}
+
+impl ChainSnapshot {
+    pub fn new(chain_config: ChainConfig, state_config: StateConfig) -> Self {
+        Self {
+            chain_config,
+            state_config,
+        }
+    }
+
+    pub fn chain_config(&self) -> &ChainConfig {
+        &self.chain_config
+    }
+
+    pub fn state_config(&self) -> &StateConfig {
+        &self.state_config
+    }
+
+    /// Writes the snapshot into `dir` as JSON, in the format `fuel-core run --snapshot` expects.
+    pub fn write_to(&self, dir: impl AsRef<Path>) -> Result<()> {
+        let dir = dir.as_ref();
+
+        SnapshotWriter::json(dir)
+            .write_state_config(self.state_config.clone(), &self.chain_config)
+            .map_err(|e| error!(IO, "could not write snapshot to `{}`: {e}", dir.display()))?;
+
+        Ok(())
+    }
+
+    /// Reads a snapshot from `dir`, e.g. one written by [`ChainSnapshot::write_to`] or by
+    /// `fuel-core snapshot`.
+    pub fn load_from(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref();
+        let read_error = |e| error!(IO, "could not read snapshot from `{}`: {e}", dir.display());
+
+        let metadata = SnapshotMetadata::read(dir).map_err(read_error)?;
+        let reader = SnapshotReader::open(metadata).map_err(read_error)?;
+        let state_config = StateConfig::from_reader(&reader).map_err(read_error)?;
+
+        Ok(Self::new(reader.chain_config().clone(), state_config))
+    }
+
+    /// Starts a new node from the snapshot.
+    pub async fn start_node(&self, node_config: Option<NodeConfig>) -> Result<TestNode> {
+        TestNode::start(
+            node_config.unwrap_or_default(),
+            self.chain_config.clone(),
+            self.state_config_for_restore(),
+        )
+        .await
+    }
+
+    // A node started from a snapshot uses the state transition version following the one of the
+    // last block. Test nodes can only execute blocks with their native executor, so the last
+    // block's version is set such that the following one is the native version.
+    fn state_config_for_restore(&self) -> StateConfig {
+        let native_version = self
+            .chain_config
+            .genesis_state_transition_version
+            .unwrap_or(LATEST_STATE_TRANSITION_VERSION);
+
+        let mut state_config = self.state_config.clone();
+        if let Some(last_block) = &mut state_config.last_block {
+            last_block.state_transition_version = native_version.saturating_sub(1);
+        }
+
+        state_config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_core_chain_config::{CoinConfig, ContractConfig, Randomize};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn snapshot_can_be_written_and_loaded() -> Result<()> {
+        // given
+        let mut rng = StdRng::seed_from_u64(42);
+        let chain_config = ChainConfig {
+            chain_name: "snapshot".to_string(),
+            ..ChainConfig::local_testnet()
+        };
+        let state_config = StateConfig {
+            coins: vec![CoinConfig::randomize(&mut rng)],
+            contracts: vec![ContractConfig::randomize(&mut rng)],
+            ..StateConfig::default()
+        };
+        let snapshot = ChainSnapshot::new(chain_config, state_config);
+        let dir = tempfile::tempdir()?;
+
+        // when
+        snapshot.write_to(dir.path())?;
+        let loaded = ChainSnapshot::load_from(dir.path())?;
+
+        // then
+        assert_eq!(loaded.chain_config(), snapshot.chain_config());
+        assert_eq!(loaded.state_config(), snapshot.state_config());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use std::{net::SocketAddr, time::Duration};
+
+use fuel_core_chain_config::{ChainConfig, StateConfig};
+use fuels_accounts::provider::Provider;
+use fuels_core::types::errors::{error, Result};
+use portpicker::is_free;
+use tokio::time::{sleep, Instant};
+
+use crate::{ChainSnapshot, FuelService, NodeConfig};
+
+const PORT_RELEASE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Handle to a local node started for a test.
+///
+/// Unlike `setup_test_provider`, which keeps its node running until the process exits, the node
+/// is owned by the handle: it can be stopped and restarted at will and is stopped when the handle
+/// is dropped. Several nodes, e.g. with different `ChainConfig`s, can run side by side.
+///
+/// A restarted node listens on the same address, so existing providers keep working. It keeps
+/// its chain only when using `DbType::RocksDb`; an in-memory node starts again from genesis.
+pub struct TestNode {
+    service: Option<FuelService>,
+    node_config: NodeConfig,
+    chain_config: ChainConfig,
+    state_config: StateConfig,
+}
+
+impl TestNode {
+    pub async fn start(
+        node_config: NodeConfig,
+        chain_config: ChainConfig,
+        state_config: StateConfig,
+    ) -> Result<Self> {
+        let service = FuelService::start(
+            node_config.clone(),
+            chain_config.clone(),
+            state_config.clone(),
+        )
+        .await?;
+
+        Ok(Self {
+            node_config: NodeConfig {
+                addr: service.bound_address(),
+                ..node_config
+            },
+            service: Some(service),
+            chain_config,
+            state_config,
+        })
+    }
+
+    /// The address the node listens on, also after it was stopped.
+    pub fn address(&self) -> SocketAddr {
+        self.node_config.addr
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.service.is_some()
+    }
+
+    pub fn node_config(&self) -> &NodeConfig {
+        &self.node_config
+    }
+
+    pub fn chain_config(&self) -> &ChainConfig {
+        &self.chain_config
+    }
+
+    /// Connects a new provider to the node.
+    pub async fn provider(&self) -> Result<Provider> {
+        if !self.is_running() {
+            return Err(error!(Other, "test node at {} is stopped", self.address()));
+        }
+
+        Provider::from(self.address()).await
+    }
+
+    /// Output of the node so far, see [`FuelService::logs`]. `None` if the node is stopped.
+    pub fn logs(&self) -> Option<String> {
+        self.service.as_ref().and_then(FuelService::logs)
+    }
+
+    /// Stops the node. Does nothing if it is already stopped.
+    pub async fn stop(&mut self) -> Result<()> {
+        if let Some(service) = self.service.take() {
+            service.stop().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Stops the node, if running, and starts it again with the same configuration and address.
+    pub async fn restart(&mut self) -> Result<()> {
+        self.stop().await?;
+        self.wait_for_port_release().await?;
+
+        let service = FuelService::start(
+            self.node_config.clone(),
+            self.chain_config.clone(),
+            self.state_config.clone(),
+        )
+        .await?;
+        self.service = Some(service);
+
+        Ok(())
+    }
+
+    /// Takes a snapshot of the node's current state, from which new nodes can be started.
+    ///
+    /// A node running the `fuel-core` binary must use `DbType::RocksDb`. Since its database can
+    /// only be read while the node is not running, the node is briefly stopped to take the
+    /// snapshot and restarted afterwards.
+    pub async fn snapshot(&mut self) -> Result<ChainSnapshot> {
+        let state_config = self.read_state_config().await?;
+
+        Ok(ChainSnapshot::new(self.chain_config.clone(), state_config))
+    }
+
+    #[cfg(feature = "fuel-core-lib")]
+    async fn read_state_config(&mut self) -> Result<StateConfig> {
+        self.service
+            .as_ref()
+            .ok_or_else(|| error!(Other, "test node at {} is stopped", self.address()))?
+            .read_state_config()
+    }
+
+    #[cfg(not(feature = "fuel-core-lib"))]
+    async fn read_state_config(&mut self) -> Result<StateConfig> {
+        use crate::{fuel_bin_service, DbType};
+
+        let DbType::RocksDb(db_path) = &self.node_config.database_type else {
+            return Err(error!(
+                Other,
+                "snapshots of a node running the `fuel-core` binary need `DbType::RocksDb`"
+            ));
+        };
+        let db_path = fuel_bin_service::rocksdb_path(db_path);
+
+        let was_running = self.is_running();
+        self.stop().await?;
+        self.wait_for_port_release().await?;
+
+        let state_config = fuel_bin_service::read_state_config(&db_path).await;
+
+        if was_running {
+            self.restart().await?;
+        }
+
+        state_config
+    }
+
+    async fn wait_for_port_release(&self) -> Result<()> {
+        let port = self.address().port();
+        let started = Instant::now();
+
+        while !is_free(port) {
+            if started.elapsed() > PORT_RELEASE_TIMEOUT {
+                return Err(error!(
+                    IO,
+                    "port {port} of the stopped test node was not released"
+                ));
+            }
+
+            sleep(Duration::from_millis(50)).await;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    marker::PhantomData,
+    sync::{Mutex, OnceLock, PoisonError},
+};
+
+use fuel_crypto::Hasher;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+/// Environment variable holding the seed of the random values generated by the setup functions,
+/// e.g. `FUELS_TEST_SEED=42 cargo test`.
+pub const TEST_SEED_ENV_VAR: &str = "FUELS_TEST_SEED";
+
+thread_local! {
+    static TEST_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+    // process seed behind the reproducible generator of this thread, see `report_test_seed`
+    static REPRODUCIBLE_SEED: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+static PROCESS_SEED: OnceLock<u64> = OnceLock::new();
+// number of generators handed out to threads of each name
+static THREAD_STREAMS: Mutex<BTreeMap<String, u64>> = Mutex::new(BTreeMap::new());
+
+/// Seeds the random values, i.e. UTXO ids, asset ids, message nonces and salts, generated by the
+/// setup functions on the current thread. Each test runs on its own thread, so seeding at the
+/// start of a test makes its coins, and with them its transaction ids, reproducible.
+///
+/// Without an explicit seed, the seed is taken from the [`TEST_SEED_ENV_VAR`] environment
+/// variable, or picked at random, once per process. Each test derives its own generator from it
+/// and the test's name. Use [`report_test_seed`] to have the seed printed if the test panics, so
+/// that the failure can be reproduced.
+///
+/// Under `--test-threads=1` libtest runs every test on the main thread, so all tests share the
+/// `main` thread name. They then draw from a single generator in the order they run, and the
+/// values of a test depend on the tests that ran before it.
+///
+/// Values generated on other threads, e.g. in tasks spawned on a multi-threaded runtime, don't
+/// use the seed given here. They come from generators derived from the process seed as well,
+/// which are distinct from each other but depend on the order in which the threads first use
+/// them, so they are not reproducible.
+pub fn seed_test_rng(seed: u64) {
+    TEST_RNG.with(|rng| *rng.borrow_mut() = Some(StdRng::seed_from_u64(seed)));
+    REPRODUCIBLE_SEED.with(|reported| reported.set(None));
+}
+
+/// Returns a guard printing the seed behind the values generated by the setup functions on the
+/// current thread if the test panics while it is alive. Keep it for the whole test, e.g.
+/// `let _seed = report_test_seed();`. Nothing is printed for a generator seeded explicitly with
+/// [`seed_test_rng`].
+pub fn report_test_seed() -> TestSeedReport {
+    TestSeedReport {
+        _thread_bound: PhantomData,
+    }
+}
+
+/// Prints the seed of the current thread's generator when dropped during a panic. Returned by
+/// [`report_test_seed`].
+#[must_use = "the seed is only reported while the guard is alive"]
+pub struct TestSeedReport {
+    // the seed is looked up on the thread the guard is dropped on
+    _thread_bound: PhantomData<*const ()>,
+}
+
+impl Drop for TestSeedReport {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            return;
+        }
+
+        let seed = REPRODUCIBLE_SEED.try_with(Cell::get).ok().flatten();
+        if let Some(seed) = seed {
+            eprintln!(
+                "test helpers seeded with {seed}, set `{TEST_SEED_ENV_VAR}={seed}` to reproduce"
+            );
+        }
+    }
+}
+
+pub(crate) fn with_test_rng<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    TEST_RNG.with(|rng| {
+        let mut rng = rng.borrow_mut();
+        f(rng.get_or_insert_with(thread_rng))
+    })
+}
+
+// libtest runs each test on a thread named after it, so the generator of a test only depends on
+// the process seed and the test's name. Threads sharing a name, like the workers of a runtime,
+// are told apart by the order in which they get their generator.
+fn thread_rng() -> StdRng {
+    let seed = *PROCESS_SEED.get_or_init(process_seed);
+    let name = std::thread::current()
+        .name()
+        .unwrap_or_default()
+        .to_string();
+
+    let stream = {
+        let mut streams = THREAD_STREAMS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let count = streams.entry(name.clone()).or_default();
+        *count += 1;
+        *count - 1
+    };
+
+    // only the first thread of a name, e.g. the test thread, gets a reproducible generator
+    if stream == 0 {
+        REPRODUCIBLE_SEED.with(|reported| reported.set(Some(seed)));
+    }
+
+    let thread_seed = Hasher::default()
+        .chain(seed.to_be_bytes())
+        .chain(name.as_bytes())
+        .chain(stream.to_be_bytes())
+        .finalize();
+
+    StdRng::from_seed(*thread_seed)
+}
+
+fn process_seed() -> u64 {
+    match std::env::var(TEST_SEED_ENV_VAR) {
+        Ok(seed) => seed
+            .parse()
+            .unwrap_or_else(|_| panic!("`{TEST_SEED_ENV_VAR}` must be a `u64`, got `{seed}`")),
+        Err(_) => StdRng::from_entropy().next_u64(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn threads_sharing_a_name_get_distinct_generators() {
+        // given
+        let draw = || {
+            std::thread::Builder::new()
+                .name("shared-name".to_string())
+                .spawn(|| with_test_rng(|rng| rng.gen::<[u8; 32]>()))
+                .expect("thread should spawn")
+                .join()
+                .expect("thread should not panic")
+        };
+
+        // when
+        let (first, second) = (draw(), draw());
+
+        // then
+        assert_ne!(first, second);
+    }
+}
@@ -17,6 +17,7 @@ pub struct AssetConfig {
 pub struct WalletsConfig {
     num_wallets: u64,
     assets: Vec<AssetConfig>,
+    seed: Option<u64>,
 }
 
 impl WalletsConfig {
@@ -28,6 +29,7 @@ impl WalletsConfig {
                 num_coins: num_coins.unwrap_or(DEFAULT_NUM_COINS),
                 coin_amount: coin_amount.unwrap_or(DEFAULT_COIN_AMOUNT),
             }],
+            seed: None,
         }
     }
 
@@ -35,9 +37,18 @@ impl WalletsConfig {
         Self {
             num_wallets,
             assets,
+            seed: None,
         }
     }
 
+    /// Derives the wallets' keys and the UTXO ids of their coins from `seed`, using a generator
+    /// of their own that leaves the one seeded by [`crate::seed_test_rng`] untouched. Without a
+    /// seed, the wallets get fixed keys and their coins random UTXO ids.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
     pub fn num_wallets(&self) -> u64 {
         self.num_wallets
     }
@@ -45,6 +56,10 @@ impl WalletsConfig {
     pub fn assets(&self) -> &[AssetConfig] {
         &self.assets[..]
     }
+
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
 }
 
 impl Default for WalletsConfig {
@@ -56,6 +71,7 @@ impl Default for WalletsConfig {
                 num_coins: DEFAULT_NUM_COINS,
                 coin_amount: DEFAULT_COIN_AMOUNT,
             }],
+            seed: None,
         }
     }
 }
@@ -64,20 +64,24 @@ pub mod prelude {
     #[cfg(feature = "std")]
     pub use super::{
         accounts::{
+            dispatcher::{Dispatchable, TxDispatcher},
             impersonated_account::ImpersonatedAccount,
             predicate::Predicate,
             provider::*,
             wallet::{generate_mnemonic_phrase, WalletUnlocked},
+            withdrawal::{
+                CommitHeightSource, LatestBlockCommitHeight, WithdrawalStatus, WithdrawalTracker,
+            },
             Account, ViewOnlyAccount,
         },
         core::{
-            codec::{LogDecoder, LogId, LogResult},
+            codec::{ContractEvent, LogDecoder, LogId, LogResult},
             traits::Signer,
         },
         macros::setup_program_test,
         programs::{
             calls::{CallHandler, CallParameters, ContractDependency, Execution},
-            contract::{Contract, LoadConfiguration, StorageConfiguration},
+            contract::{Contract, LoadConfiguration, Src14Proxy, Src20Asset, StorageConfiguration},
         },
         test_helpers::*,
         types::transaction_builders::*,
@@ -87,7 +91,7 @@ pub mod prelude {
         macros::abigen,
         tx::Receipt,
         types::{
-            bech32::{Bech32Address, Bech32ContractId},
+            bech32::{AddressFormat, Bech32Address, Bech32ContractId},
             errors::{Error, Result},
             transaction::*,
             Address, AssetId, Bytes, ContractId, RawSlice, Salt,
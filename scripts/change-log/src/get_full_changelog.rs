@@ -53,7 +53,7 @@ pub async fn get_changelog_info(
         .as_ref()
         .map_or("misc", |title| title.split(':').next().unwrap_or("misc"))
         .to_string();
-    let is_breaking = pr.title.as_ref().map_or(false, |title| title.contains('!'));
+    let is_breaking = pr.title.as_ref().is_some_and(|title| title.contains('!'));
 
     let title_description = pr
         .title